@@ -1,6 +1,6 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    order_plugins, First, Main, MainSchedulePlugin, PendingPlugin, PlaceholderPlugin, Plugin,
+    PluginGraph, Plugins, PluginsState, SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -48,6 +48,17 @@ pub type InternedAppLabel = Interned<dyn AppLabel>;
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error("missing plugin dependencies:{}", format_missing_dependencies(.missing))]
+    MissingPluginDependencies { missing: Vec<(String, String)> },
+    #[error("plugin dependency cycle: {}", .plugins.join(" -> "))]
+    PluginDependencyCycle { plugins: Vec<String> },
+}
+
+fn format_missing_dependencies(missing: &[(String, String)]) -> String {
+    missing
+        .iter()
+        .map(|(plugin, dependency)| alloc::format!("\n    {plugin} requires {dependency}"))
+        .collect()
 }
 
 /// [`App`] is the primary API for writing user applications. It automates the setup of a
@@ -572,7 +583,9 @@ impl App {
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugins had already been added to the application.
+    /// Panics if one of the plugins had already been added to the application, or if one of the
+    /// plugins [requires](crate::PluginDependencies::require) a plugin that is neither already
+    /// added nor part of `plugins`.
     ///
     /// [`PluginGroup`]:super::PluginGroup
     #[track_caller]
@@ -585,10 +598,53 @@ impl App {
                 "Plugins cannot be added after App::cleanup() or App::finish() has been called."
             );
         }
-        plugins.add_to_app(self);
+        let mut pending = Vec::new();
+        plugins.collect_plugins(&mut pending);
+        self.add_pending_plugins(pending);
         self
     }
 
+    /// Orders plugins added together by their [dependencies](Plugin::dependencies), then builds
+    /// them.
+    #[track_caller]
+    pub(crate) fn add_pending_plugins(&mut self, plugins: Vec<PendingPlugin>) {
+        let plugins = order_plugins(plugins, |type_id| {
+            self.main()
+                .plugin_registry
+                .iter()
+                .any(|plugin| (**plugin).as_any().type_id() == type_id)
+        })
+        .unwrap_or_else(|error| panic!("Error adding plugins: {error}"));
+        for PendingPlugin { plugin, group } in plugins {
+            if let Err(AppError::DuplicatePlugin { plugin_name }) = self.add_boxed_plugin(plugin) {
+                match group {
+                    Some(group_name) => panic!(
+                        "Error adding plugin {plugin_name} in group {group_name}: plugin was already added in application"
+                    ),
+                    None => panic!(
+                        "Error adding plugin {plugin_name}: plugin was already added in application"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Returns the plugins added to the main app with their [dependencies](Plugin::dependencies),
+    /// in the order they were built.
+    ///
+    /// This can be printed to inspect how plugins were ordered.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # fn setup(_: &mut App) {}
+    /// let mut app = App::new();
+    /// app.add_plugins(setup);
+    /// println!("{}", app.plugin_graph());
+    /// ```
+    pub fn plugin_graph(&self) -> PluginGraph {
+        self.main().plugin_graph()
+    }
+
     /// Registers the type `T` in the [`AppTypeRegistry`] resource,
     /// adding reflect data as specified in the [`Reflect`](bevy_reflect::Reflect) derive:
     /// ```ignore (No serde "derive" feature)
@@ -1500,7 +1556,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginDependencies, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1572,7 +1628,9 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(
+        expected = "Error adding plugin bevy_app::app::tests::PluginA: plugin was already added"
+    )]
     fn cant_add_twice_the_same_plugin() {
         App::new().add_plugins((PluginA, PluginA));
     }
@@ -1587,6 +1645,83 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    struct PluginDependent;
+    impl Plugin for PluginDependent {
+        fn build(&self, app: &mut App) {
+            assert!(app.is_plugin_added::<PluginA>());
+        }
+        fn dependencies(&self, dependencies: &mut PluginDependencies) {
+            dependencies.require::<PluginA>().optional::<PluginB>();
+        }
+    }
+
+    #[test]
+    fn plugins_are_built_after_their_dependencies() {
+        let mut app = App::new();
+        app.add_plugins((PluginDependent, PluginB, PluginA));
+
+        let graph = app.plugin_graph();
+        let position = |name: &str| graph.nodes().iter().position(|n| n.name == name).unwrap();
+        let dependent = position(core::any::type_name::<PluginDependent>());
+        assert!(position(core::any::type_name::<PluginA>()) < dependent);
+        assert!(position(core::any::type_name::<PluginB>()) < dependent);
+    }
+
+    #[test]
+    fn required_plugin_can_be_added_before() {
+        App::new().add_plugins(PluginA).add_plugins(PluginDependent);
+    }
+
+    #[test]
+    #[should_panic(expected = "requires bevy_app::app::tests::PluginA")]
+    fn cant_add_plugin_without_its_required_plugins() {
+        App::new().add_plugins((PluginDependent, PluginB));
+    }
+
+    #[test]
+    fn dependencies_are_matched_by_type_not_name() {
+        struct NamedPlugin;
+        impl Plugin for NamedPlugin {
+            fn build(&self, _app: &mut App) {}
+            fn name(&self) -> &str {
+                "named"
+            }
+        }
+        struct PluginRequiringNamed;
+        impl Plugin for PluginRequiringNamed {
+            fn build(&self, app: &mut App) {
+                assert!(app.plugin_graph().contains::<NamedPlugin>());
+            }
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies.require::<NamedPlugin>();
+            }
+        }
+        App::new().add_plugins((PluginRequiringNamed, NamedPlugin));
+        App::new()
+            .add_plugins(NamedPlugin)
+            .add_plugins(PluginRequiringNamed);
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin dependency cycle")]
+    fn cant_add_plugins_depending_on_each_other() {
+        struct PluginX;
+        impl Plugin for PluginX {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies.optional::<PluginY>();
+            }
+        }
+        struct PluginY;
+        impl Plugin for PluginY {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies.require::<PluginX>();
+            }
+        }
+        App::new().add_plugins((PluginX, PluginY));
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
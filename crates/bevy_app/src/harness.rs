@@ -0,0 +1,441 @@
+use crate::{
+    App, AppExit, FixedMain, FixedMainScheduleOrder, Main, MainScheduleOrder, PluginsState,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{catch_system_panic, InternedScheduleLabel, ScheduleLabel},
+};
+use bevy_utils::prelude::DebugName;
+use core::{fmt, panic::AssertUnwindSafe};
+
+/// Drives an [`App`] frame by frame, for tests.
+///
+/// The harness finishes building the plugins of the app, then runs it one [`update`](App::update)
+/// per frame with [`run_frames`](Self::run_frames) or [`run_until`](Self::run_until). Panics are
+/// caught and reported as a [`HarnessError::Panic`], along with the system and the schedules that
+/// were running.
+///
+/// Events and observer triggers can be captured to be checked after the frames have run. Time can
+/// be advanced by a fixed step every frame with the `TimeHarnessExt` extension provided by
+/// `bevy_time`.
+///
+/// ```
+/// # use bevy_app::{prelude::*, AppHarness};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// #[derive(BufferedEvent, Clone, Debug, PartialEq)]
+/// struct Scored(u32);
+///
+/// let mut app = App::new();
+/// app.init_resource::<Score>().add_event::<Scored>().add_systems(
+///     Update,
+///     |mut score: ResMut<Score>, mut scored: EventWriter<Scored>| {
+///         score.0 += 1;
+///         if score.0 % 2 == 0 {
+///             scored.write(Scored(score.0));
+///         }
+///     },
+/// );
+///
+/// let mut harness = AppHarness::new(app);
+/// harness.capture_events::<Scored>();
+/// let frames = harness
+///     .run_until(10, |world| world.resource::<Score>().0 == 4)
+///     .unwrap();
+/// assert_eq!(frames, 4);
+/// assert_eq!(harness.captured_events::<Scored>(), [Scored(2), Scored(4)]);
+/// ```
+pub struct AppHarness {
+    app: App,
+    frame: u64,
+    poisoned: bool,
+    before_frame: Vec<Box<dyn FnMut(&mut World)>>,
+    after_frame: Vec<Box<dyn FnMut(&mut World)>>,
+}
+
+impl AppHarness {
+    /// Creates a harness for `app`, finishing and cleaning up its plugins if needed.
+    pub fn new(mut app: App) -> Self {
+        if app.plugins_state() != PluginsState::Cleaned {
+            while app.plugins_state() == PluginsState::Adding {
+                #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+                bevy_tasks::tick_global_task_pools_on_main_thread();
+            }
+            app.finish();
+            app.cleanup();
+        }
+        Self {
+            app,
+            frame: 0,
+            poisoned: false,
+            before_frame: Vec::new(),
+            after_frame: Vec::new(),
+        }
+    }
+
+    /// Returns the app.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the app, mutably.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns the world of the main app.
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns the world of the main app, mutably.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Returns the number of frames run so far.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Calls `f` with the world of the main app before each frame.
+    ///
+    /// Use this to feed input or advance clocks deterministically.
+    pub fn before_each_frame(&mut self, f: impl FnMut(&mut World) + 'static) -> &mut Self {
+        self.before_frame.push(Box::new(f));
+        self
+    }
+
+    /// Calls `f` with the world of the main app after each frame.
+    pub fn after_each_frame(&mut self, f: impl FnMut(&mut World) + 'static) -> &mut Self {
+        self.after_frame.push(Box::new(f));
+        self
+    }
+
+    /// Runs a single frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the app panicked or requested to exit during the frame, or if it
+    /// panicked during an earlier frame.
+    pub fn update(&mut self) -> Result<(), HarnessError> {
+        if self.poisoned {
+            return Err(HarnessError::Poisoned);
+        }
+        self.frame += 1;
+        for f in &mut self.before_frame {
+            f(self.app.world_mut());
+        }
+
+        // Running schedules are removed from `Schedules`, and are not put back when a system
+        // panics: the ones missing after a panic are the ones that were running.
+        let nesting = schedule_nesting(self.app.world());
+        let schedules: Vec<InternedScheduleLabel> = self
+            .app
+            .world()
+            .get_resource::<Schedules>()
+            .map(|schedules| {
+                schedules
+                    .iter()
+                    .map(|(_, schedule)| schedule.label())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if let Err((system, payload)) = catch_system_panic(AssertUnwindSafe(|| self.app.update())) {
+            self.poisoned = true;
+            let remaining = self.app.world().get_resource::<Schedules>();
+            let mut running: Vec<_> = schedules
+                .into_iter()
+                .filter(|label| remaining.is_none_or(|schedules| !schedules.contains(*label)))
+                .collect();
+            running.sort_by_key(|label| {
+                nesting
+                    .iter()
+                    .position(|outer| outer == label)
+                    .unwrap_or(usize::MAX)
+            });
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".into());
+            return Err(HarnessError::Panic(AppPanic {
+                frame: self.frame,
+                system,
+                schedules: running,
+                message,
+            }));
+        }
+
+        for f in &mut self.after_frame {
+            f(self.app.world_mut());
+        }
+        match self.app.should_exit() {
+            Some(exit) => Err(HarnessError::Exited {
+                frame: self.frame,
+                exit,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs `frames` frames.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first frame returning an error from [`update`](Self::update).
+    pub fn run_frames(&mut self, frames: u64) -> Result<(), HarnessError> {
+        for _ in 0..frames {
+            self.update()?;
+        }
+        Ok(())
+    }
+
+    /// Runs frames until `condition` holds after a frame, and returns the number of frames run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HarnessError::Timeout`] if `condition` still does not hold after `max_frames`
+    /// frames, and stops at the first frame returning an error from [`update`](Self::update).
+    pub fn run_until(
+        &mut self,
+        max_frames: u64,
+        mut condition: impl FnMut(&World) -> bool,
+    ) -> Result<u64, HarnessError> {
+        for frames in 1..=max_frames {
+            self.update()?;
+            if condition(self.app.world()) {
+                return Ok(frames);
+            }
+        }
+        Err(HarnessError::Timeout { frames: max_frames })
+    }
+
+    /// Starts capturing the events of type `E` written from now on.
+    ///
+    /// Events are collected after each frame, so none is missed even if no system reads them.
+    pub fn capture_events<E: BufferedEvent + Clone>(&mut self) -> &mut Self {
+        let world = self.app.world_mut();
+        if world.contains_resource::<CapturedEvents<E>>() {
+            return self;
+        }
+        let mut cursor = world
+            .get_resource::<Events<E>>()
+            .map(Events::get_cursor_current)
+            .unwrap_or_default();
+        world.insert_resource(CapturedEvents::<E>(Vec::new()));
+        self.after_each_frame(move |world| {
+            let Some(events) = world.get_resource::<Events<E>>() else {
+                return;
+            };
+            let events: Vec<E> = cursor.read(events).cloned().collect();
+            world.resource_mut::<CapturedEvents<E>>().0.extend(events);
+        })
+    }
+
+    /// Returns the captured events of type `E`, in the order they were written.
+    ///
+    /// This is empty if they are not [captured](Self::capture_events).
+    pub fn captured_events<E: BufferedEvent>(&self) -> &[E] {
+        self.app
+            .world()
+            .get_resource::<CapturedEvents<E>>()
+            .map_or(&[], |captured| &captured.0)
+    }
+
+    /// Returns the captured events of type `E`, and forgets them.
+    pub fn take_captured_events<E: BufferedEvent>(&mut self) -> Vec<E> {
+        self.app
+            .world_mut()
+            .get_resource_mut::<CapturedEvents<E>>()
+            .map(|mut captured| core::mem::take(&mut captured.0))
+            .unwrap_or_default()
+    }
+
+    /// Starts capturing the events of type `E` triggered from now on, with a global observer.
+    pub fn capture_triggers<E: Event + Clone>(&mut self) -> &mut Self {
+        let world = self.app.world_mut();
+        if !world.contains_resource::<CapturedTriggers<E>>() {
+            world.insert_resource(CapturedTriggers::<E>(Vec::new()));
+            world.add_observer(|event: On<E>, mut captured: ResMut<CapturedTriggers<E>>| {
+                captured.0.push(event.event().clone());
+            });
+        }
+        self
+    }
+
+    /// Returns the captured triggers of events of type `E`, in the order they were observed.
+    ///
+    /// An [`EntityEvent`] that propagates is captured once for each entity it reaches. This is
+    /// empty if they are not [captured](Self::capture_triggers).
+    pub fn captured_triggers<E: Event>(&self) -> &[E] {
+        self.app
+            .world()
+            .get_resource::<CapturedTriggers<E>>()
+            .map_or(&[], |captured| &captured.0)
+    }
+
+    /// Returns the captured triggers of events of type `E`, and forgets them.
+    pub fn take_captured_triggers<E: Event>(&mut self) -> Vec<E> {
+        self.app
+            .world_mut()
+            .get_resource_mut::<CapturedTriggers<E>>()
+            .map(|mut captured| core::mem::take(&mut captured.0))
+            .unwrap_or_default()
+    }
+
+    /// Returns the app.
+    pub fn into_app(self) -> App {
+        self.app
+    }
+}
+
+#[derive(Resource)]
+struct CapturedEvents<E: Send + Sync + 'static>(Vec<E>);
+
+#[derive(Resource)]
+struct CapturedTriggers<E: Send + Sync + 'static>(Vec<E>);
+
+/// Returns the schedules of the main schedule, from the outermost to the innermost.
+fn schedule_nesting(world: &World) -> Vec<InternedScheduleLabel> {
+    let mut nesting = alloc::vec![Main.intern()];
+    if let Some(order) = world.get_resource::<MainScheduleOrder>() {
+        nesting.extend(&order.startup_labels);
+        nesting.extend(&order.labels);
+    }
+    nesting.push(FixedMain.intern());
+    if let Some(order) = world.get_resource::<FixedMainScheduleOrder>() {
+        nesting.extend(&order.labels);
+    }
+    nesting
+}
+
+/// A panic caught by an [`AppHarness`].
+#[derive(Debug, Clone)]
+pub struct AppPanic {
+    /// The frame during which the app panicked.
+    pub frame: u64,
+    /// The system that panicked, if the panic happened in a system.
+    pub system: Option<DebugName>,
+    /// The schedules that were running, from the outermost to the innermost as far as the
+    /// [`Main`] schedule tells.
+    pub schedules: Vec<InternedScheduleLabel>,
+    /// The panic message.
+    pub message: String,
+}
+
+impl fmt::Display for AppPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the app panicked during frame {}", self.frame)?;
+        if let Some(system) = &self.system {
+            write!(f, " in system `{system}`")?;
+        }
+        if !self.schedules.is_empty() {
+            write!(f, " in schedule ")?;
+            for (index, label) in self.schedules.iter().enumerate() {
+                if index > 0 {
+                    write!(f, " > ")?;
+                }
+                write!(f, "{label:?}")?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// An error returned by [`AppHarness`] when running frames.
+#[derive(Debug, thiserror::Error)]
+pub enum HarnessError {
+    /// The app panicked.
+    #[error("{0}")]
+    Panic(AppPanic),
+    /// The app requested to exit.
+    #[error("the app exited during frame {frame} with {exit:?}")]
+    Exited {
+        /// The frame during which the app requested to exit.
+        frame: u64,
+        /// How the app exited.
+        exit: AppExit,
+    },
+    /// The condition of [`AppHarness::run_until`] did not hold in time.
+    #[error("the condition still did not hold after {frames} frames")]
+    Timeout {
+        /// The number of frames run.
+        frames: u64,
+    },
+    /// The app panicked during an earlier frame, and cannot run anymore.
+    #[error("the app panicked during an earlier frame")]
+    Poisoned,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Update;
+
+    #[derive(Event, Clone, Debug, PartialEq)]
+    struct Pinged(u32);
+
+    #[test]
+    fn captures_triggers_and_exits() {
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            |mut frames: Local<u32>, mut commands: Commands, mut exit: EventWriter<AppExit>| {
+                *frames += 1;
+                commands.trigger(Pinged(*frames));
+                if *frames == 3 {
+                    exit.write(AppExit::error());
+                }
+            },
+        );
+
+        let mut harness = AppHarness::new(app);
+        harness.capture_triggers::<Pinged>();
+        harness.run_frames(2).unwrap();
+        assert_eq!(
+            harness.take_captured_triggers::<Pinged>(),
+            [Pinged(1), Pinged(2)]
+        );
+        assert!(matches!(
+            harness.run_frames(5),
+            Err(HarnessError::Exited { frame: 3, .. })
+        ));
+        assert_eq!(harness.captured_triggers::<Pinged>(), [Pinged(3)]);
+    }
+
+    #[test]
+    fn reports_panics_with_schedules() {
+        fn count_frames(mut frames: Local<u32>) {
+            *frames += 1;
+            assert!(*frames < 2, "too many frames");
+        }
+
+        let mut app = App::new();
+        app.add_systems(Update, count_frames);
+
+        let mut harness = AppHarness::new(app);
+        let Err(HarnessError::Panic(panic)) = harness.run_until(5, |_| false) else {
+            panic!("the app did not panic");
+        };
+        assert_eq!(panic.frame, 2);
+        assert_eq!(panic.message, "too many frames");
+        assert_eq!(panic.schedules, [Main.intern(), Update.intern()]);
+        let system = IntoSystem::into_system(count_frames).name();
+        assert_eq!(panic.system.as_ref(), Some(&system));
+        assert_eq!(
+            panic.to_string(),
+            alloc::format!(
+                "the app panicked during frame 2 in system `{system}` in schedule Main > Update: too many frames"
+            )
+        );
+        assert!(matches!(harness.update(), Err(HarnessError::Poisoned)));
+    }
+}
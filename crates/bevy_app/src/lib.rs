@@ -24,6 +24,8 @@ extern crate alloc;
 extern crate self as bevy_app;
 
 mod app;
+#[cfg(feature = "std")]
+mod harness;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
@@ -39,6 +41,8 @@ mod terminal_ctrl_c_handler;
 pub mod hotpatch;
 
 pub use app::*;
+#[cfg(feature = "std")]
+pub use harness::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
@@ -1,5 +1,14 @@
-use crate::App;
-use core::any::Any;
+use crate::{App, AppError};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::HashMap;
+use core::{
+    any::{Any, TypeId},
+    fmt,
+};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -21,6 +30,12 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// ## Dependencies between plugins
+///
+/// A plugin can declare the plugins it depends on with [`Plugin::dependencies`]. Plugins added
+/// in the same [`App::add_plugins`] call are built after the plugins they depend on, and adding a
+/// plugin panics if one of its required plugins is missing from the app.
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -89,6 +104,39 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Declares the plugins this plugin depends on.
+    ///
+    /// When plugins are added together with [`App::add_plugins`], each plugin is built after the
+    /// plugins it depends on that are part of the same call. [Required](PluginDependencies::require)
+    /// plugins must either have been added to the app before, or be part of the same call.
+    ///
+    /// ```
+    /// # use bevy_app::*;
+    /// # struct AssetPlugin;
+    /// # impl Plugin for AssetPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct DiagnosticsPlugin;
+    /// # impl Plugin for DiagnosticsPlugin { fn build(&self, _: &mut App) {} }
+    /// struct AudioPlugin;
+    ///
+    /// impl Plugin for AudioPlugin {
+    ///     fn build(&self, _app: &mut App) {
+    ///         // Can rely on the resources initialized by `AssetPlugin`.
+    ///     }
+    ///
+    ///     fn dependencies(&self, dependencies: &mut PluginDependencies) {
+    ///         dependencies
+    ///             .require::<AssetPlugin>()
+    ///             .optional::<DiagnosticsPlugin>();
+    ///     }
+    /// }
+    ///
+    /// // `AssetPlugin` is built first.
+    /// App::new().add_plugins((AudioPlugin, AssetPlugin));
+    /// ```
+    fn dependencies(&self, _dependencies: &mut PluginDependencies) {
+        // no dependencies
+    }
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +147,226 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// The plugins a [`Plugin`] depends on, declared in [`Plugin::dependencies`].
+///
+/// Plugins are identified by their type, so a plugin overriding [`Plugin::name`] still satisfies
+/// the dependencies on it. Their type names are used in error messages.
+#[derive(Debug, Clone, Default)]
+pub struct PluginDependencies {
+    required: Vec<(TypeId, &'static str)>,
+    optional: Vec<(TypeId, &'static str)>,
+}
+
+impl PluginDependencies {
+    /// Requires the plugin `T` to be added to the app before this plugin is built.
+    pub fn require<T: Plugin>(&mut self) -> &mut Self {
+        self.required
+            .push((TypeId::of::<T>(), core::any::type_name::<T>()));
+        self
+    }
+
+    /// Builds this plugin after the plugin `T` if both are added together, without requiring `T`.
+    pub fn optional<T: Plugin>(&mut self) -> &mut Self {
+        self.optional
+            .push((TypeId::of::<T>(), core::any::type_name::<T>()));
+        self
+    }
+
+    /// Returns the type names of the required plugins.
+    pub fn iter_required(&self) -> impl Iterator<Item = &str> {
+        self.required.iter().map(|&(_, name)| name)
+    }
+
+    /// Returns the type names of the optional plugins.
+    pub fn iter_optional(&self) -> impl Iterator<Item = &str> {
+        self.optional.iter().map(|&(_, name)| name)
+    }
+
+    /// Returns the type names of all the plugins depended on.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.iter_required().chain(self.iter_optional())
+    }
+
+    /// Returns the type ids of all the plugins depended on.
+    pub fn iter_type_ids(&self) -> impl Iterator<Item = TypeId> {
+        self.required
+            .iter()
+            .chain(&self.optional)
+            .map(|&(type_id, _)| type_id)
+    }
+}
+
+/// A plugin added to an [`App`] and the plugins it depends on. See [`PluginGraph`].
+#[derive(Debug, Clone)]
+pub struct PluginGraphNode {
+    /// The [name](Plugin::name) of the plugin.
+    pub name: String,
+    /// The type of the plugin.
+    pub type_id: TypeId,
+    /// The [dependencies](Plugin::dependencies) of the plugin.
+    pub dependencies: PluginDependencies,
+}
+
+/// The plugins added to an app with their dependencies, in the order they were built.
+///
+/// Returned by [`App::plugin_graph`]. Its [`Display`](fmt::Display) implementation lists every
+/// plugin with its dependencies, marking the optional ones that were not added.
+#[derive(Debug, Clone, Default)]
+pub struct PluginGraph {
+    nodes: Vec<PluginGraphNode>,
+}
+
+impl PluginGraph {
+    pub(crate) fn new(plugins: &[Box<dyn Plugin>]) -> Self {
+        let nodes = plugins
+            .iter()
+            .filter(|plugin| !plugin.is::<PlaceholderPlugin>())
+            .map(|plugin| {
+                let mut dependencies = PluginDependencies::default();
+                plugin.dependencies(&mut dependencies);
+                PluginGraphNode {
+                    name: plugin.name().to_string(),
+                    type_id: (**plugin).as_any().type_id(),
+                    dependencies,
+                }
+            })
+            .collect();
+        Self { nodes }
+    }
+
+    /// Returns the plugins in the order they were built.
+    pub fn nodes(&self) -> &[PluginGraphNode] {
+        &self.nodes
+    }
+
+    /// Returns `true` if a plugin of type `T` is in the graph.
+    pub fn contains<T: Plugin>(&self) -> bool {
+        self.contains_type(TypeId::of::<T>())
+    }
+
+    fn contains_type(&self, type_id: TypeId) -> bool {
+        self.nodes.iter().any(|node| node.type_id == type_id)
+    }
+}
+
+impl fmt::Display for PluginGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            writeln!(f, "{}", node.name)?;
+            for name in node.dependencies.iter_required() {
+                writeln!(f, "    requires {name}")?;
+            }
+            for &(type_id, name) in &node.dependencies.optional {
+                if self.contains_type(type_id) {
+                    writeln!(f, "    optional {name}")?;
+                } else {
+                    writeln!(f, "    optional {name} (not added)")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Orders plugins added together so that each plugin comes after the plugins it depends on,
+/// keeping the given order otherwise.
+///
+/// `is_added` tells whether a plugin of the given type was added to the app before.
+pub(crate) fn order_plugins(
+    plugins: Vec<PendingPlugin>,
+    is_added: impl Fn(TypeId) -> bool,
+) -> Result<Vec<PendingPlugin>, AppError> {
+    let type_ids: Vec<TypeId> = plugins
+        .iter()
+        .map(|pending| (*pending.plugin).as_any().type_id())
+        .collect();
+    let mut indices = HashMap::<TypeId, Vec<usize>>::default();
+    for (index, &type_id) in type_ids.iter().enumerate() {
+        indices.entry(type_id).or_default().push(index);
+    }
+
+    let mut missing = Vec::new();
+    let mut edges = Vec::with_capacity(plugins.len());
+    for (index, pending) in plugins.iter().enumerate() {
+        let mut dependencies = PluginDependencies::default();
+        pending.plugin.dependencies(&mut dependencies);
+        for &(type_id, name) in &dependencies.required {
+            if !indices.contains_key(&type_id) && !is_added(type_id) {
+                missing.push((pending.plugin.name().to_string(), name.to_string()));
+            }
+        }
+        let targets: Vec<usize> = dependencies
+            .iter_type_ids()
+            .filter(|&type_id| type_id != type_ids[index])
+            .filter_map(|type_id| indices.get(&type_id))
+            .flatten()
+            .copied()
+            .collect();
+        edges.push(targets);
+    }
+    if !missing.is_empty() {
+        return Err(AppError::MissingPluginDependencies { missing });
+    }
+
+    let mut states = alloc::vec![VisitState::Unvisited; plugins.len()];
+    let mut path = Vec::new();
+    let mut order = Vec::with_capacity(plugins.len());
+    for index in 0..plugins.len() {
+        if let Err(cycle) = visit(index, &edges, &mut states, &mut path, &mut order) {
+            return Err(AppError::PluginDependencyCycle {
+                plugins: cycle
+                    .into_iter()
+                    .map(|index| plugins[index].plugin.name().to_string())
+                    .collect(),
+            });
+        }
+    }
+
+    let mut plugins: Vec<Option<PendingPlugin>> = plugins.into_iter().map(Some).collect();
+    Ok(order
+        .into_iter()
+        .filter_map(|index| plugins[index].take())
+        .collect())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VisitState {
+    Unvisited,
+    Visiting,
+    Visited,
+}
+
+/// Depth-first visit pushing dependencies before their dependents. Returns the plugins forming a
+/// cycle if one is found.
+fn visit(
+    index: usize,
+    edges: &[Vec<usize>],
+    states: &mut [VisitState],
+    path: &mut Vec<usize>,
+    order: &mut Vec<usize>,
+) -> Result<(), Vec<usize>> {
+    match states[index] {
+        VisitState::Visited => return Ok(()),
+        VisitState::Visiting => {
+            let start = path.iter().position(|&i| i == index).unwrap_or(0);
+            let mut cycle = path[start..].to_vec();
+            cycle.push(index);
+            return Err(cycle);
+        }
+        VisitState::Unvisited => {}
+    }
+
+    states[index] = VisitState::Visiting;
+    path.push(index);
+    for &target in &edges[index] {
+        visit(target, edges, states, path, order)?;
+    }
+    path.pop();
+    states[index] = VisitState::Visited;
+    order.push(index);
+    Ok(())
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -127,14 +395,22 @@ pub trait Plugins<Marker>: sealed::Plugins<Marker> {}
 
 impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
+pub(crate) use sealed::PendingPlugin;
+
 mod sealed {
-    use alloc::boxed::Box;
+    use alloc::{boxed::Box, string::String, vec::Vec};
     use variadics_please::all_tuples;
 
-    use crate::{App, AppError, Plugin, PluginGroup};
+    use crate::{Plugin, PluginGroup};
+
+    /// A plugin about to be added to an app, with the name of the group it comes from.
+    pub struct PendingPlugin {
+        pub plugin: Box<dyn Plugin>,
+        pub group: Option<String>,
+    }
 
     pub trait Plugins<Marker> {
-        fn add_to_app(self, app: &mut App);
+        fn collect_plugins(self, plugins: &mut Vec<PendingPlugin>);
     }
 
     pub struct PluginMarker;
@@ -142,22 +418,17 @@ mod sealed {
     pub struct PluginsTupleMarker;
 
     impl<P: Plugin> Plugins<PluginMarker> for P {
-        #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
-            {
-                panic!(
-                    "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
-            }
+        fn collect_plugins(self, plugins: &mut Vec<PendingPlugin>) {
+            plugins.push(PendingPlugin {
+                plugin: Box::new(self),
+                group: None,
+            });
         }
     }
 
     impl<P: PluginGroup> Plugins<PluginGroupMarker> for P {
-        #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            self.build().finish(app);
+        fn collect_plugins(self, plugins: &mut Vec<PendingPlugin>) {
+            self.build().collect_enabled(plugins);
         }
     }
 
@@ -173,11 +444,10 @@ mod sealed {
                     reason = "This is inside a macro, and as such, may not trigger in all cases."
                 )]
                 #[allow(non_snake_case, reason = "`all_tuples!()` generates non-snake-case variable names.")]
-                #[allow(unused_variables, reason = "`app` is unused when implemented for the unit type `()`.")]
-                #[track_caller]
-                fn add_to_app(self, app: &mut App) {
+                #[allow(unused_variables, reason = "`plugins` is unused when implemented for the unit type `()`.")]
+                fn collect_plugins(self, plugins: &mut Vec<PendingPlugin>) {
                     let ($($plugins,)*) = self;
-                    $($plugins.add_to_app(app);)*
+                    $($plugins.collect_plugins(plugins);)*
                 }
             }
         }
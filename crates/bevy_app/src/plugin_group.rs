@@ -1,4 +1,4 @@
-use crate::{App, AppError, Plugin};
+use crate::{App, PendingPlugin, Plugin};
 use alloc::{
     boxed::Box,
     string::{String, ToString},
@@ -7,7 +7,7 @@ use alloc::{
 use bevy_platform::collections::hash_map::Entry;
 use bevy_utils::TypeIdMap;
 use core::any::TypeId;
-use log::{debug, warn};
+use log::warn;
 
 /// A macro for generating a well-documented [`PluginGroup`] from a list of [`Plugin`] paths.
 ///
@@ -509,27 +509,30 @@ impl PluginGroupBuilder {
     }
 
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
-    /// in the order specified.
+    /// in the order specified, moving plugins after the [plugins they depend on](Plugin::dependencies).
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugin in the group was already added to the application.
+    /// Panics if one of the plugin in the group was already added to the application, or if
+    /// the dependencies of the plugins can't be satisfied.
     #[track_caller]
-    pub fn finish(mut self, app: &mut App) {
+    pub fn finish(self, app: &mut App) {
+        let mut plugins = Vec::new();
+        self.collect_enabled(&mut plugins);
+        app.add_pending_plugins(plugins);
+    }
+
+    /// Consumes the [`PluginGroupBuilder`], collecting the enabled [`Plugin`]s in the order
+    /// specified.
+    pub(crate) fn collect_enabled(mut self, plugins: &mut Vec<PendingPlugin>) {
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty)
                 && entry.enabled
             {
-                debug!("added plugin: {}", entry.plugin.name());
-                if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                    app.add_boxed_plugin(entry.plugin)
-                {
-                    panic!(
-                        "Error adding plugin {} in group {}: plugin was already added in application",
-                        plugin_name,
-                        self.group_name
-                    );
-                }
+                plugins.push(PendingPlugin {
+                    plugin: entry.plugin,
+                    group: Some(self.group_name.clone()),
+                });
             }
         }
     }
@@ -1,4 +1,4 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{App, AppLabel, InternedAppLabel, Plugin, PluginGraph, Plugins, PluginsState};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
     event::EventRegistry,
@@ -349,7 +349,9 @@ impl SubApp {
 
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
-        self.run_as_app(|app| plugins.add_to_app(app));
+        let mut pending = Vec::new();
+        plugins.collect_plugins(&mut pending);
+        self.run_as_app(|app| app.add_pending_plugins(pending));
         self
     }
 
@@ -372,6 +374,11 @@ impl SubApp {
             .collect()
     }
 
+    /// See [`App::plugin_graph`].
+    pub fn plugin_graph(&self) -> PluginGraph {
+        PluginGraph::new(&self.plugin_registry)
+    }
+
     /// Returns `true` if there is no plugin in the middle of being built.
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.plugin_build_depth > 0
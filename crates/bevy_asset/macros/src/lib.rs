@@ -43,6 +43,107 @@ pub fn derive_asset_dependency_visitor(input: TokenStream) -> TokenStream {
     }
 }
 
+const ASSET_ATTRIBUTE: &str = "asset";
+
+/// Implement the `AssetCollection` trait, along with the `Asset` and `VisitAssetDependencies` traits.
+///
+/// Every field must be annotated with `#[asset(path = "...")]` (or `#[asset(path = "...", folder)]` for
+/// `Handle<LoadedFolder>` fields).
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs with named fields",
+        ));
+    };
+    let syn::Fields::Named(fields) = &data_struct.fields else {
+        return Err(syn::Error::new_spanned(
+            &data_struct.fields,
+            "AssetCollection derive only works on structs with named fields",
+        ));
+    };
+
+    let mut field_loaders = Vec::new();
+    let mut field_visitors = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let mut path = None;
+        let mut folder = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    path = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("folder") {
+                    folder = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported asset attribute, expected `path` or `folder`"))
+                }
+            })?;
+        }
+        let Some(path) = path else {
+            return Err(syn::Error::new_spanned(
+                field,
+                "AssetCollection fields must have an `#[asset(path = \"...\")]` attribute",
+            ));
+        };
+        field_loaders.push(if folder {
+            quote!(#ident: asset_server.load_folder(#path))
+        } else {
+            quote!(#ident: asset_server.load(#path))
+        });
+        field_visitors.push(quote!(
+            #bevy_asset_path::VisitAssetDependencies::visit_dependencies(&self.#ident, visit);
+        ));
+    }
+
+    // prevent unused variable warnings in case the collection is empty
+    let (visit, asset_server) = if fields.named.is_empty() {
+        (quote! { _visit }, quote! { _asset_server })
+    } else {
+        (quote! { visit }, quote! { asset_server })
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause { }
+
+        impl #impl_generics #bevy_asset_path::VisitAssetDependencies for #struct_name #type_generics #where_clause {
+            fn visit_dependencies(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedAssetId)) {
+                #(#field_visitors)*
+            }
+        }
+
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(#asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self {
+                    #(#field_loaders,)*
+                }
+            }
+        }
+    })
+}
+
 fn derive_dependency_visitor_internal(
     ast: &DeriveInput,
     bevy_asset_path: &Path,
@@ -0,0 +1,80 @@
+use crate::{Asset, AssetServer, Assets, Handle, RecursiveDependencyLoadState};
+use bevy_ecs::{
+    resource::Resource,
+    system::{Commands, Res, ResMut},
+};
+use tracing::error;
+
+/// A group of assets that must be loaded together, declared as a struct of handles keyed by path.
+///
+/// An [`AssetCollection`] is itself an [`Asset`] that depends on every handle it contains. Load it with
+/// [`AssetServer::load_collection`] and use [`AssetServer::recursive_dependency_load_state`] on the returned handle to
+/// get the combined load state of every asset in the collection. Alternatively, [`AssetApp::load_asset_collection`]
+/// will start loading the collection and insert it as a [`Resource`] once every asset (and their dependencies) has loaded.
+///
+/// This trait should be derived. Every field must be annotated with `#[asset(path = "...")]`, or with
+/// `#[asset(path = "...", folder)]` for [`Handle<LoadedFolder>`](crate::LoadedFolder) fields:
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle, LoadedFolder};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::TypePath;
+/// # #[derive(bevy_asset::Asset, TypePath)]
+/// # struct Image;
+/// # #[derive(bevy_asset::Asset, TypePath)]
+/// # struct AudioSource;
+/// #[derive(AssetCollection, Resource, TypePath)]
+/// struct LevelAssets {
+///     #[asset(path = "textures/player.png")]
+///     player: Handle<Image>,
+///     #[asset(path = "audio/theme.ogg")]
+///     theme: Handle<AudioSource>,
+///     #[asset(path = "textures/tiles", folder)]
+///     tiles: Handle<LoadedFolder>,
+/// }
+/// ```
+///
+/// [`AssetApp::load_asset_collection`]: crate::AssetApp::load_asset_collection
+pub trait AssetCollection: Asset + Resource {
+    /// Starts loading every asset in this collection, returning the collection of (loading) handles.
+    fn load(asset_server: &AssetServer) -> Self;
+}
+
+/// A [`Resource`] holding the handle of an [`AssetCollection`] that is being loaded by [`AssetApp::load_asset_collection`].
+///
+/// It is removed once the collection has been inserted as a [`Resource`], or if the collection failed to load.
+///
+/// [`AssetApp::load_asset_collection`]: crate::AssetApp::load_asset_collection
+#[derive(Resource)]
+pub struct LoadingAssetCollection<C: AssetCollection> {
+    /// The handle of the collection being loaded.
+    pub handle: Handle<C>,
+}
+
+/// Inserts the [`AssetCollection`] `C` as a [`Resource`] once it has loaded, along with all of its dependencies.
+pub(crate) fn insert_loaded_asset_collection<C: AssetCollection>(
+    mut commands: Commands,
+    loading: Option<Res<LoadingAssetCollection<C>>>,
+    asset_server: Res<AssetServer>,
+    mut collections: ResMut<Assets<C>>,
+) {
+    let Some(loading) = loading else {
+        return;
+    };
+    match asset_server.recursive_dependency_load_state(&loading.handle) {
+        RecursiveDependencyLoadState::Loaded => {
+            if let Some(collection) = collections.remove(&loading.handle) {
+                commands.insert_resource(collection);
+            }
+            commands.remove_resource::<LoadingAssetCollection<C>>();
+        }
+        RecursiveDependencyLoadState::Failed(err) => {
+            error!(
+                "Failed to load asset collection {}: {err}",
+                core::any::type_name::<C>()
+            );
+            commands.remove_resource::<LoadingAssetCollection<C>>();
+        }
+        _ => {}
+    }
+}
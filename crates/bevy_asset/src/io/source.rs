@@ -1,5 +1,9 @@
 use crate::{
-    io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
+    io::{
+        memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+        processor_gated::ProcessorGatedReader,
+        AssetSourceEvent, AssetWatcher,
+    },
     processor::AssetProcessorData,
 };
 use alloc::{
@@ -205,6 +209,25 @@ impl AssetSourceBuilder {
         Some(source)
     }
 
+    /// Replaces the processed [`AssetReader`](crate::io::AssetReader) and [`AssetWriter`](crate::io::AssetWriter) with in-memory storage,
+    /// if this source has a processed writer. See [`AssetSourceBuilders::use_in_memory_processed_storage`].
+    pub fn use_in_memory_processed_storage(&mut self) {
+        if self.processed_writer.is_none() {
+            return;
+        }
+        let dir = Dir::default();
+        let reader_dir = dir.clone();
+        self.processed_reader = Some(Box::new(move || {
+            Box::new(MemoryAssetReader {
+                root: reader_dir.clone(),
+            })
+        }));
+        self.processed_writer = Some(Box::new(move |_| {
+            Some(Box::new(MemoryAssetWriter { root: dir.clone() }))
+        }));
+        self.processed_watcher = None;
+    }
+
     /// Will use the given `reader` function to construct unprocessed [`AssetReader`](crate::io::AssetReader) instances.
     pub fn with_reader(
         mut self,
@@ -360,6 +383,15 @@ impl AssetSourceBuilders {
         }
     }
 
+    /// Replaces the processed [`AssetReader`](crate::io::AssetReader) and [`AssetWriter`](crate::io::AssetWriter) of every source that
+    /// has them with in-memory storage. Processed assets will then only live as long as the app, and nothing is written to the
+    /// processed folders. This is useful to "dry run" the [`AssetProcessor`](crate::processor::AssetProcessor).
+    pub fn use_in_memory_processed_storage(&mut self) {
+        for source in self.sources.values_mut().chain(self.default.as_mut()) {
+            source.use_in_memory_processed_storage();
+        }
+    }
+
     /// Initializes the default [`AssetSourceBuilder`] if it has not already been set.
     pub fn init_default_source(&mut self, path: &str, processed_path: Option<&str>) {
         self.default
@@ -167,13 +167,14 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
-        Asset, AssetApp, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer, Assets,
-        DirectAssetAccessExt, Handle, UntypedHandle,
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer,
+        Assets, DirectAssetAccessExt, Handle, UntypedHandle,
     };
 }
 
 mod asset_changed;
 mod assets;
+mod collection;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -187,7 +188,8 @@ mod render_asset;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use collection::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -256,8 +258,24 @@ pub struct AssetPlugin {
     /// Approved folders are [`AssetPlugin::file_path`] and the folder of each
     /// [`AssetSource`](io::AssetSource). Subfolders within these folders are also valid.
     pub unapproved_path_mode: UnapprovedPathMode,
+    /// If set, the [`AssetProcessor`] will store processed assets in (and reuse processed assets from) a
+    /// [`ProcessedAssetCache`](processor::ProcessedAssetCache) at this file path (relative to the project root, or absolute).
+    ///
+    /// Point this at a folder shared between machines (ex: a network drive used by CI and every developer) to avoid
+    /// reprocessing the same assets everywhere. This only has an effect when [`AssetMode::Processed`] is used and the
+    /// `asset_processor` cargo feature is enabled.
+    pub processed_cache_path: Option<String>,
+    /// If `true`, the [`AssetProcessor`] is created with [`AssetProcessor::new_dry_run`]: assets are processed into in-memory storage,
+    /// and nothing is written to disk. This is intended for headless "asset check" runs (see [`AssetProcessor::check`]).
+    /// This only has an effect when [`AssetMode::Processed`] is used and the `asset_processor` cargo feature is enabled.
+    ///
+    /// Setting the `BEVY_ASSET_CHECK` environment variable also enables this (see [`AssetProcessor::run_check`]).
+    pub dry_run_processor: bool,
 }
 
+/// The environment variable that runs the app as a headless asset check. See [`AssetProcessor::run_check`].
+pub(crate) const ASSET_CHECK_VAR: &str = "BEVY_ASSET_CHECK";
+
 /// Determines how to react to attempts to load assets not inside the approved folders.
 ///
 /// Approved folders are [`AssetPlugin::file_path`] and the folder of each
@@ -332,6 +350,8 @@ impl Default for AssetPlugin {
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
             unapproved_path_mode: UnapprovedPathMode::default(),
+            processed_cache_path: None,
+            dry_run_processor: false,
         }
     }
 }
@@ -346,13 +366,20 @@ impl AssetPlugin {
 impl Plugin for AssetPlugin {
     fn build(&self, app: &mut App) {
         let embedded = EmbeddedAssetRegistry::default();
+        let check =
+            cfg!(feature = "asset_processor") && std::env::var_os(ASSET_CHECK_VAR).is_some();
+        let mode = if check {
+            &AssetMode::Processed
+        } else {
+            &self.mode
+        };
         {
             let mut sources = app
                 .world_mut()
                 .get_resource_or_init::<AssetSourceBuilders>();
             sources.init_default_source(
                 &self.file_path,
-                (!matches!(self.mode, AssetMode::Unprocessed))
+                (!matches!(mode, AssetMode::Unprocessed))
                     .then_some(self.processed_file_path.as_str()),
             );
             embedded.register_source(&mut sources);
@@ -362,7 +389,7 @@ impl Plugin for AssetPlugin {
             if let Some(watch_override) = self.watch_for_changes_override {
                 watch = watch_override;
             }
-            match self.mode {
+            match mode {
                 AssetMode::Unprocessed => {
                     let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                     let sources = builders.build_sources(watch, false);
@@ -379,7 +406,15 @@ impl Plugin for AssetPlugin {
                     #[cfg(feature = "asset_processor")]
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
-                        let processor = AssetProcessor::new(&mut builders);
+                        let processor = if self.dry_run_processor || check {
+                            AssetProcessor::new_dry_run(&mut builders)
+                        } else {
+                            AssetProcessor::new(&mut builders)
+                        };
+                        #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+                        if let Some(path) = &self.processed_cache_path {
+                            processor.set_cache(processor::ProcessedAssetCache::from_path(path));
+                        }
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
                         // the main asset server shares loaders with the processor asset server
@@ -391,8 +426,15 @@ impl Plugin for AssetPlugin {
                             watch,
                             self.unapproved_path_mode.clone(),
                         ))
-                        .insert_resource(processor)
-                        .add_systems(bevy_app::Startup, AssetProcessor::start);
+                        .insert_resource(processor);
+                        #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+                        if check {
+                            app.add_systems(bevy_app::Startup, AssetProcessor::run_check);
+                        } else {
+                            app.add_systems(bevy_app::Startup, AssetProcessor::start);
+                        }
+                        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
+                        app.add_systems(bevy_app::Startup, AssetProcessor::start);
                     }
                     #[cfg(not(feature = "asset_processor"))]
                     {
@@ -538,6 +580,11 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Initializes the given [`AssetCollection`] and starts loading it. Once every asset of the collection (and all of
+    /// their dependencies) has loaded, the collection is inserted as a [`Resource`](bevy_ecs::resource::Resource).
+    ///
+    /// While the collection is loading, its handle is available in the [`LoadingAssetCollection<C>`] resource.
+    fn load_asset_collection<C: AssetCollection>(&mut self) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -644,6 +691,21 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn load_asset_collection<C: AssetCollection>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<Assets<C>>() {
+            self.init_asset::<C>();
+        }
+        let handle = self
+            .world()
+            .resource::<AssetServer>()
+            .load_collection::<C>();
+        self.insert_resource(LoadingAssetCollection { handle })
+            .add_systems(
+                PreUpdate,
+                insert_loaded_asset_collection::<C>.after(AssetTrackingSystems),
+            )
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -671,12 +733,15 @@ mod tests {
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
-            memory::{Dir, MemoryAssetReader},
-            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader, Writer,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, InvalidGenerationError, LoadState, UnapprovedPathMode,
+        meta::{AssetActionMinimal, AssetMetaMinimal},
+        saver::{AssetSaver, SaveAssetError, SavedAsset},
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetLoadError,
+        AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets, InvalidGenerationError,
+        LoadState, LoadingAssetCollection, UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -694,12 +759,14 @@ mod tests {
     };
     use bevy_platform::collections::HashMap;
     use bevy_reflect::TypePath;
+    use bevy_tasks::block_on;
     use core::time::Duration;
+    use futures_lite::AsyncWriteExt;
     use serde::{Deserialize, Serialize};
     use std::path::Path;
     use thiserror::Error;
 
-    #[derive(Asset, TypePath, Debug, Default)]
+    #[derive(Asset, TypePath, Debug, Default, Clone)]
     pub struct CoolText {
         pub text: String,
         pub embedded: String,
@@ -1646,6 +1713,86 @@ mod tests {
         });
     }
 
+    #[derive(AssetCollection, Resource, TypePath)]
+    struct CoolTextCollection {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(path = "text", folder)]
+        text: Handle<LoadedFolder>,
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let c_path = "text/c.cool.ron";
+        let c_ron = r#"
+(
+    text: "c",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+        dir.insert_asset_text(Path::new(c_path), c_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .load_asset_collection::<CoolTextCollection>();
+        let handle = app
+            .world()
+            .resource::<LoadingAssetCollection<CoolTextCollection>>()
+            .handle
+            .clone();
+        gate_opener.open(a_path);
+        gate_opener.open(c_path);
+
+        // The collection must wait for the dependencies of its assets to load.
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(!app.world().contains_resource::<CoolTextCollection>());
+        assert!(!app
+            .world()
+            .resource::<AssetServer>()
+            .recursive_dependency_load_state(&handle)
+            .is_loaded());
+
+        gate_opener.open(b_path);
+        run_app_until(&mut app, |world| {
+            let collection = world.get_resource::<CoolTextCollection>()?;
+            let a_text = get::<CoolText>(world, collection.a.id()).unwrap();
+            assert_eq!("a", a_text.text);
+            let folder = world
+                .resource::<Assets<LoadedFolder>>()
+                .get(&collection.text)
+                .unwrap();
+            assert_eq!(folder.handles.len(), 1);
+            assert!(!world.contains_resource::<LoadingAssetCollection<CoolTextCollection>>());
+            Some(())
+        });
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
@@ -2017,4 +2164,122 @@ mod tests {
             Err(InvalidGenerationError::Removed { index })
         );
     }
+
+    struct CoolTextSaver;
+
+    impl AssetSaver for CoolTextSaver {
+        type Asset = CoolText;
+        type Settings = ();
+        type OutputLoader = CoolTextLoader;
+        type Error = std::io::Error;
+
+        async fn save(
+            &self,
+            writer: &mut Writer,
+            asset: SavedAsset<'_, Self::Asset>,
+            _settings: &Self::Settings,
+        ) -> Result<(), Self::Error> {
+            let ron = CoolTextRon {
+                text: asset.text.clone(),
+                dependencies: asset
+                    .dependencies
+                    .iter()
+                    .filter_map(|handle| handle.path().map(ToString::to_string))
+                    .collect(),
+                embedded_dependencies: Vec::new(),
+                sub_texts: Vec::new(),
+            };
+            writer
+                .write_all(ron::ser::to_string(&ron).unwrap().as_bytes())
+                .await
+        }
+    }
+
+    #[test]
+    fn save_asset() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        dir.insert_asset_text(
+            Path::new(a_path),
+            r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        let writer_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || {
+                    Box::new(MemoryAssetReader {
+                        root: reader_dir.clone(),
+                    })
+                })
+                .with_writer(move |_| {
+                    Some(Box::new(MemoryAssetWriter {
+                        root: writer_dir.clone(),
+                    }))
+                }),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(a_path);
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<Assets<CoolText>>()
+                .get(&handle)
+                .map(|_| ())
+        });
+
+        let mut texts = app.world_mut().resource_mut::<Assets<CoolText>>();
+        texts.get_mut(&handle).unwrap().text = "edited".to_string();
+        let task = asset_server
+            .save_to_source_path(&handle, &texts, CoolTextSaver, ())
+            .unwrap();
+        block_on(task).unwrap();
+
+        let reader = MemoryAssetReader { root: dir };
+        let mut saved = Vec::new();
+        block_on(async {
+            let mut asset_reader = reader.read(Path::new(a_path)).await.unwrap();
+            asset_reader.read_to_end(&mut saved).await.unwrap();
+        });
+        let saved: CoolTextRon = ron::de::from_bytes(&saved).unwrap();
+        assert_eq!(saved.text, "edited");
+        let meta = block_on(reader.read_meta_bytes(Path::new(a_path))).unwrap();
+        let meta: AssetMetaMinimal = ron::de::from_bytes(&meta).unwrap();
+        assert!(matches!(
+            meta.asset,
+            AssetActionMinimal::Load { loader } if loader == core::any::type_name::<CoolTextLoader>()
+        ));
+
+        // Save a copy to a new path and load it back.
+        let copy = CoolText {
+            text: "copy".to_string(),
+            dependencies: vec![handle.clone()],
+            ..Default::default()
+        };
+        block_on(asset_server.save("copy.cool.ron", copy, CoolTextSaver, ())).unwrap();
+        let copy_handle: Handle<CoolText> = asset_server.load("copy.cool.ron");
+        run_app_until(&mut app, |world| {
+            let copy = world.resource::<Assets<CoolText>>().get(&copy_handle)?;
+            assert_eq!(copy.text, "copy");
+            assert_eq!(copy.dependencies[0].id(), handle.id());
+            Some(())
+        });
+
+        assert!(matches!(
+            block_on(asset_server.save(
+                "copy.cool.ron#label",
+                CoolText::default(),
+                CoolTextSaver,
+                ()
+            )),
+            Err(SaveAssetError::LabeledPath(_))
+        ));
+    }
 }
@@ -1,7 +1,9 @@
 use crate::{
     io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
     loader_builders::{Deferred, NestedLoader, StaticTyped},
-    meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
+    meta::{
+        AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings, SettingsMigrations,
+    },
     path::AssetPath,
     Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
     UntypedHandle,
@@ -48,6 +50,19 @@ pub trait AssetLoader: Send + Sync + 'static {
     fn extensions(&self) -> &[&str] {
         &[]
     }
+
+    /// The current version of [`AssetLoader::Settings`]. This is stored in the [`AssetMeta`] of assets loaded by this loader.
+    /// Bump it whenever [`AssetLoader::Settings`] changes in a way that breaks existing `.meta` files, and add a matching
+    /// migration to [`AssetLoader::settings_migrations`].
+    const SETTINGS_VERSION: u32 = 0;
+
+    /// Returns the [`SettingsMigrations`] used to upgrade `.meta` files written for older versions of [`AssetLoader::Settings`].
+    fn settings_migrations() -> SettingsMigrations
+    where
+        Self: Sized,
+    {
+        SettingsMigrations::default()
+    }
 }
 
 /// Provides type-erased access to an [`AssetLoader`].
@@ -311,6 +326,12 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error(
+        "No settings migration is registered to upgrade asset meta from settings version {version}"
+    )]
+    MissingSettingsMigration { version: u32 },
+    #[error("Asset meta settings version {version} is newer than the current settings version {current_version}")]
+    UnsupportedSettingsVersion { version: u32, current_version: u32 },
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
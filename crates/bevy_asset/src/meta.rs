@@ -8,9 +8,10 @@ use crate::{
     loader::AssetLoader, processor::Process, Asset, AssetPath, DeserializeMetaError,
     VisitAssetDependencies,
 };
+use bevy_platform::collections::HashMap;
 use downcast_rs::{impl_downcast, Downcast};
 use ron::ser::PrettyConfig;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::error;
 
 pub const META_FORMAT_VERSION: &str = "1.0";
@@ -25,6 +26,10 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the [`AssetLoader::Settings`] or [`Process::Settings`] stored in [`AssetMeta::asset`].
+    /// Older versions are upgraded using the [`SettingsMigrations`] of the loader / processor when deserialized.
+    #[serde(default, skip_serializing_if = "is_default_settings_version")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -37,16 +42,186 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
 
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
             processed_info: None,
             asset,
         }
     }
 
     /// Deserializes the given serialized byte representation of the asset meta.
+    ///
+    /// If the meta was written for an older settings version, it is first upgraded to the current
+    /// [`AssetLoader::SETTINGS_VERSION`] / [`Process::SETTINGS_VERSION`] using the registered [`SettingsMigrations`].
     pub fn deserialize(bytes: &[u8]) -> Result<Self, DeserializeMetaError> {
-        Ok(ron::de::from_bytes(bytes)?)
+        let minimal: AssetMetaMinimal =
+            ron::de::from_bytes(bytes).map_err(DeserializeMetaError::DeserializeMinimal)?;
+        let (current_version, migrations): (u32, fn() -> SettingsMigrations) = match minimal.asset {
+            AssetActionMinimal::Load { .. } => (L::SETTINGS_VERSION, L::settings_migrations),
+            AssetActionMinimal::Process { .. } => (P::SETTINGS_VERSION, P::settings_migrations),
+            AssetActionMinimal::Ignore => return Ok(ron::de::from_bytes(bytes)?),
+        };
+        if minimal.settings_version == current_version {
+            return Ok(ron::de::from_bytes(bytes)?);
+        }
+        let migrated = migrations().migrate(bytes, minimal.settings_version, current_version)?;
+        Ok(ron::de::from_bytes(&migrated)?)
+    }
+}
+
+fn is_default_settings_version(version: &u32) -> bool {
+    *version == 0
+}
+
+type SettingsMigrationFn =
+    Box<dyn Fn(&[u8]) -> Result<Vec<u8>, DeserializeMetaError> + Send + Sync>;
+
+/// A set of functions that upgrade the settings stored in `.meta` files from an older settings version to a newer one.
+///
+/// Whenever the [`AssetLoader::Settings`] (or [`Process::Settings`]) of a loader (or processor) change in a way that breaks
+/// deserialization of existing `.meta` files, bump [`AssetLoader::SETTINGS_VERSION`] (or [`Process::SETTINGS_VERSION`]) and
+/// return a migration from the previous version in [`AssetLoader::settings_migrations`] (or [`Process::settings_migrations`]).
+/// The previous settings type should be kept around (it only needs to implement [`Deserialize`]) so the old `.meta` can be read.
+///
+/// ```
+/// # use bevy_asset::meta::SettingsMigrations;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Deserialize)]
+/// struct ImageSettingsV0 {
+///     srgb: bool,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct ImageSettings {
+///     is_srgb: bool,
+///     generate_mipmaps: bool,
+/// }
+///
+/// let migrations = SettingsMigrations::new().with_migration(0, |old: ImageSettingsV0| ImageSettings {
+///     is_srgb: old.srgb,
+///     generate_mipmaps: false,
+/// });
+/// ```
+#[derive(Default)]
+pub struct SettingsMigrations {
+    migrations: HashMap<u32, SettingsMigrationFn>,
+}
+
+impl SettingsMigrations {
+    /// Creates an empty set of migrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a migration that upgrades settings of type `Old` stored with `from_version` to settings of type `New`, stored with
+    /// `from_version + 1`.
+    pub fn with_migration<Old, New>(
+        mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> Self
+    where
+        Old: DeserializeOwned,
+        New: Serialize,
+    {
+        let migrate = move |bytes: &[u8]| {
+            let minimal: AssetMetaMinimal =
+                ron::de::from_bytes(bytes).map_err(DeserializeMetaError::DeserializeMinimal)?;
+            match minimal.asset {
+                AssetActionMinimal::Load { .. } => {
+                    let meta: SettingsMeta<Old, ()> = ron::de::from_bytes(bytes)?;
+                    Ok(meta.migrate(&migrate, |settings| settings).serialize())
+                }
+                AssetActionMinimal::Process { .. } => {
+                    let meta: SettingsMeta<(), Old> = ron::de::from_bytes(bytes)?;
+                    Ok(meta.migrate(|settings| settings, &migrate).serialize())
+                }
+                AssetActionMinimal::Ignore => Ok(bytes.to_vec()),
+            }
+        };
+        self.migrations.insert(from_version, Box::new(migrate));
+        self
+    }
+
+    /// Upgrades the given serialized `meta` from settings version `from` to settings version `to`, one version at a time.
+    pub fn migrate(
+        &self,
+        meta: &[u8],
+        from: u32,
+        to: u32,
+    ) -> Result<Vec<u8>, DeserializeMetaError> {
+        if from > to {
+            return Err(DeserializeMetaError::UnsupportedSettingsVersion {
+                version: from,
+                current_version: to,
+            });
+        }
+        let mut meta = meta.to_vec();
+        for version in from..to {
+            let migrate = self
+                .migrations
+                .get(&version)
+                .ok_or(DeserializeMetaError::MissingSettingsMigration { version })?;
+            meta = migrate(&meta)?;
+        }
+        Ok(meta)
+    }
+}
+
+/// A counterpart to [`AssetMeta`] that is generic over the settings types instead of the loader / processor types.
+/// This is used to read and write [`AssetMeta`] with settings types that no longer belong to a loader / processor.
+#[derive(Serialize, Deserialize)]
+struct SettingsMeta<LoaderSettings, ProcessSettings> {
+    meta_format_version: String,
+    #[serde(default, skip_serializing_if = "is_default_settings_version")]
+    settings_version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    processed_info: Option<ProcessedInfo>,
+    asset: AssetAction<LoaderSettings, ProcessSettings>,
+}
+
+impl<LoaderSettings, ProcessSettings> SettingsMeta<LoaderSettings, ProcessSettings> {
+    fn migrate<NewLoaderSettings, NewProcessSettings>(
+        self,
+        migrate_loader_settings: impl FnOnce(LoaderSettings) -> NewLoaderSettings,
+        migrate_process_settings: impl FnOnce(ProcessSettings) -> NewProcessSettings,
+    ) -> SettingsMeta<NewLoaderSettings, NewProcessSettings> {
+        let asset = match self.asset {
+            AssetAction::Load { loader, settings } => AssetAction::Load {
+                loader,
+                settings: migrate_loader_settings(settings),
+            },
+            AssetAction::Process {
+                processor,
+                settings,
+            } => AssetAction::Process {
+                processor,
+                settings: migrate_process_settings(settings),
+            },
+            AssetAction::Ignore => AssetAction::Ignore,
+        };
+        SettingsMeta {
+            meta_format_version: self.meta_format_version,
+            settings_version: self.settings_version + 1,
+            processed_info: self.processed_info,
+            asset,
+        }
+    }
+}
+
+impl<LoaderSettings: Serialize, ProcessSettings: Serialize>
+    SettingsMeta<LoaderSettings, ProcessSettings>
+{
+    fn serialize(&self) -> Vec<u8> {
+        ron::ser::to_string_pretty(&self, PrettyConfig::default())
+            .expect("type is convertible to ron")
+            .into_bytes()
     }
 }
 
@@ -101,6 +276,8 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -133,6 +310,8 @@ pub trait AssetMetaDyn: Downcast + Send + Sync {
     fn processed_info(&self) -> &Option<ProcessedInfo>;
     /// Returns a mutable reference to the [`ProcessedInfo`] if it exists.
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo>;
+    /// Returns the settings version of the internal [`AssetMeta`].
+    fn settings_version(&self) -> u32;
 }
 
 impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
@@ -161,6 +340,9 @@ impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo> {
         &mut self.processed_info
     }
+    fn settings_version(&self) -> u32 {
+        self.settings_version
+    }
 }
 
 impl_downcast!(AssetMetaDyn);
@@ -260,3 +442,145 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetAction, AssetMeta, SettingsMigrations};
+    use crate::{io::Reader, AssetLoader, DeserializeMetaError, LoadContext};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize)]
+    struct SettingsV0 {
+        scale: u32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsV1 {
+        scale: u32,
+        flip: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct Settings {
+        scale: f32,
+        flip: bool,
+    }
+
+    struct VersionedLoader;
+
+    impl AssetLoader for VersionedLoader {
+        type Asset = ();
+        type Settings = Settings;
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            unreachable!()
+        }
+
+        const SETTINGS_VERSION: u32 = 2;
+
+        fn settings_migrations() -> SettingsMigrations {
+            SettingsMigrations::new()
+                .with_migration(0, |old: SettingsV0| SettingsV1 {
+                    scale: old.scale,
+                    flip: false,
+                })
+                .with_migration(1, |old: SettingsV1| Settings {
+                    scale: old.scale as f32,
+                    flip: old.flip,
+                })
+        }
+    }
+
+    fn settings(meta: &AssetMeta<VersionedLoader, ()>) -> &Settings {
+        match &meta.asset {
+            AssetAction::Load { settings, .. } => settings,
+            _ => panic!("expected a load action"),
+        }
+    }
+
+    #[test]
+    fn migrates_old_settings() {
+        let meta_v0 = r#"(
+            meta_format_version: "1.0",
+            asset: Load(
+                loader: "VersionedLoader",
+                settings: (scale: 3),
+            ),
+        )"#;
+        let meta = AssetMeta::<VersionedLoader, ()>::deserialize(meta_v0.as_bytes()).unwrap();
+        assert_eq!(meta.settings_version, 2);
+        assert_eq!(
+            settings(&meta),
+            &Settings {
+                scale: 3.0,
+                flip: false
+            }
+        );
+
+        let meta_v1 = r#"(
+            meta_format_version: "1.0",
+            settings_version: 1,
+            asset: Load(
+                loader: "VersionedLoader",
+                settings: (scale: 2, flip: true),
+            ),
+        )"#;
+        let meta = AssetMeta::<VersionedLoader, ()>::deserialize(meta_v1.as_bytes()).unwrap();
+        assert_eq!(
+            settings(&meta),
+            &Settings {
+                scale: 2.0,
+                flip: true
+            }
+        );
+    }
+
+    #[test]
+    fn current_settings_are_not_migrated() {
+        let meta = AssetMeta::<VersionedLoader, ()>::new(AssetAction::Load {
+            loader: "VersionedLoader".into(),
+            settings: Settings {
+                scale: 1.5,
+                flip: true,
+            },
+        });
+        let bytes = super::AssetMetaDyn::serialize(&meta);
+        let meta = AssetMeta::<VersionedLoader, ()>::deserialize(&bytes).unwrap();
+        assert_eq!(meta.settings_version, 2);
+        assert_eq!(
+            settings(&meta),
+            &Settings {
+                scale: 1.5,
+                flip: true
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_settings_versions_fail() {
+        let newer = r#"(
+            meta_format_version: "1.0",
+            settings_version: 3,
+            asset: Load(loader: "VersionedLoader", settings: (scale: 1.0, flip: false)),
+        )"#;
+        assert!(matches!(
+            AssetMeta::<VersionedLoader, ()>::deserialize(newer.as_bytes()),
+            Err(DeserializeMetaError::UnsupportedSettingsVersion {
+                version: 3,
+                current_version: 2
+            })
+        ));
+
+        let migrations = SettingsMigrations::new();
+        assert_eq!(
+            migrations.migrate(b"", 0, 1),
+            Err(DeserializeMetaError::MissingSettingsMigration { version: 0 })
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    io::{AssetReader, AssetReaderError, AssetWriter, AssetWriterError},
+    io::{ErasedAssetReader, ErasedAssetWriter},
+    meta::{get_full_asset_hash, AssetHash, ProcessedInfo, ProcessedInfoMinimal},
+    AssetPath,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use futures_lite::AsyncReadExt;
+use ron::ser::PrettyConfig;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// A content-addressed store of processed assets that can be shared between multiple [`AssetProcessor`] runs, and across machines.
+///
+/// Before processing an asset, the [`AssetProcessor`] looks up the asset's [`ProcessedAssetCacheKey`] in the cache. If an entry exists
+/// and every "process dependency" recorded in that entry still has the same `full_hash`, the cached processed bytes and meta are copied
+/// into the processed [`AssetSource`] instead of running the [`Process`] implementation. Freshly processed assets are written back to the cache.
+///
+/// Entries are stored through a regular [`AssetReader`] / [`AssetWriter`] pair, so the cache can live anywhere an [`AssetSource`] can:
+/// a local folder, a network share mounted by every developer machine and CI runner, or a custom remote backend.
+///
+/// Entries are laid out as follows (relative to the root of the reader / writer):
+/// * `manifests/<key>`: the paths of the process dependencies that were used when the asset was processed.
+/// * `outputs/<key + dependency hashes>`: the processed asset bytes, alongside the processed meta (including its [`ProcessedInfo`]).
+///
+/// [`AssetProcessor`]: crate::processor::AssetProcessor
+/// [`AssetSource`]: crate::io::AssetSource
+/// [`Process`]: crate::processor::Process
+pub struct ProcessedAssetCache {
+    reader: Box<dyn ErasedAssetReader>,
+    writer: Box<dyn ErasedAssetWriter>,
+}
+
+/// Identifies the inputs of a processed asset, independently of its process dependencies.
+///
+/// Process dependencies are only known _after_ an asset has been processed, so they are not part of this key.
+/// Instead, the [`ProcessedAssetCache`] records them per key and combines their `full_hash` with this key to locate the processed output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProcessedAssetCacheKey {
+    /// The path of the asset being processed.
+    pub path: AssetPath<'static>,
+    /// A hash of the source asset bytes.
+    pub source_hash: AssetHash,
+    /// A hash of the source asset meta, which contains the processor and its settings.
+    pub settings_hash: AssetHash,
+}
+
+/// A processed asset stored in (or retrieved from) a [`ProcessedAssetCache`].
+#[derive(Debug, Clone)]
+pub struct CachedProcessedAsset {
+    /// The bytes of the processed asset.
+    pub asset_bytes: Vec<u8>,
+    /// The serialized processed meta of the asset, including its [`ProcessedInfo`].
+    pub meta_bytes: Vec<u8>,
+    /// The [`ProcessedInfo`] stored in `meta_bytes`.
+    pub processed_info: ProcessedInfo,
+}
+
+/// An error that occurs while reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    #[error("Failed to read from the processed asset cache: {0}")]
+    AssetReaderError(#[from] AssetReaderError),
+    #[error("Failed to write to the processed asset cache: {0}")]
+    AssetWriterError(#[from] AssetWriterError),
+    #[error("Encountered an invalid processed asset cache entry: {0}")]
+    InvalidEntry(#[from] ron::error::SpannedError),
+    #[error("The processed asset cache entry is missing its processed info")]
+    MissingProcessedInfo,
+}
+
+const MANIFESTS_FOLDER: &str = "manifests";
+const OUTPUTS_FOLDER: &str = "outputs";
+
+impl ProcessedAssetCacheKey {
+    /// Creates a new key for the asset at `path` with the given source `meta_bytes` and `asset_bytes`.
+    pub fn new(path: AssetPath<'static>, meta_bytes: &[u8], asset_bytes: &[u8]) -> Self {
+        Self {
+            path,
+            source_hash: *blake3::hash(asset_bytes).as_bytes(),
+            settings_hash: *blake3::hash(meta_bytes).as_bytes(),
+        }
+    }
+
+    /// NOTE: changing the hashing logic here invalidates every existing cache entry.
+    fn hash(&self) -> AssetHash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.path.to_string().as_bytes());
+        hasher.update(&self.source_hash);
+        hasher.update(&self.settings_hash);
+        *hasher.finalize().as_bytes()
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        Path::new(MANIFESTS_FOLDER).join(hash_to_hex(&self.hash()))
+    }
+
+    fn output_path(&self, dependency_hashes: impl Iterator<Item = AssetHash>) -> PathBuf {
+        let hash = get_full_asset_hash(self.hash(), dependency_hashes);
+        Path::new(OUTPUTS_FOLDER).join(hash_to_hex(&hash))
+    }
+}
+
+impl ProcessedAssetCache {
+    /// Creates a new cache that stores its entries using the given `reader` and `writer`. Both must point to the same storage.
+    pub fn new(reader: impl AssetReader, writer: impl AssetWriter) -> Self {
+        Self {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Creates a new cache that stores its entries in the folder at `path`. Relative paths are resolved the same way as
+    /// [`FileAssetReader`](crate::io::file::FileAssetReader) paths, absolute paths (ex: a shared network folder) are used as-is.
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+    pub fn from_path(path: impl AsRef<Path> + core::fmt::Debug) -> Self {
+        use crate::io::file::{FileAssetReader, FileAssetWriter};
+        Self::new(
+            FileAssetReader::new(path.as_ref()),
+            FileAssetWriter::new(path.as_ref(), true),
+        )
+    }
+
+    /// Returns the paths of the process dependencies recorded for `key`, if an entry exists.
+    pub async fn get_dependencies(
+        &self,
+        key: &ProcessedAssetCacheKey,
+    ) -> Result<Option<Vec<AssetPath<'static>>>, ProcessedAssetCacheError> {
+        let Some(bytes) = self.read_bytes(&key.manifest_path()).await? else {
+            return Ok(None);
+        };
+        Ok(Some(ron::de::from_bytes(&bytes)?))
+    }
+
+    /// Retrieves the processed asset for `key`, given the current `full_hash` of each of its process dependencies (in the order
+    /// returned by [`ProcessedAssetCache::get_dependencies`]).
+    pub async fn get(
+        &self,
+        key: &ProcessedAssetCacheKey,
+        dependency_hashes: impl Iterator<Item = AssetHash>,
+    ) -> Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError> {
+        let output_path = key.output_path(dependency_hashes);
+        let Some(asset_bytes) = self.read_bytes(&output_path).await? else {
+            return Ok(None);
+        };
+        let meta_bytes = match self.reader.read_meta_bytes(&output_path).await {
+            Ok(meta_bytes) => meta_bytes,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let processed_info = ron::de::from_bytes::<ProcessedInfoMinimal>(&meta_bytes)?
+            .processed_info
+            .ok_or(ProcessedAssetCacheError::MissingProcessedInfo)?;
+        Ok(Some(CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+            processed_info,
+        }))
+    }
+
+    /// Stores the processed `asset` for `key`. The process dependencies of the entry are taken from the [`ProcessedInfo`] of `asset`.
+    pub async fn insert(
+        &self,
+        key: &ProcessedAssetCacheKey,
+        asset: &CachedProcessedAsset,
+    ) -> Result<(), ProcessedAssetCacheError> {
+        let dependencies = &asset.processed_info.process_dependencies;
+        let output_path = key.output_path(dependencies.iter().map(|dep| dep.full_hash));
+        // Write the output before the manifest, so readers never observe a manifest without a matching output.
+        self.writer
+            .write_bytes(&output_path, &asset.asset_bytes)
+            .await?;
+        self.writer
+            .write_meta_bytes(&output_path, &asset.meta_bytes)
+            .await?;
+        let dependency_paths = dependencies
+            .iter()
+            .map(|dep| dep.path.clone())
+            .collect::<Vec<_>>();
+        let manifest = ron::ser::to_string_pretty(&dependency_paths, PrettyConfig::default())
+            .expect("asset paths are convertible to ron");
+        self.writer
+            .write_bytes(&key.manifest_path(), manifest.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn read_bytes(&self, path: &Path) -> Result<Option<Vec<u8>>, ProcessedAssetCacheError> {
+        let mut reader = match self.reader.read(path).await {
+            Ok(reader) => reader,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|err| AssetReaderError::Io(err.into()))?;
+        Ok(Some(bytes))
+    }
+}
+
+fn hash_to_hex(hash: &AssetHash) -> String {
+    use core::fmt::Write;
+    let mut hex = String::with_capacity(hash.len() * 2);
+    for byte in hash {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_to_hex, ProcessedAssetCacheKey};
+    use crate::AssetPath;
+
+    #[test]
+    fn cache_key_depends_on_all_inputs() {
+        let key = ProcessedAssetCacheKey::new(AssetPath::from("a.png"), b"meta", b"asset");
+        assert_eq!(
+            key,
+            ProcessedAssetCacheKey::new(AssetPath::from("a.png"), b"meta", b"asset")
+        );
+        assert_ne!(
+            key.hash(),
+            ProcessedAssetCacheKey::new(AssetPath::from("b.png"), b"meta", b"asset").hash()
+        );
+        assert_ne!(
+            key.hash(),
+            ProcessedAssetCacheKey::new(AssetPath::from("a.png"), b"other", b"asset").hash()
+        );
+        assert_ne!(
+            key.hash(),
+            ProcessedAssetCacheKey::new(AssetPath::from("a.png"), b"meta", b"other").hash()
+        );
+        assert_ne!(
+            key.output_path([[1; 32]].into_iter()),
+            key.output_path([[2; 32]].into_iter())
+        );
+    }
+
+    #[test]
+    fn hash_to_hex_is_lowercase_and_padded() {
+        let mut hash = [0; 32];
+        hash[0] = 0xab;
+        hash[31] = 0x01;
+        let hex = hash_to_hex(&hash);
+        assert_eq!(hex.len(), 64);
+        assert!(hex.starts_with("ab00"));
+        assert!(hex.ends_with("0001"));
+    }
+}
@@ -37,11 +37,15 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
+mod report;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
+pub use report::*;
 
 use crate::{
     io::{
@@ -59,10 +63,12 @@ use crate::{
 use alloc::{borrow::ToOwned, boxed::Box, collections::VecDeque, sync::Arc, vec, vec::Vec};
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::{HashMap, HashSet};
+use bevy_platform::time::Instant;
 use bevy_tasks::IoTaskPool;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{debug, error, trace, warn};
@@ -99,9 +105,19 @@ pub struct AssetProcessor {
 pub struct AssetProcessorData {
     pub(crate) asset_infos: async_lock::RwLock<ProcessorAssetInfos>,
     log: async_lock::RwLock<Option<ProcessorTransactionLog>>,
+    /// Where the [`ProcessorTransactionLog`] is stored.
+    log_path: RwLock<PathBuf>,
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// The shared cache of processed assets, if one is configured.
+    cache: RwLock<Option<Arc<ProcessedAssetCache>>>,
+    /// Whether source `.meta` files that were upgraded by [`SettingsMigrations`](crate::meta::SettingsMigrations) should be written back.
+    rewrite_migrated_meta: AtomicBool,
+    /// Whether this processor is a "dry run" processor. See [`AssetProcessor::new_dry_run`].
+    dry_run: bool,
+    /// The per-asset results of processing, used to build a [`ProcessorReport`].
+    report: Mutex<ProcessorReportCollector>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -113,7 +129,27 @@ pub struct AssetProcessorData {
 impl AssetProcessor {
     /// Creates a new [`AssetProcessor`] instance.
     pub fn new(source: &mut AssetSourceBuilders) -> Self {
-        let data = Arc::new(AssetProcessorData::new(source.build_sources(true, false)));
+        Self::new_internal(source, false)
+    }
+
+    /// Creates a new "dry run" [`AssetProcessor`] instance, which processes assets into in-memory processed storage instead of
+    /// the processed [`AssetSource`]s, and never writes to the processor transaction log or the source assets.
+    ///
+    /// This is intended to validate assets without producing any artifacts (ex: in CI). Use [`AssetProcessor::check`]
+    /// (or [`AssetProcessor::process_assets`] followed by [`AssetProcessor::report`]) to find out which assets are broken.
+    ///
+    /// Note that this changes the processed storage of the given `source` builders to be in-memory
+    /// (see [`AssetSourceBuilders::use_in_memory_processed_storage`]), so the main [`AssetServer`] built from them will load
+    /// the dry run results.
+    pub fn new_dry_run(source: &mut AssetSourceBuilders) -> Self {
+        source.use_in_memory_processed_storage();
+        Self::new_internal(source, true)
+    }
+
+    fn new_internal(source: &mut AssetSourceBuilders, dry_run: bool) -> Self {
+        let mut data = AssetProcessorData::new(source.build_sources(true, false));
+        data.dry_run = dry_run;
+        let data = Arc::new(data);
         // The asset processor uses its own asset server with its own id space
         let mut sources = source.build_sources(false, false);
         sources.gate_on_processor(data.clone());
@@ -168,9 +204,100 @@ impl AssetProcessor {
         &self.data.sources
     }
 
+    /// Returns `true` if this is a "dry run" processor. See [`AssetProcessor::new_dry_run`].
+    pub fn is_dry_run(&self) -> bool {
+        self.data.dry_run
+    }
+
+    /// Returns a [`ProcessorReport`] describing the latest result of processing every asset encountered so far.
+    pub fn report(&self) -> ProcessorReport {
+        self.data.report.lock().report()
+    }
+
+    /// Processes all assets (see [`AssetProcessor::process_assets`]) and returns the resulting [`ProcessorReport`].
+    /// This blocks until processing has finished.
+    ///
+    /// Combined with [`AssetProcessor::new_dry_run`] (or [`AssetPlugin::dry_run_processor`](crate::AssetPlugin::dry_run_processor)),
+    /// this can be used to build a headless "asset check" command that fails when assets are broken, without running the app:
+    ///
+    /// ```no_run
+    /// # use bevy_app::{App, AppExit};
+    /// # use bevy_asset::{AssetMode, AssetPlugin, processor::AssetProcessor};
+    /// let mut app = App::new();
+    /// app.add_plugins(AssetPlugin {
+    ///     mode: AssetMode::Processed,
+    ///     dry_run_processor: true,
+    ///     ..Default::default()
+    /// });
+    /// // Register loaders and processors here (or add the plugins that do).
+    /// app.finish();
+    /// app.cleanup();
+    ///
+    /// let report = app.world().resource::<AssetProcessor>().check();
+    /// println!("{}", report.to_ron());
+    /// if report.has_errors() {
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    ///
+    /// Without a call to [`ProcessorReport::with_roots`], [`ProcessorReport::unused`] is empty. To run this check without
+    /// writing any code, see [`AssetProcessor::run_check`].
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    pub fn check(&self) -> ProcessorReport {
+        self.process_assets();
+        self.report()
+    }
+
+    /// Runs [`AssetProcessor::check`], prints the resulting [`ProcessorReport`] to stdout as RON and exits the app, with an
+    /// error if any asset is broken.
+    ///
+    /// [`AssetPlugin`](crate::AssetPlugin) runs this system instead of [`AssetProcessor::start`] when the `BEVY_ASSET_CHECK`
+    /// environment variable is set, in which case it also uses a dry run processor (see [`AssetProcessor::new_dry_run`]) and
+    /// [`AssetMode::Processed`](crate::AssetMode::Processed). This turns any app built with the `asset_processor` cargo
+    /// feature into a headless asset checker:
+    ///
+    /// ```sh
+    /// BEVY_ASSET_CHECK="levels/main.scn.ron,ui/menu.scn.ron" cargo run --features bevy/asset_processor
+    /// ```
+    ///
+    /// The variable contains a comma-separated list of root asset paths, used to detect unused assets
+    /// (see [`ProcessorReport::with_roots`]). If it is empty, unused assets are not detected.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    #[expect(
+        clippy::print_stdout,
+        reason = "The report is the output of the asset check."
+    )]
+    pub fn run_check(processor: Res<Self>, mut exit: EventWriter<bevy_app::AppExit>) {
+        let roots = std::env::var(crate::ASSET_CHECK_VAR).unwrap_or_default();
+        let report = processor.check().with_roots(
+            roots
+                .split(',')
+                .map(str::trim)
+                .filter(|root| !root.is_empty())
+                .map(|root| AssetPath::parse(root).into_owned()),
+        );
+        std::println!("{}", report.to_ron());
+        for asset in report.failed() {
+            if let AssetReportOutcome::Failed(err) = &asset.outcome {
+                error!("Failed to process asset {}: {err}", asset.path);
+            }
+        }
+        for asset in report.missing_loaders() {
+            error!("No loader found for asset {}", asset.path);
+        }
+        exit.write(if report.has_errors() {
+            bevy_app::AppExit::error()
+        } else {
+            bevy_app::AppExit::Success
+        });
+    }
+
     /// Logs an unrecoverable error. On the next run of the processor, all assets will be regenerated. This should only be used as a last resort.
     /// Every call to this should be considered with scrutiny and ideally replaced with something more granular.
     async fn log_unrecoverable(&self) {
+        if self.data.dry_run {
+            return;
+        }
         let mut log = self.data.log.write().await;
         let log = log.as_mut().unwrap();
         log.unrecoverable().await.unwrap();
@@ -179,6 +306,9 @@ impl AssetProcessor {
     /// Logs the start of an asset being processed. If this is not followed at some point in the log by a closing [`AssetProcessor::log_end_processing`],
     /// in the next run of the processor the asset processing will be considered "incomplete" and it will be reprocessed.
     async fn log_begin_processing(&self, path: &AssetPath<'_>) {
+        if self.data.dry_run {
+            return;
+        }
         let mut log = self.data.log.write().await;
         let log = log.as_mut().unwrap();
         log.begin_processing(path).await.unwrap();
@@ -186,6 +316,9 @@ impl AssetProcessor {
 
     /// Logs the end of an asset being successfully processed. See [`AssetProcessor::log_begin_processing`].
     async fn log_end_processing(&self, path: &AssetPath<'_>) {
+        if self.data.dry_run {
+            return;
+        }
         let mut log = self.data.log.write().await;
         let log = log.as_mut().unwrap();
         log.end_processing(path).await.unwrap();
@@ -217,7 +350,7 @@ impl AssetProcessor {
     ///   (if the latest version of the asset has not been processed).
     #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
     pub fn process_assets(&self) {
-        let start_time = std::time::Instant::now();
+        let start_time = Instant::now();
         debug!("Processing Assets");
         IoTaskPool::get().scope(|scope| {
             scope.spawn(async move {
@@ -232,7 +365,7 @@ impl AssetProcessor {
         // This must happen _after_ the scope resolves or it will happen "too early"
         // Don't move this into the async scope above! process_assets is a blocking/sync function this is fine
         bevy_tasks::block_on(self.finish_processing_assets());
-        let end_time = std::time::Instant::now();
+        let end_time = Instant::now();
         debug!("Processing finished in {:?}", end_time - start_time);
     }
 
@@ -505,6 +638,7 @@ impl AssetProcessor {
                 .await;
         }
         infos.remove(&asset_path).await;
+        self.data.report.lock().remove(&asset_path);
     }
 
     /// Handles a renamed source asset by moving its processed results to the new location and updating in-memory paths + metadata.
@@ -528,6 +662,11 @@ impl AssetProcessor {
                 .unwrap();
         }
         infos.rename(&old, &new).await;
+        let mut report = self.data.report.lock();
+        if let Some(mut asset_report) = report.remove(&old) {
+            asset_report.path = new;
+            report.insert(asset_report);
+        }
     }
 
     async fn finish_processing_assets(&self) {
@@ -606,6 +745,33 @@ impl AssetProcessor {
         processors.get(processor_type_name).cloned()
     }
 
+    /// Sets the [`ProcessedAssetCache`] used to share processed assets across runs and machines.
+    /// This should be set before the processor starts.
+    pub fn set_cache(&self, cache: ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns the [`ProcessedAssetCache`] used by this processor, if one is configured.
+    pub fn cache(&self) -> Option<Arc<ProcessedAssetCache>> {
+        self.data.cache.read().clone()
+    }
+
+    /// Stores the [`ProcessorTransactionLog`] at `path` instead of its default location.
+    /// This must be set before the processor starts.
+    #[cfg(test)]
+    pub(crate) fn set_log_path(&self, path: PathBuf) {
+        *self.data.log_path.write() = path;
+    }
+
+    /// Sets whether source `.meta` files written for an older settings version should be rewritten with the upgraded settings
+    /// after being migrated by the [`SettingsMigrations`](crate::meta::SettingsMigrations) of their loader / processor.
+    /// If this is `false` (the default), the migration happens in memory every time the asset is processed.
+    pub fn set_rewrite_migrated_meta(&self, rewrite: bool) {
+        self.data
+            .rewrite_migrated_meta
+            .store(rewrite, Ordering::Relaxed);
+    }
+
     /// Populates the initial view of each asset by scanning the unprocessed and processed asset folders.
     /// This info will later be used to determine whether or not to re-process an asset
     ///
@@ -618,7 +784,9 @@ impl AssetProcessor {
         )
     )]
     async fn initialize(&self) -> Result<(), InitializeError> {
-        self.validate_transaction_log_and_recover().await;
+        if !self.data.dry_run {
+            self.validate_transaction_log_and_recover().await;
+        }
         let mut asset_infos = self.data.asset_infos.write().await;
 
         /// Retrieves asset paths recursively. If `clean_empty_folders_writer` is Some, it will be used to clean up empty
@@ -786,9 +954,42 @@ impl AssetProcessor {
     /// [`ProcessorGatedReader`]: crate::io::processor_gated::ProcessorGatedReader
     async fn process_asset(&self, source: &AssetSource, path: PathBuf) {
         let asset_path = AssetPath::from(path).with_source(source.id());
+        let start_time = Instant::now();
         let result = self.process_asset_internal(source, &asset_path).await;
+        let duration = start_time.elapsed();
+        let outcome = AssetReportOutcome::new(&result);
         let mut infos = self.data.asset_infos.write().await;
-        infos.finish_processing(asset_path, result).await;
+        infos.finish_processing(asset_path.clone(), result).await;
+        let Some(mut outcome) = outcome else {
+            // The source asset does not exist (anymore), so there is nothing to report.
+            self.data.report.lock().remove(&asset_path);
+            return;
+        };
+        if matches!(
+            outcome,
+            AssetReportOutcome::Processed
+                | AssetReportOutcome::Cached
+                | AssetReportOutcome::SkippedNotChanged
+        ) && self.data.report.lock().is_missing_loader(&asset_path)
+        {
+            outcome = AssetReportOutcome::MissingLoader;
+        }
+        let process_dependencies = infos
+            .get(&asset_path)
+            .and_then(|info| info.processed_info.as_ref())
+            .map(|info| {
+                info.process_dependencies
+                    .iter()
+                    .map(|dependency| dependency.path.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.data.report.lock().insert(AssetReport {
+            path: asset_path,
+            outcome,
+            duration,
+            process_dependencies,
+        });
     }
 
     async fn process_asset_internal(
@@ -820,6 +1021,7 @@ impl AssetProcessor {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
+                let settings_version = minimal.settings_version;
                 let (meta, processor) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
@@ -837,6 +1039,22 @@ impl AssetProcessor {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
+                let meta_bytes = if meta.settings_version() != settings_version {
+                    // Hash the migrated meta, so the result doesn't change once the meta file is rewritten.
+                    let migrated_meta_bytes = meta.serialize();
+                    if !self.data.dry_run && self.data.rewrite_migrated_meta.load(Ordering::Relaxed)
+                    {
+                        debug!("Rewriting migrated meta for {}", asset_path);
+                        source
+                            .writer()?
+                            .write_meta_bytes(path, &migrated_meta_bytes)
+                            .await
+                            .map_err(writer_err)?;
+                    }
+                    migrated_meta_bytes
+                } else {
+                    meta_bytes
+                };
                 (meta, meta_bytes, processor)
             }
             Err(AssetReaderError::NotFound(_path)) => {
@@ -848,8 +1066,18 @@ impl AssetProcessor {
                     (meta, Some(processor))
                 } else {
                     match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
+                        Ok(loader) => {
+                            self.data
+                                .report
+                                .lock()
+                                .set_missing_loader(asset_path.clone(), false);
+                            (loader.default_meta(), None)
+                        }
                         Err(MissingAssetLoaderForExtensionError { .. }) => {
+                            self.data
+                                .report
+                                .lock()
+                                .set_missing_loader(asset_path.clone(), true);
                             let meta: Box<dyn AssetMetaDyn> =
                                 Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
                             (meta, None)
@@ -911,6 +1139,17 @@ impl AssetProcessor {
                 }
             }
         }
+        // Only assets that are actually processed are cached. Assets that are copied as-is are cheap to "process".
+        let cache = processor.as_ref().and_then(|_| self.cache());
+        let cache_key = ProcessedAssetCacheKey::new(asset_path.clone(), &meta_bytes, &asset_bytes);
+        let cached_asset = match &cache {
+            Some(cache) => self
+                .get_cached_processed_asset(cache, &cache_key)
+                .await
+                .filter(|cached_asset| cached_asset.processed_info.hash == new_hash),
+            None => None,
+        };
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -923,7 +1162,19 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
+        let is_cached = cached_asset.is_some();
+        if let Some(cached_asset) = cached_asset {
+            debug!("Using cached processed asset for {}", asset_path);
+            processed_writer
+                .write_bytes(path, &cached_asset.asset_bytes)
+                .await
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &cached_asset.meta_bytes)
+                .await
+                .map_err(writer_err)?;
+            new_processed_info = cached_asset.processed_info;
+        } else if let Some(processor) = processor {
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
             let mut processed_meta = {
                 let mut context =
@@ -955,6 +1206,16 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            if let Some(cache) = cache.as_ref().filter(|_| !self.data.dry_run) {
+                self.insert_cached_processed_asset(
+                    cache,
+                    source,
+                    &cache_key,
+                    meta_bytes,
+                    new_processed_info.clone(),
+                )
+                .await;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -969,11 +1230,100 @@ impl AssetProcessor {
         }
         self.log_end_processing(asset_path).await;
 
-        Ok(ProcessResult::Processed(new_processed_info))
+        Ok(if is_cached {
+            ProcessResult::Cached(new_processed_info)
+        } else {
+            ProcessResult::Processed(new_processed_info)
+        })
+    }
+
+    /// Retrieves the processed version of the asset identified by `key` from the `cache`, if it exists and all of its
+    /// process dependencies are up to date. Cache failures are not fatal: they are logged and treated as a cache miss.
+    async fn get_cached_processed_asset(
+        &self,
+        cache: &ProcessedAssetCache,
+        key: &ProcessedAssetCacheKey,
+    ) -> Option<CachedProcessedAsset> {
+        let dependencies = match cache.get_dependencies(key).await {
+            Ok(dependencies) => dependencies?,
+            Err(err) => {
+                warn!(
+                    "Failed to read processed asset cache entry for {}: {err}",
+                    key.path
+                );
+                return None;
+            }
+        };
+        let mut dependency_hashes = Vec::with_capacity(dependencies.len());
+        for dependency in dependencies {
+            // The dependency must be processed first, otherwise its `full_hash` might be stale.
+            if self.data.wait_until_processed(dependency.clone()).await != ProcessStatus::Processed
+            {
+                return None;
+            }
+            let infos = self.data.asset_infos.read().await;
+            let full_hash = infos
+                .get(&dependency)
+                .and_then(|info| info.processed_info.as_ref())
+                .map(|info| info.full_hash)?;
+            dependency_hashes.push(full_hash);
+        }
+        match cache.get(key, dependency_hashes.into_iter()).await {
+            Ok(cached_asset) => cached_asset,
+            Err(err) => {
+                warn!(
+                    "Failed to read processed asset cache entry for {}: {err}",
+                    key.path
+                );
+                None
+            }
+        }
+    }
+
+    /// Stores the freshly processed asset identified by `key` in the `cache`. The processed asset bytes are read back
+    /// from the processed [`AssetSource`].
+    async fn insert_cached_processed_asset(
+        &self,
+        cache: &ProcessedAssetCache,
+        source: &AssetSource,
+        key: &ProcessedAssetCacheKey,
+        meta_bytes: Vec<u8>,
+        processed_info: ProcessedInfo,
+    ) {
+        let Ok(processed_reader) = source.processed_reader() else {
+            return;
+        };
+        let mut asset_bytes = Vec::new();
+        let read_result = match processed_reader.read(key.path.path()).await {
+            Ok(mut reader) => reader
+                .read_to_end(&mut asset_bytes)
+                .await
+                .map_err(|err| AssetReaderError::Io(err.into())),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = read_result {
+            warn!(
+                "Failed to read processed asset {} in order to cache it: {err}",
+                key.path
+            );
+            return;
+        }
+        let cached_asset = CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+            processed_info,
+        };
+        if let Err(err) = cache.insert(key, &cached_asset).await {
+            warn!(
+                "Failed to write processed asset cache entry for {}: {err}",
+                key.path
+            );
+        }
     }
 
     async fn validate_transaction_log_and_recover(&self) {
-        if let Err(err) = ProcessorTransactionLog::validate().await {
+        let log_path = self.data.log_path.read().clone();
+        if let Err(err) = ProcessorTransactionLog::validate(&log_path).await {
             let state_is_valid = match err {
                 ValidateLogError::ReadLogError(err) => {
                     error!("Failed to read processor log file. Processed assets cannot be validated so they must be re-generated {err}");
@@ -1051,7 +1401,7 @@ impl AssetProcessor {
             }
         }
         let mut log = self.data.log.write().await;
-        *log = match ProcessorTransactionLog::new().await {
+        *log = match ProcessorTransactionLog::new(&log_path).await {
             Ok(log) => Some(log),
             Err(err) => panic!("Failed to initialize asset processor log. This cannot be recovered. Try restarting. If that doesn't work, try deleting processed asset folder. {}", err),
         };
@@ -1076,9 +1426,14 @@ impl AssetProcessorData {
             initialized_receiver,
             state: async_lock::RwLock::new(ProcessorState::Initializing),
             log: Default::default(),
+            log_path: RwLock::new(ProcessorTransactionLog::default_path()),
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
+            rewrite_migrated_meta: AtomicBool::new(false),
+            dry_run: false,
+            report: Default::default(),
         }
     }
 
@@ -1144,6 +1499,11 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
+
+    fn settings_migrations() -> crate::meta::SettingsMigrations {
+        T::settings_migrations()
+    }
 
     fn process(
         &self,
@@ -1156,6 +1516,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1172,6 +1533,8 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
 #[derive(Debug, Clone)]
 pub enum ProcessResult {
     Processed(ProcessedInfo),
+    /// The processed asset was copied from the [`ProcessedAssetCache`] instead of being processed.
+    Cached(ProcessedInfo),
     SkippedNotChanged,
     Ignored,
 }
@@ -1288,7 +1651,9 @@ impl ProcessorAssetInfos {
         result: Result<ProcessResult, ProcessError>,
     ) {
         match result {
-            Ok(ProcessResult::Processed(processed_info)) => {
+            Ok(
+                ProcessResult::Processed(processed_info) | ProcessResult::Cached(processed_info),
+            ) => {
                 debug!("Finished processing \"{}\"", asset_path);
                 // clean up old dependents
                 let old_processed_info = self
@@ -1474,3 +1839,240 @@ pub enum InitializeError {
     #[error("Failed to validate asset log: {0}")]
     ValidateLogError(#[from] ValidateLogError),
 }
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "multi_threaded"))]
+mod tests {
+    use super::{
+        AssetProcessor, AssetReportOutcome, CachedProcessedAsset, Process, ProcessContext,
+        ProcessError, ProcessResult, ProcessedAssetCacheKey,
+    };
+    use crate::{
+        io::{AssetSourceId, Writer},
+        meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessedInfo},
+        tests::CoolTextLoader,
+        AssetMode, AssetPath, AssetPlugin,
+    };
+    use alloc::{borrow::ToOwned, format, string::ToString, vec, vec::Vec};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_tasks::block_on;
+    use futures_lite::AsyncWriteExt;
+    use std::path::{Path, PathBuf};
+
+    /// Uppercases text assets, and fails to process assets containing "broken".
+    struct UppercaseProcess;
+
+    impl Process for UppercaseProcess {
+        type Settings = ();
+        type OutputLoader = CoolTextLoader;
+
+        async fn process(
+            &self,
+            context: &mut ProcessContext<'_>,
+            _meta: AssetMeta<(), Self>,
+            writer: &mut Writer,
+        ) -> Result<(), ProcessError> {
+            let text = core::str::from_utf8(context.asset_bytes())
+                .map_err(|err| ProcessError::AssetTransformError(err.into()))?;
+            if text.contains("broken") {
+                return Err(ProcessError::AssetTransformError("broken asset".into()));
+            }
+            writer
+                .write_all(text.to_uppercase().as_bytes())
+                .await
+                .map_err(|err| ProcessError::AssetTransformError(err.into()))?;
+            Ok(())
+        }
+    }
+
+    /// Creates an empty temporary folder unique to `name` and to this test run.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bevy_asset_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Returns the paths of all files in `dir` (recursively), relative to `root`.
+    fn file_paths(root: &Path, dir: &Path) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                paths.extend(file_paths(root, &path));
+            } else {
+                paths.push(path.strip_prefix(root).unwrap().to_owned());
+            }
+        }
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn dry_run_reports_assets_without_writing() {
+        let dir = temp_dir("dry_run");
+        let assets = dir.join("assets");
+        std::fs::create_dir_all(&assets).unwrap();
+        std::fs::write(assets.join("a.txt"), "hello").unwrap();
+        std::fs::write(assets.join("b.txt"), "unused").unwrap();
+        std::fs::write(assets.join("broken.txt"), "broken").unwrap();
+        std::fs::write(assets.join("notes.xyz"), "no loader").unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: assets.to_str().unwrap().to_string(),
+                processed_file_path: dir.join("imported_assets").to_str().unwrap().to_string(),
+                mode: AssetMode::Processed,
+                dry_run_processor: true,
+                ..Default::default()
+            },
+        ));
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        assert!(processor.is_dry_run());
+        processor.register_processor(UppercaseProcess);
+        processor.set_default_processor::<UppercaseProcess>("txt");
+
+        let report = processor.check().with_roots([AssetPath::from("a.txt")]);
+        let outcomes = report
+            .assets
+            .iter()
+            .map(|asset| (asset.path.to_string(), asset.outcome.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes.len(), 4);
+        assert_eq!(
+            outcomes[0],
+            ("a.txt".to_string(), AssetReportOutcome::Processed)
+        );
+        assert_eq!(
+            outcomes[1],
+            ("b.txt".to_string(), AssetReportOutcome::Processed)
+        );
+        assert!(
+            matches!(&outcomes[2], (path, AssetReportOutcome::Failed(err))
+            if path == "broken.txt" && err.contains("broken asset"))
+        );
+        assert_eq!(
+            outcomes[3],
+            ("notes.xyz".to_string(), AssetReportOutcome::MissingLoader)
+        );
+        assert!(report.has_errors());
+        assert_eq!(
+            report
+                .unused
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["b.txt", "broken.txt", "notes.xyz"]
+        );
+
+        // Nothing is written: no meta files next to the source assets, no processed assets and no transaction log.
+        assert_eq!(
+            file_paths(&dir, &dir),
+            vec![
+                Path::new("assets/a.txt"),
+                Path::new("assets/b.txt"),
+                Path::new("assets/broken.txt"),
+                Path::new("assets/notes.xyz"),
+            ]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Creates a processor for the assets in `dir`, which writes its processed assets to the `processed` folder of `dir`, its
+    /// transaction log next to it, and shares the processed asset cache in `dir/cache`.
+    fn cached_processor(dir: &Path, processed: &str) -> AssetProcessor {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: dir.join("assets").to_str().unwrap().to_string(),
+                processed_file_path: dir.join(processed).to_str().unwrap().to_string(),
+                processed_cache_path: Some(dir.join("cache").to_str().unwrap().to_string()),
+                mode: AssetMode::Processed,
+                ..Default::default()
+            },
+        ));
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        processor.register_processor(UppercaseProcess);
+        processor.set_default_processor::<UppercaseProcess>("txt");
+        processor.set_log_path(dir.join(format!("{processed}.log")));
+        block_on(processor.initialize()).unwrap();
+        processor
+    }
+
+    fn process(processor: &AssetProcessor, path: &'static str) -> ProcessResult {
+        let source = processor.get_source(AssetSourceId::Default).unwrap();
+        block_on(processor.process_asset_internal(source, &AssetPath::from(path))).unwrap()
+    }
+
+    #[test]
+    fn processed_assets_are_shared_through_the_cache() {
+        let dir = temp_dir("cache");
+        let assets = dir.join("assets");
+        std::fs::create_dir_all(&assets).unwrap();
+        std::fs::write(assets.join("a.txt"), "hello").unwrap();
+        std::fs::write(assets.join("b.txt"), "world").unwrap();
+
+        // The cache is empty, so the asset is processed and stored in the cache.
+        let first = cached_processor(&dir, "first");
+        let ProcessResult::Processed(processed_info) = process(&first, "a.txt") else {
+            panic!("expected a.txt to be processed");
+        };
+
+        // Another processor (ex: on another machine) reuses the processed asset.
+        let second = cached_processor(&dir, "second");
+        let ProcessResult::Cached(cached_info) = process(&second, "a.txt") else {
+            panic!("expected a.txt to be retrieved from the cache");
+        };
+        assert_eq!(cached_info.full_hash, processed_info.full_hash);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("second/a.txt")).unwrap(),
+            "HELLO"
+        );
+
+        // Changing the source asset changes its cache key, so it is processed again.
+        std::fs::write(assets.join("a.txt"), "changed").unwrap();
+        assert!(matches!(
+            process(&second, "a.txt"),
+            ProcessResult::Processed(_)
+        ));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("second/a.txt")).unwrap(),
+            "CHANGED"
+        );
+
+        // A cache entry whose hash doesn't match the source asset is ignored.
+        let cache = second.cache().unwrap();
+        let meta_bytes = second
+            .get_default_processor("txt")
+            .unwrap()
+            .default_meta()
+            .serialize();
+        let key = ProcessedAssetCacheKey::new(AssetPath::from("b.txt"), &meta_bytes, b"world");
+        let mut stale_meta = AssetMeta::<CoolTextLoader, ()>::new(AssetAction::Load {
+            loader: core::any::type_name::<CoolTextLoader>().to_string(),
+            settings: (),
+        });
+        stale_meta.processed_info = Some(ProcessedInfo::default());
+        block_on(cache.insert(
+            &key,
+            &CachedProcessedAsset {
+                asset_bytes: b"STALE".to_vec(),
+                meta_bytes: stale_meta.serialize(),
+                processed_info: ProcessedInfo::default(),
+            },
+        ))
+        .unwrap();
+        assert!(matches!(
+            process(&second, "b.txt"),
+            ProcessResult::Processed(_)
+        ));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("second/b.txt")).unwrap(),
+            "WORLD"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,7 +3,10 @@ use crate::{
         AssetReaderError, AssetWriterError, MissingAssetWriterError,
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, SliceReader, Writer,
     },
-    meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
+    meta::{
+        AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings,
+        SettingsMigrations,
+    },
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
@@ -40,6 +43,16 @@ pub trait Process: Send + Sync + Sized + 'static {
     ) -> impl ConditionalSendFuture<
         Output = Result<<Self::OutputLoader as AssetLoader>::Settings, ProcessError>,
     >;
+
+    /// The current version of [`Process::Settings`]. This is stored in the [`AssetMeta`] of assets processed by this processor.
+    /// Bump it whenever [`Process::Settings`] changes in a way that breaks existing `.meta` files, and add a matching
+    /// migration to [`Process::settings_migrations`].
+    const SETTINGS_VERSION: u32 = 0;
+
+    /// Returns the [`SettingsMigrations`] used to upgrade `.meta` files written for older versions of [`Process::Settings`].
+    fn settings_migrations() -> SettingsMigrations {
+        SettingsMigrations::default()
+    }
 }
 
 /// A flexible [`Process`] implementation that loads the source [`Asset`] using the `L` [`AssetLoader`], then transforms
@@ -245,7 +258,7 @@ impl<P: Process> ErasedProcessor for P {
     }
 
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta: AssetMeta<(), P> = ron::de::from_bytes(meta)?;
+        let meta = AssetMeta::<(), P>::deserialize(meta)?;
         Ok(Box::new(meta))
     }
 
@@ -0,0 +1,266 @@
+use crate::{
+    io::AssetReaderError,
+    processor::{ProcessError, ProcessResult},
+    AssetPath,
+};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use core::time::Duration;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+/// A machine-readable summary of what the [`AssetProcessor`] did with every asset it encountered.
+///
+/// This is primarily intended for automated "asset checks" (ex: in CI), where broken assets should be detected without
+/// launching the app. See [`AssetProcessor::check`].
+///
+/// [`AssetProcessor`]: crate::processor::AssetProcessor
+/// [`AssetProcessor::check`]: crate::processor::AssetProcessor::check
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProcessorReport {
+    /// The report of every asset encountered by the processor, sorted by path.
+    pub assets: Vec<AssetReport>,
+    /// Assets that are not reachable from the roots passed to [`ProcessorReport::with_roots`], through process dependencies.
+    /// This is empty if no roots were provided.
+    pub unused: Vec<AssetPath<'static>>,
+}
+
+/// The report of a single asset in a [`ProcessorReport`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReport {
+    /// The path of the asset.
+    pub path: AssetPath<'static>,
+    /// What happened to the asset.
+    pub outcome: AssetReportOutcome,
+    /// How long it took to process the asset (including checking if it changed).
+    pub duration: Duration,
+    /// The assets that were used to process this asset.
+    pub process_dependencies: Vec<AssetPath<'static>>,
+}
+
+/// The outcome of processing a single asset. See [`AssetReport`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AssetReportOutcome {
+    /// The asset was processed (or loaded as-is) successfully.
+    Processed,
+    /// The processed asset was copied from the [`ProcessedAssetCache`](crate::processor::ProcessedAssetCache).
+    Cached,
+    /// The asset was not processed because it has not changed since it was last processed.
+    SkippedNotChanged,
+    /// The asset is configured to be ignored by its meta file.
+    Ignored,
+    /// No [`AssetLoader`](crate::AssetLoader) exists for this asset, so it can't be loaded.
+    MissingLoader,
+    /// Processing the asset failed with the given error.
+    Failed(String),
+}
+
+impl AssetReportOutcome {
+    /// Returns the outcome for the given processing `result`, or `None` if the source asset does not exist.
+    pub(crate) fn new(result: &Result<ProcessResult, ProcessError>) -> Option<Self> {
+        Some(match result {
+            Ok(ProcessResult::Processed(_)) => Self::Processed,
+            Ok(ProcessResult::Cached(_)) => Self::Cached,
+            Ok(ProcessResult::SkippedNotChanged) => Self::SkippedNotChanged,
+            Ok(ProcessResult::Ignored) | Err(ProcessError::ExtensionRequired) => Self::Ignored,
+            Err(
+                ProcessError::MissingAssetLoaderForExtension(_)
+                | ProcessError::MissingAssetLoaderForTypeName(_),
+            ) => Self::MissingLoader,
+            Err(ProcessError::AssetReaderError {
+                err: AssetReaderError::NotFound(_),
+                ..
+            }) => return None,
+            Err(err) => Self::Failed(err.to_string()),
+        })
+    }
+
+    /// Returns `true` if this outcome should be considered an error.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::MissingLoader | Self::Failed(_))
+    }
+}
+
+impl ProcessorReport {
+    /// Computes [`ProcessorReport::unused`]: every asset that is not one of the given `roots`, and is not (transitively) a
+    /// process dependency of one of the `roots`.
+    ///
+    /// Only process dependencies are known to the processor. Assets that are only loaded at runtime by other assets (or by code)
+    /// should be included in `roots`.
+    pub fn with_roots(mut self, roots: impl IntoIterator<Item = AssetPath<'static>>) -> Self {
+        let mut used = <HashSet<AssetPath<'static>>>::default();
+        {
+            let dependencies: HashMap<_, _> = self
+                .assets
+                .iter()
+                .map(|asset| (&asset.path, &asset.process_dependencies))
+                .collect();
+            let mut queue: VecDeque<_> = roots.into_iter().collect();
+            while let Some(path) = queue.pop_front() {
+                if used.contains(&path) {
+                    continue;
+                }
+                if let Some(asset_dependencies) = dependencies.get(&path) {
+                    queue.extend(asset_dependencies.iter().cloned());
+                }
+                used.insert(path);
+            }
+        }
+        self.unused = self
+            .assets
+            .iter()
+            .filter(|asset| asset.outcome != AssetReportOutcome::Ignored)
+            .map(|asset| asset.path.clone())
+            .filter(|path| !used.contains(path))
+            .collect();
+        self
+    }
+
+    /// Returns the assets that failed to process.
+    pub fn failed(&self) -> impl Iterator<Item = &AssetReport> {
+        self.assets
+            .iter()
+            .filter(|asset| matches!(asset.outcome, AssetReportOutcome::Failed(_)))
+    }
+
+    /// Returns the assets that don't have an [`AssetLoader`](crate::AssetLoader).
+    pub fn missing_loaders(&self) -> impl Iterator<Item = &AssetReport> {
+        self.assets
+            .iter()
+            .filter(|asset| asset.outcome == AssetReportOutcome::MissingLoader)
+    }
+
+    /// Returns `true` if any asset failed to process or is missing a loader.
+    pub fn has_errors(&self) -> bool {
+        self.assets.iter().any(|asset| asset.outcome.is_error())
+    }
+
+    /// Returns the total time spent processing assets. Assets are processed in parallel, so this is usually longer than
+    /// the time it took to process all assets.
+    pub fn total_duration(&self) -> Duration {
+        self.assets.iter().map(|asset| asset.duration).sum()
+    }
+
+    /// Serializes this report to RON.
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, PrettyConfig::default())
+            .expect("type is convertible to ron")
+    }
+}
+
+/// Collects [`AssetReport`]s while the [`AssetProcessor`](crate::processor::AssetProcessor) runs.
+#[derive(Default, Debug)]
+pub(crate) struct ProcessorReportCollector {
+    assets: HashMap<AssetPath<'static>, AssetReport>,
+    /// Assets without a meta file that were processed with an "ignore" meta because no loader exists for them.
+    missing_loaders: HashSet<AssetPath<'static>>,
+}
+
+impl ProcessorReportCollector {
+    pub(crate) fn insert(&mut self, report: AssetReport) {
+        self.assets.insert(report.path.clone(), report);
+    }
+
+    pub(crate) fn remove(&mut self, path: &AssetPath<'static>) -> Option<AssetReport> {
+        self.missing_loaders.remove(path);
+        self.assets.remove(path)
+    }
+
+    pub(crate) fn set_missing_loader(&mut self, path: AssetPath<'static>, missing: bool) {
+        if missing {
+            self.missing_loaders.insert(path);
+        } else {
+            self.missing_loaders.remove(&path);
+        }
+    }
+
+    pub(crate) fn is_missing_loader(&self, path: &AssetPath<'static>) -> bool {
+        self.missing_loaders.contains(path)
+    }
+
+    pub(crate) fn report(&self) -> ProcessorReport {
+        let mut assets: Vec<_> = self.assets.values().cloned().collect();
+        assets.sort_by_cached_key(|asset| asset.path.to_string());
+        ProcessorReport {
+            assets,
+            unused: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetReport, AssetReportOutcome, ProcessorReportCollector};
+    use crate::AssetPath;
+    use alloc::{string::ToString, vec, vec::Vec};
+    use core::time::Duration;
+
+    fn report(
+        path: &'static str,
+        outcome: AssetReportOutcome,
+        deps: &[&'static str],
+    ) -> AssetReport {
+        AssetReport {
+            path: AssetPath::from(path),
+            outcome,
+            duration: Duration::from_millis(10),
+            process_dependencies: deps.iter().map(|dep| AssetPath::from(*dep)).collect(),
+        }
+    }
+
+    #[test]
+    fn report_summary() {
+        let mut collector = ProcessorReportCollector::default();
+        collector.insert(report(
+            "level.scn",
+            AssetReportOutcome::Processed,
+            &["a.mat"],
+        ));
+        collector.insert(report("a.mat", AssetReportOutcome::Processed, &["a.png"]));
+        collector.insert(report("a.png", AssetReportOutcome::SkippedNotChanged, &[]));
+        collector.insert(report("old.png", AssetReportOutcome::Processed, &[]));
+        collector.insert(report("notes.txt", AssetReportOutcome::Ignored, &[]));
+        collector.insert(report("b.xyz", AssetReportOutcome::MissingLoader, &[]));
+        collector.insert(report(
+            "broken.png",
+            AssetReportOutcome::Failed("bad header".to_string()),
+            &[],
+        ));
+        collector.remove(&AssetPath::from("old.png"));
+        collector.insert(report("old.png", AssetReportOutcome::Processed, &[]));
+
+        let report = collector
+            .report()
+            .with_roots([AssetPath::from("level.scn")]);
+        assert_eq!(report.assets.len(), 7);
+        assert!(report.has_errors());
+        assert_eq!(
+            report
+                .failed()
+                .map(|a| a.path.to_string())
+                .collect::<Vec<_>>(),
+            vec!["broken.png"]
+        );
+        assert_eq!(
+            report
+                .missing_loaders()
+                .map(|a| a.path.to_string())
+                .collect::<Vec<_>>(),
+            vec!["b.xyz"]
+        );
+        assert_eq!(
+            report
+                .unused
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["b.xyz", "broken.png", "old.png"]
+        );
+        assert_eq!(report.total_duration(), Duration::from_millis(70));
+        assert!(report.to_ron().contains("MissingLoader"));
+    }
+}
@@ -1,6 +1,12 @@
 use crate::{
-    io::Writer, meta::Settings, transformer::TransformedAsset, Asset, AssetLoader,
-    ErasedLoadedAsset, Handle, LabeledAsset, UntypedHandle,
+    io::{
+        AssetReaderError, AssetWriterError, MissingAssetSourceError, MissingAssetWriterError,
+        Writer,
+    },
+    meta::Settings,
+    transformer::TransformedAsset,
+    Asset, AssetLoader, AssetPath, ErasedLoadedAsset, Handle, LabeledAsset, UntypedAssetId,
+    UntypedHandle,
 };
 use alloc::boxed::Box;
 use atomicow::CowArc;
@@ -8,6 +14,7 @@ use bevy_platform::collections::HashMap;
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::{borrow::Borrow, hash::Hash, ops::Deref};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Saves an [`Asset`] of a given [`AssetSaver::Asset`] type. [`AssetSaver::OutputLoader`] will then be used to load the saved asset
 /// in the final deployed application. The saver should produce asset bytes in a format that [`AssetSaver::OutputLoader`] can read.
@@ -89,7 +96,18 @@ impl<'a, A: Asset> Deref for SavedAsset<'a, A> {
     }
 }
 
+/// Used by [`SavedAsset::from_asset`] for assets that don't have any labeled assets.
+static NO_LABELED_ASSETS: HashMap<CowArc<'static, str>, LabeledAsset> = HashMap::new();
+
 impl<'a, A: Asset> SavedAsset<'a, A> {
+    /// Creates a new [`SavedAsset`] from a runtime `asset` value, without any labeled assets.
+    pub fn from_asset(value: &'a A) -> Self {
+        Self {
+            value,
+            labeled_assets: &NO_LABELED_ASSETS,
+        }
+    }
+
     /// Creates a new [`SavedAsset`] from `asset` if its internal value matches `A`.
     pub fn from_loaded(asset: &'a ErasedLoadedAsset) -> Option<Self> {
         let value = asset.value.downcast_ref::<A>()?;
@@ -165,3 +183,33 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         self.labeled_assets.keys().map(|s| &**s)
     }
 }
+
+/// An error that occurs when saving an asset with [`AssetServer::save_asset`](crate::AssetServer::save_asset).
+#[derive(Error, Debug)]
+pub enum SaveAssetError {
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    #[error("cannot save labeled asset path {0}: labeled assets are saved as part of the asset that contains them")]
+    LabeledPath(AssetPath<'static>),
+    #[error("asset {0:?} does not exist")]
+    MissingAsset(UntypedAssetId),
+    #[error("asset {0:?} does not have a path to save to")]
+    MissingAssetPath(UntypedAssetId),
+    #[error("failed to write asset {path}: {err}")]
+    AssetWriterError {
+        path: AssetPath<'static>,
+        err: AssetWriterError,
+    },
+    #[error("failed to read the existing meta file of asset {path}: {err}")]
+    AssetReaderError {
+        path: AssetPath<'static>,
+        err: AssetReaderError,
+    },
+    #[error("failed to save asset {path}: {err}")]
+    AssetSaverError {
+        path: AssetPath<'static>,
+        err: Box<dyn core::error::Error + Send + Sync + 'static>,
+    },
+}
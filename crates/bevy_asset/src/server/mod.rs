@@ -10,13 +10,14 @@ use crate::{
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
-        loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
-        MetaTransform, Settings,
+        loader_settings_meta_transform, AssetAction, AssetActionMinimal, AssetMeta, AssetMetaDyn,
+        AssetMetaMinimal, MetaTransform, Settings,
     },
     path::AssetPath,
-    Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
-    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UnapprovedPathMode,
-    UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
+    saver::{AssetSaver, SaveAssetError, SavedAsset},
+    Asset, AssetCollection, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent,
+    AssetMetaCheck, Assets, DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset,
+    UnapprovedPathMode, UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
 };
 use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
 use alloc::{
@@ -27,7 +28,7 @@ use alloc::{
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::HashSet;
-use bevy_tasks::IoTaskPool;
+use bevy_tasks::{IoTaskPool, Task};
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
@@ -955,6 +956,14 @@ impl AssetServer {
         handle.typed_debug_checked()
     }
 
+    /// Starts loading every asset of the given [`AssetCollection`], and returns a handle to the collection. Use
+    /// [`AssetServer::recursive_dependency_load_state`] on the returned handle to get the combined load state of every asset
+    /// in the collection.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_collection<C: AssetCollection>(&self) -> Handle<C> {
+        self.add(C::load(self))
+    }
+
     /// Loads all assets from the specified folder recursively. The [`LoadedFolder`] asset (when it loads) will
     /// contain handles to all assets in the folder. You can wait for all assets to load by checking the [`LoadedFolder`]'s
     /// [`RecursiveDependencyLoadState`].
@@ -1635,6 +1644,110 @@ impl AssetServer {
 
         Ok(())
     }
+
+    /// Saves `asset` to `path` using `saver`, through the [`AssetWriter`](crate::io::AssetWriter) of the [`AssetSource`] of `path`.
+    ///
+    /// The `.meta` file of the asset is updated to load the saved asset with the [`AssetSaver::OutputLoader`] and the settings
+    /// returned by the `saver`. If the asset is currently configured to be processed, the existing `.meta` file is kept
+    /// (the asset processor will reprocess the saved asset).
+    ///
+    /// If the [`AssetSource`] watches for changes, the saved asset will be hot-reloaded.
+    pub async fn save_asset<'a, S: AssetSaver>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        asset: SavedAsset<'_, S::Asset>,
+        saver: &S,
+        settings: &S::Settings,
+    ) -> Result<(), SaveAssetError> {
+        let path = path.into().into_owned();
+        if path.label().is_some() {
+            return Err(SaveAssetError::LabeledPath(path));
+        }
+        let source = self.get_source(path.source())?;
+        let writer = source.writer()?;
+
+        // Save into memory first, so nothing is written if saving fails.
+        let mut bytes = Vec::new();
+        let loader_settings = saver
+            .save(&mut bytes, asset, settings)
+            .await
+            .map_err(|err| SaveAssetError::AssetSaverError {
+                path: path.clone(),
+                err: err.into(),
+            })?;
+
+        let keep_meta = match source.reader().read_meta_bytes(path.path()).await {
+            Ok(meta_bytes) => matches!(
+                ron::de::from_bytes::<AssetMetaMinimal>(&meta_bytes),
+                Ok(AssetMetaMinimal {
+                    asset: AssetActionMinimal::Process { .. },
+                    ..
+                })
+            ),
+            Err(AssetReaderError::NotFound(_)) => false,
+            Err(err) => return Err(SaveAssetError::AssetReaderError { path, err }),
+        };
+        let writer_err = |err| SaveAssetError::AssetWriterError {
+            path: path.clone(),
+            err,
+        };
+        if !keep_meta {
+            let meta = AssetMeta::<S::OutputLoader, ()>::new(AssetAction::Load {
+                loader: core::any::type_name::<S::OutputLoader>().to_string(),
+                settings: loader_settings,
+            });
+            writer
+                .write_meta_bytes(path.path(), &meta.serialize())
+                .await
+                .map_err(writer_err)?;
+        }
+        writer
+            .write_bytes(path.path(), &bytes)
+            .await
+            .map_err(writer_err)?;
+        Ok(())
+    }
+
+    /// Saves `asset` to `path` using `saver` in a background task. See [`AssetServer::save_asset`] for details.
+    pub fn save<'a, S: AssetSaver>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        asset: S::Asset,
+        saver: S,
+        settings: S::Settings,
+    ) -> Task<Result<(), SaveAssetError>> {
+        let server = self.clone();
+        let path = path.into().into_owned();
+        IoTaskPool::get().spawn(async move {
+            server
+                .save_asset(path, SavedAsset::from_asset(&asset), &saver, &settings)
+                .await
+        })
+    }
+
+    /// Saves the asset with the given `id` in `assets` back to the path it was loaded from, using `saver` in a background task.
+    /// See [`AssetServer::save_asset`] for details.
+    ///
+    /// To save the asset to a new path, use [`AssetServer::save`] instead.
+    pub fn save_to_source_path<S: AssetSaver>(
+        &self,
+        id: impl Into<AssetId<S::Asset>>,
+        assets: &Assets<S::Asset>,
+        saver: S,
+        settings: S::Settings,
+    ) -> Result<Task<Result<(), SaveAssetError>>, SaveAssetError>
+    where
+        S::Asset: Clone,
+    {
+        let id = id.into();
+        let path = self
+            .get_path(id)
+            .ok_or(SaveAssetError::MissingAssetPath(id.untyped()))?;
+        let asset = assets
+            .get(id)
+            .ok_or(SaveAssetError::MissingAsset(id.untyped()))?;
+        Ok(self.save(path, asset.clone(), saver, settings))
+    }
 }
 
 /// A system that manages internal [`AssetServer`] events, such as finalizing asset loads.
@@ -0,0 +1,577 @@
+//! Deterministic recording and replay of the input of an app.
+//!
+//! The [`InputRecordingPlugin`] records every input message read by `InputPlugin`,
+//! along with the duration of each frame, and saves it to a [`ron`] file periodically and when the app exits.
+//! The [`InputReplayPlugin`] plays such a file back, frame by frame, with the same frame durations,
+//! so that bug reports can come with a reproducible input log.
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_dev_tools::input_recording::{InputRecordingPlugin, InputReplayPlugin};
+//! let mut app = App::new();
+//! if let Ok(path) = std::env::var("REPLAY_INPUT") {
+//!     app.add_plugins(InputReplayPlugin::from_file(path).unwrap());
+//! } else {
+//!     app.add_plugins(InputRecordingPlugin::new("input.ron"));
+//! }
+//! ```
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{
+        GamepadConnectionEvent, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent,
+        RawGamepadEvent,
+    },
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::TouchInput,
+    InputSystems,
+};
+use bevy_platform::collections::HashMap;
+use bevy_time::{Real, Time, TimeUpdateStrategy};
+use bevy_window::{CursorMoved, PrimaryWindow, WindowEvent};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{self, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// An input message recorded by the [`InputRecordingPlugin`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedInput {
+    /// A [`KeyboardInput`] message.
+    Keyboard(KeyboardInput),
+    /// A [`MouseButtonInput`] message.
+    MouseButton(MouseButtonInput),
+    /// A [`MouseMotion`] message.
+    MouseMotion(MouseMotion),
+    /// A [`MouseWheel`] message.
+    MouseWheel(MouseWheel),
+    /// A [`TouchInput`] message.
+    Touch(TouchInput),
+    /// A [`RawGamepadEvent`] message.
+    Gamepad(RawGamepadEvent),
+    /// A [`CursorMoved`] message.
+    CursorMoved(CursorMoved),
+}
+
+/// The inputs of a single frame of an [`InputRecording`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    /// The [`Real`] time elapsed since the previous frame.
+    pub delta: Duration,
+    /// The inputs of the frame, in the order they were received.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<RecordedInput>,
+}
+
+/// The inputs of an app, frame by frame.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputRecording {
+    /// The recorded frames, indexed by their frame number since the start of the recording.
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// An error occurring while loading or saving an [`InputRecording`].
+#[derive(Error, Debug)]
+pub enum InputRecordingError {
+    /// The recording file couldn't be read or written.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The recording couldn't be serialized.
+    #[error(transparent)]
+    Serialize(#[from] ron::Error),
+    /// The recording file couldn't be deserialized.
+    #[error(transparent)]
+    Deserialize(#[from] ron::de::SpannedError),
+}
+
+impl InputRecording {
+    /// Loads a recording from the [`ron`] file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InputRecordingError> {
+        Ok(ron::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Saves this recording to a compact [`ron`] file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), InputRecordingError> {
+        fs::write(path, ron::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// The end of a compact [`ron`] [`InputRecording`], after its last frame.
+const RECORDING_END: &str = "])";
+
+/// A plugin recording the input of the app into the [`InputRecorder`] resource,
+/// and saving it to a file periodically and when the app exits.
+///
+/// Keyboard, mouse, touch and cursor inputs are recorded from the [`WindowEvent`] messages written by the
+/// windowing backend, which keep the order in which they were received. Gamepad inputs follow them.
+pub struct InputRecordingPlugin {
+    /// The path of the file the recording is saved to.
+    pub path: PathBuf,
+    /// How often the recording is saved while the app runs, so that it isn't lost if the app crashes.
+    /// If `None`, the recording is only saved when the app exits.
+    pub save_interval: Option<Duration>,
+}
+
+impl InputRecordingPlugin {
+    /// Creates a plugin saving the recording to `path` every 10 seconds and when the app exits.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            save_interval: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WindowEvent>()
+            .insert_resource(InputRecorder {
+                path: Some(self.path.clone()),
+                save_interval: self.save_interval,
+                ..Default::default()
+            })
+            .add_systems(PreUpdate, record_inputs.after(InputSystems))
+            .add_systems(Last, save_recording);
+    }
+}
+
+/// The recording in progress of the [`InputRecordingPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct InputRecorder {
+    /// The frames recorded so far.
+    pub recording: InputRecording,
+    /// The path the recording is saved to, if any.
+    pub path: Option<PathBuf>,
+    /// How often the recording is saved to [`InputRecorder::path`] while the app runs.
+    /// If `None`, the recording is only saved when the app exits.
+    pub save_interval: Option<Duration>,
+    /// Whether the recording is paused, in which case frames aren't recorded.
+    pub paused: bool,
+    /// The [`Real`] time recorded since the recording was last saved.
+    since_save: Duration,
+    /// The number of frames already written to [`InputRecorder::path`].
+    saved_frames: usize,
+}
+
+impl InputRecorder {
+    /// Saves the recording to [`InputRecorder::path`], if any.
+    ///
+    /// Only the frames recorded since the previous save are serialized and appended to the file,
+    /// so that saving periodically doesn't get slower as the recording grows.
+    fn save(&mut self) -> Result<(), InputRecordingError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let frames = &self.recording.frames;
+        if self.saved_frames == 0 || self.saved_frames > frames.len() {
+            self.recording.save(path)?;
+        } else if self.saved_frames < frames.len() {
+            let mut content = String::new();
+            for frame in &frames[self.saved_frames..] {
+                content.push(',');
+                content.push_str(&ron::to_string(frame)?);
+            }
+            content.push_str(RECORDING_END);
+            let mut file = fs::OpenOptions::new().write(true).open(path)?;
+            file.seek(SeekFrom::End(-(RECORDING_END.len() as i64)))?;
+            file.write_all(content.as_bytes())?;
+        }
+        self.saved_frames = frames.len();
+        Ok(())
+    }
+}
+
+/// Records the inputs of the current frame into the [`InputRecorder`].
+pub fn record_inputs(
+    mut recorder: ResMut<InputRecorder>,
+    time: Res<Time<Real>>,
+    mut window_events: EventReader<WindowEvent>,
+    mut gamepads: EventReader<RawGamepadEvent>,
+) {
+    let inputs = window_events
+        .read()
+        .filter_map(|event| match event {
+            WindowEvent::KeyboardInput(input) => Some(RecordedInput::Keyboard(input.clone())),
+            WindowEvent::MouseButtonInput(input) => Some(RecordedInput::MouseButton(*input)),
+            WindowEvent::MouseMotion(input) => Some(RecordedInput::MouseMotion(*input)),
+            WindowEvent::MouseWheel(input) => Some(RecordedInput::MouseWheel(*input)),
+            WindowEvent::TouchInput(input) => Some(RecordedInput::Touch(*input)),
+            WindowEvent::CursorMoved(input) => Some(RecordedInput::CursorMoved(input.clone())),
+            _ => None,
+        })
+        .chain(gamepads.read().cloned().map(RecordedInput::Gamepad))
+        .collect();
+
+    if !recorder.paused {
+        recorder.since_save += time.delta();
+        recorder.recording.frames.push(RecordedFrame {
+            delta: time.delta(),
+            inputs,
+        });
+    }
+}
+
+/// Saves the recording of the [`InputRecorder`] when the app exits, and every [`InputRecorder::save_interval`].
+fn save_recording(mut recorder: ResMut<InputRecorder>, mut exit: EventReader<AppExit>) {
+    let exiting = exit.read().next().is_some();
+    let save_due = recorder
+        .save_interval
+        .is_some_and(|interval| recorder.since_save >= interval);
+    if !exiting && !save_due {
+        return;
+    }
+    recorder.since_save = Duration::ZERO;
+    let result = recorder.save();
+    if let Some(path) = &recorder.path {
+        match result {
+            Ok(()) if exiting => tracing::info!("Saved input recording to {}", path.display()),
+            Ok(()) => tracing::debug!("Saved input recording to {}", path.display()),
+            Err(error) => tracing::error!(
+                "Failed to save input recording to {}: {error}",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// A plugin replaying an [`InputRecording`], frame by frame.
+///
+/// Frame durations are replayed with [`TimeUpdateStrategy::ManualDuration`], which is reset to
+/// [`TimeUpdateStrategy::Automatic`] once the replay is finished.
+///
+/// Windows of the recorded inputs are replaced with the [`PrimaryWindow`], if any,
+/// and gamepads with new entities, as if they were connected by a backend.
+/// Inputs from the actual devices aren't suppressed, so replays are best run without touching them.
+pub struct InputReplayPlugin {
+    /// The recording to replay.
+    pub recording: InputRecording,
+}
+
+impl InputReplayPlugin {
+    /// Creates a plugin replaying `recording`.
+    pub fn new(recording: InputRecording) -> Self {
+        Self { recording }
+    }
+
+    /// Creates a plugin replaying the recording saved at `path`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, InputRecordingError> {
+        InputRecording::load(path).map(Self::new)
+    }
+}
+
+impl Plugin for InputReplayPlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(frame) = self.recording.frames.first() {
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(frame.delta));
+        }
+        app.add_event::<WindowEvent>()
+            .insert_resource(InputReplay::new(self.recording.clone()))
+            .add_systems(PreUpdate, replay_inputs.before(InputSystems));
+    }
+}
+
+/// The replay in progress of the [`InputReplayPlugin`].
+#[derive(Resource, Debug)]
+pub struct InputReplay {
+    recording: InputRecording,
+    frame: usize,
+    gamepads: HashMap<Entity, Entity>,
+}
+
+impl InputReplay {
+    /// Creates a replay of `recording`, starting at its first frame.
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            frame: 0,
+            gamepads: HashMap::default(),
+        }
+    }
+
+    /// Returns the number of the next frame to replay.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns `true` once all the frames of the recording have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.recording.frames.len()
+    }
+}
+
+/// Writes the inputs of the current frame of the [`InputReplay`].
+pub fn replay_inputs(
+    mut commands: Commands,
+    mut replay: ResMut<InputReplay>,
+    mut time_update_strategy: ResMut<TimeUpdateStrategy>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut writers: (
+        EventWriter<KeyboardInput>,
+        EventWriter<MouseButtonInput>,
+        EventWriter<MouseMotion>,
+        EventWriter<MouseWheel>,
+        EventWriter<TouchInput>,
+        EventWriter<CursorMoved>,
+        EventWriter<WindowEvent>,
+    ),
+    mut gamepad_writers: (
+        EventWriter<RawGamepadEvent>,
+        EventWriter<GamepadConnectionEvent>,
+        EventWriter<RawGamepadButtonChangedEvent>,
+        EventWriter<RawGamepadAxisChangedEvent>,
+    ),
+) {
+    if replay.is_finished() {
+        return;
+    }
+
+    let window = primary_window.single().ok();
+    let map_window = |recorded: Entity| window.unwrap_or(recorded);
+
+    let replay = replay.as_mut();
+    let frame = replay.frame;
+    replay.frame += 1;
+    // Window inputs are written like `bevy_winit` does, to both their own readers and the `WindowEvent` readers.
+    for input in replay.recording.frames[frame].inputs.clone() {
+        match input {
+            RecordedInput::Keyboard(mut input) => {
+                input.window = map_window(input.window);
+                writers.6.write(WindowEvent::KeyboardInput(input.clone()));
+                writers.0.write(input);
+            }
+            RecordedInput::MouseButton(mut input) => {
+                input.window = map_window(input.window);
+                writers.6.write(WindowEvent::MouseButtonInput(input));
+                writers.1.write(input);
+            }
+            RecordedInput::MouseMotion(input) => {
+                writers.6.write(WindowEvent::MouseMotion(input));
+                writers.2.write(input);
+            }
+            RecordedInput::MouseWheel(mut input) => {
+                input.window = map_window(input.window);
+                writers.6.write(WindowEvent::MouseWheel(input));
+                writers.3.write(input);
+            }
+            RecordedInput::Touch(mut input) => {
+                input.window = map_window(input.window);
+                writers.6.write(WindowEvent::TouchInput(input));
+                writers.4.write(input);
+            }
+            RecordedInput::CursorMoved(mut input) => {
+                input.window = map_window(input.window);
+                writers.6.write(WindowEvent::CursorMoved(input.clone()));
+                writers.5.write(input);
+            }
+            // Gamepad messages are written like `bevy_gilrs` does, to both the raw and the specific readers.
+            RecordedInput::Gamepad(mut input) => {
+                let recorded = match &mut input {
+                    RawGamepadEvent::Connection(event) => &mut event.gamepad,
+                    RawGamepadEvent::Button(event) => &mut event.gamepad,
+                    RawGamepadEvent::Axis(event) => &mut event.gamepad,
+                };
+                *recorded = *replay
+                    .gamepads
+                    .entry(*recorded)
+                    .or_insert_with(|| commands.spawn_empty().id());
+                match &input {
+                    RawGamepadEvent::Connection(event) => {
+                        gamepad_writers.1.write(event.clone());
+                    }
+                    RawGamepadEvent::Button(event) => {
+                        gamepad_writers.2.write(*event);
+                    }
+                    RawGamepadEvent::Axis(event) => {
+                        gamepad_writers.3.write(*event);
+                    }
+                }
+                gamepad_writers.0.write(input);
+            }
+        }
+    }
+
+    // The time of the next frame is updated before this system runs again.
+    *time_update_strategy = match replay.recording.frames.get(replay.frame) {
+        Some(next) => TimeUpdateStrategy::ManualDuration(next.delta),
+        None => TimeUpdateStrategy::Automatic,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_input::{
+        keyboard::Key, keyboard::KeyCode, mouse::MouseButton, ButtonInput, ButtonState, InputPlugin,
+    };
+    use bevy_time::TimePlugin;
+
+    fn key_input(window: Entity, state: ButtonState) -> RecordedInput {
+        RecordedInput::Keyboard(KeyboardInput {
+            key_code: KeyCode::Space,
+            logical_key: Key::Space,
+            state,
+            text: None,
+            repeat: false,
+            window,
+        })
+    }
+
+    #[test]
+    fn replay_recording() {
+        let window = Entity::from_raw_u32(42).unwrap();
+        let recording = InputRecording {
+            frames: vec![
+                RecordedFrame {
+                    delta: Duration::ZERO,
+                    inputs: vec![key_input(window, ButtonState::Pressed)],
+                },
+                RecordedFrame {
+                    delta: Duration::from_millis(20),
+                    inputs: vec![],
+                },
+                RecordedFrame {
+                    delta: Duration::from_millis(30),
+                    inputs: vec![key_input(window, ButtonState::Released)],
+                },
+            ],
+        };
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputReplayPlugin::new(recording.clone()),
+        ))
+        .add_event::<CursorMoved>()
+        .insert_resource(InputRecorder::default())
+        .add_systems(PreUpdate, record_inputs.after(InputSystems));
+
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::Space));
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::Space));
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .just_released(KeyCode::Space));
+        assert!(app.world().resource::<InputReplay>().is_finished());
+
+        // Replaying while recording gives back the original recording.
+        let recorder = app.world().resource::<InputRecorder>();
+        assert_eq!(recorder.recording, recording);
+        assert!(matches!(
+            app.world().resource::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::Automatic
+        ));
+    }
+
+    #[test]
+    fn record_inputs_in_received_order() {
+        let window = Entity::from_raw_u32(42).unwrap();
+        let mouse_input = RecordedInput::MouseButton(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Pressed,
+            window,
+        });
+        let recording = InputRecording {
+            frames: vec![RecordedFrame {
+                delta: Duration::ZERO,
+                inputs: vec![
+                    key_input(window, ButtonState::Pressed),
+                    mouse_input,
+                    key_input(window, ButtonState::Released),
+                ],
+            }],
+        };
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputReplayPlugin::new(recording.clone()),
+        ))
+        .add_event::<CursorMoved>()
+        .insert_resource(InputRecorder::default())
+        .add_systems(PreUpdate, record_inputs.after(InputSystems));
+        app.update();
+
+        assert_eq!(app.world().resource::<InputRecorder>().recording, recording);
+    }
+
+    #[test]
+    fn save_recording_periodically() {
+        let path = std::env::temp_dir().join(format!(
+            "bevy_dev_tools_input_recording_{}.ron",
+            std::process::id()
+        ));
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputRecordingPlugin {
+                path: path.clone(),
+                save_interval: Some(Duration::ZERO),
+            },
+        ));
+        app.update();
+        app.update();
+        app.update();
+
+        // The recording is saved without the app exiting, new frames being appended to the file.
+        let recording = InputRecording::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(recording, app.world().resource::<InputRecorder>().recording);
+        assert_eq!(recording.frames.len(), 3);
+    }
+
+    #[test]
+    fn paused_recording_does_not_count_towards_saves() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, InputPlugin))
+            .add_event::<WindowEvent>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10,
+            )))
+            .insert_resource(InputRecorder {
+                paused: true,
+                ..Default::default()
+            })
+            .add_systems(PreUpdate, record_inputs.after(InputSystems));
+        app.update();
+        app.update();
+
+        let recorder = app.world().resource::<InputRecorder>();
+        assert!(recorder.recording.frames.is_empty());
+        assert_eq!(recorder.since_save, Duration::ZERO);
+    }
+
+    #[test]
+    fn roundtrip_recording() {
+        let recording = InputRecording {
+            frames: vec![RecordedFrame {
+                delta: Duration::from_millis(16),
+                inputs: vec![key_input(Entity::PLACEHOLDER, ButtonState::Pressed)],
+            }],
+        };
+        let content = ron::to_string(&recording).unwrap();
+        assert!(content.ends_with(RECORDING_END));
+        assert_eq!(
+            ron::from_str::<InputRecording>(&content).unwrap(),
+            recording
+        );
+    }
+}
@@ -16,6 +16,9 @@ pub mod ci_testing;
 pub mod fps_overlay;
 pub mod frame_time_graph;
 
+#[cfg(feature = "input_recording")]
+pub mod input_recording;
+
 pub mod picking_debug;
 
 pub mod states;
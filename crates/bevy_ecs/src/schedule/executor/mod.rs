@@ -234,6 +234,46 @@ impl IntoSystemSet<()> for ApplyDeferred {
     }
 }
 
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// `Some` while [`catch_system_panic`] runs on this thread, with the system that panicked.
+    static PANICKED_SYSTEM: core::cell::RefCell<Option<Option<DebugName>>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Records `name` as the system that panicked if [`catch_system_panic`] is running on this thread,
+/// unless one is already recorded, so that a panic propagating through the systems running outer
+/// schedules keeps the innermost one.
+#[cfg(feature = "std")]
+fn record_panicked_system(name: DebugName) {
+    PANICKED_SYSTEM.with_borrow_mut(|panicked| {
+        if let Some(panicked @ None) = panicked {
+            *panicked = Some(name);
+        }
+    });
+}
+
+/// Runs `f`, catching its panic along with the name of the system that panicked, if a schedule
+/// executor propagated it.
+///
+/// This is an implementation detail of the `AppHarness` of `bevy_app`.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub fn catch_system_panic<R>(
+    f: impl FnOnce() -> R + core::panic::UnwindSafe,
+) -> Result<
+    R,
+    (
+        Option<DebugName>,
+        alloc::boxed::Box<dyn core::any::Any + Send>,
+    ),
+> {
+    let outer = PANICKED_SYSTEM.replace(Some(None));
+    let result = std::panic::catch_unwind(f);
+    let system = PANICKED_SYSTEM.replace(outer).flatten();
+    result.map_err(|payload| (system, payload))
+}
+
 /// These functions hide the bottom of the callstack from `RUST_BACKTRACE=1` (assuming the default panic handler is used).
 ///
 /// The full callstack will still be visible with `RUST_BACKTRACE=full`.
@@ -316,7 +356,7 @@ mod __rust_begin_short_backtrace {
 #[cfg(test)]
 mod tests {
     use crate::{
-        prelude::{Component, In, IntoSystem, Resource, Schedule},
+        prelude::{Component, In, IntoSystem, Resource, Schedule, System},
         schedule::ExecutorKind,
         system::{Populated, Res, ResMut, Single},
         world::World,
@@ -352,6 +392,35 @@ mod tests {
         state.populated_ran = true;
     }
 
+    #[test]
+    fn records_panicked_system() {
+        fn panicking_system() {
+            panic!("expected panic");
+        }
+
+        for executor in EXECUTORS {
+            let run_schedule = || {
+                let mut schedule = Schedule::default();
+                schedule.set_executor_kind(executor);
+                schedule.add_systems(panicking_system);
+                schedule.run(&mut World::new());
+            };
+
+            // Panics are only recorded while they are caught.
+            assert!(std::panic::catch_unwind(run_schedule).is_err());
+            assert!(super::catch_system_panic(|| ()).is_ok());
+
+            let Err((system, _)) = super::catch_system_panic(run_schedule) else {
+                panic!("the schedule did not panic");
+            };
+            assert_eq!(
+                system,
+                Some(IntoSystem::into_system(panicking_system).name()),
+                "{executor:?}"
+            );
+        }
+    }
+
     #[test]
     #[expect(clippy::print_stdout, reason = "std and println are allowed in tests")]
     fn single_and_populated_skipped_and_run() {
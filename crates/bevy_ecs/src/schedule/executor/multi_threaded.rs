@@ -2,6 +2,7 @@ use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::cell::SyncUnsafeCell;
 use bevy_platform::sync::Arc;
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
+use bevy_utils::prelude::DebugName;
 use concurrent_queue::ConcurrentQueue;
 use core::{any::Any, panic::AssertUnwindSafe};
 use fixedbitset::FixedBitSet;
@@ -85,6 +86,9 @@ struct SystemResult {
     system_index: usize,
 }
 
+/// The name of a system that panicked, and the panic payload.
+type SystemPanic = (DebugName, Box<dyn Any + Send>);
+
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
 pub struct MultiThreadedExecutor {
     /// The running state, protected by a mutex so that a reference to the executor can be shared across tasks.
@@ -93,8 +97,8 @@ pub struct MultiThreadedExecutor {
     system_completion: ConcurrentQueue<SystemResult>,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
-    /// When set, tells the executor that a thread has panicked.
-    panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
+    /// When set, tells the executor that a thread has panicked, and which system panicked.
+    panic_payload: Mutex<Option<SystemPanic>>,
     starting_systems: FixedBitSet,
     /// Cached tracing span
     #[cfg(feature = "trace")]
@@ -309,7 +313,8 @@ impl SystemExecutor for MultiThreadedExecutor {
 
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
-        if let Some(payload) = payload.take() {
+        if let Some((system, payload)) = payload.take() {
+            super::record_panicked_system(system);
             std::panic::resume_unwind(payload);
         }
 
@@ -326,28 +331,23 @@ impl SystemExecutor for MultiThreadedExecutor {
 }
 
 impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
-    fn system_completed(
-        &self,
-        system_index: usize,
-        res: Result<(), Box<dyn Any + Send>>,
-        system: &ScheduleSystem,
-    ) {
+    fn system_completed(&self, system_index: usize, res: Result<(), SystemPanic>) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
             .push(SystemResult { system_index })
             .unwrap_or_else(|error| unreachable!("{}", error));
-        if let Err(payload) = res {
+        if let Err((system, payload)) = res {
             #[cfg(feature = "std")]
             #[expect(clippy::print_stderr, reason = "Allowed behind `std` feature gate.")]
             {
-                eprintln!("Encountered a panic in system `{}`!", system.name());
+                eprintln!("Encountered a panic in system `{system}`!");
             }
             // set the payload to propagate the error
             {
                 let mut panic_payload = self.environment.executor.panic_payload.lock().unwrap();
-                *panic_payload = Some(payload);
+                *panic_payload = Some((system, payload));
             }
         }
         self.tick_executor();
@@ -696,7 +696,10 @@ impl ExecutorState {
                     }
                 };
             }));
-            context.system_completed(system_index, res, system);
+            context.system_completed(
+                system_index,
+                res.map_err(|payload| (system.name(), payload)),
+            );
         };
 
         if system_meta.is_send {
@@ -724,7 +727,7 @@ impl ExecutorState {
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res);
             };
 
             context.scope.spawn_on_scope(task);
@@ -746,7 +749,10 @@ impl ExecutorState {
                         );
                     }
                 }));
-                context.system_completed(system_index, res, system);
+                context.system_completed(
+                    system_index,
+                    res.map_err(|payload| (system.name(), payload)),
+                );
             };
 
             context.scope.spawn_on_scope(task);
@@ -797,7 +803,7 @@ fn apply_deferred(
     unapplied_systems: &FixedBitSet,
     systems: &[SyncUnsafeCell<SystemWithAccess>],
     world: &mut World,
-) -> Result<(), Box<dyn Any + Send>> {
+) -> Result<(), SystemPanic> {
     for system_index in unapplied_systems.ones() {
         // SAFETY: none of these systems are running, no other references exist
         let system = &mut unsafe { &mut *systems[system_index].get() }.system;
@@ -813,7 +819,7 @@ fn apply_deferred(
                     system.name()
                 );
             }
-            return Err(payload);
+            return Err((system.name(), payload));
         }
     }
     Ok(())
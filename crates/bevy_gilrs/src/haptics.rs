@@ -0,0 +1,572 @@
+//! Authored haptic patterns, played on gamepads alongside [`GamepadRumbleRequest`](bevy_input::gamepad::GamepadRumbleRequest)s.
+//!
+//! A [`HapticPattern`] describes how the strong and weak motors of a gamepad rumble over time,
+//! with an envelope of keyframes for each motor, and how many times the pattern repeats. Patterns
+//! are assets, and can be loaded from `.haptic.ron` files:
+//!
+//! ```ron
+//! (
+//!     strong_motor: [(time: 0.0, intensity: 1.0), (time: 0.15, intensity: 0.0)],
+//!     weak_motor: [(time: 0.0, intensity: 0.0), (time: 0.3, intensity: 0.6), (time: 0.5, intensity: 0.0)],
+//!     repeat: Times(3),
+//! )
+//! ```
+//!
+//! Patterns are played with the [`GamepadHaptics`] component, which the [`GamepadHapticsPlugin`]
+//! adds to every [`Gamepad`]. Several patterns can play at the same time on the same gamepad,
+//! each with its own [`HapticSettings`]: only the patterns with the highest priority are felt,
+//! and their intensities add up.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{
+    io::Reader, Asset, AssetApp, AssetLoader, AssetServer, Assets, Handle, LoadContext,
+};
+use bevy_ecs::prelude::*;
+use bevy_input::gamepad::{Gamepad, GamepadRumbleIntensity};
+use bevy_reflect::TypePath;
+use bevy_time::{Real, Time};
+use core::time::Duration;
+use ron::de::SpannedError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+use crate::RumbleSystems;
+
+/// How long each rumble played for a [`GamepadHaptics`] lasts.
+///
+/// Requests are renewed before they run out, and as soon as the intensity changes.
+pub const HAPTICS_REFRESH_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Plugin that plays the [`HapticPattern`]s of every [`GamepadHaptics`].
+///
+/// The patterns are played as a separate rumble, so [`GamepadRumbleRequest`](bevy_input::gamepad::GamepadRumbleRequest)s don't affect them,
+/// and the other way around.
+///
+/// It requires the `AssetPlugin`, and the [`GilrsPlugin`](crate::GilrsPlugin) to actually rumble
+/// the gamepads.
+#[derive(Default)]
+pub struct GamepadHapticsPlugin;
+
+impl Plugin for GamepadHapticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<HapticPattern>()
+            .init_asset_loader::<HapticPatternLoader>()
+            .add_observer(add_gamepad_haptics)
+            .add_event::<HapticRumbleRequest>()
+            .add_systems(PostUpdate, play_haptic_patterns.before(RumbleSystems));
+    }
+}
+
+/// A point of a [`HapticEnvelope`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HapticKeyframe {
+    /// The time of the keyframe since the start of the pattern, in seconds.
+    pub time: f32,
+    /// The intensity of the motor, from `0.0` to `1.0`.
+    pub intensity: f32,
+}
+
+/// The intensity of a motor over time, linearly interpolated between keyframes.
+///
+/// The intensity before the first keyframe is the one of the first keyframe, and the intensity
+/// after the last keyframe is the one of the last keyframe.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HapticEnvelope {
+    /// The keyframes, sorted by time.
+    pub keyframes: Vec<HapticKeyframe>,
+}
+
+impl HapticEnvelope {
+    /// Creates an envelope from `(time, intensity)` pairs, with times in seconds.
+    ///
+    /// The keyframes are sorted by time.
+    pub fn new(keyframes: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut keyframes: Vec<_> = keyframes
+            .into_iter()
+            .map(|(time, intensity)| HapticKeyframe { time, intensity })
+            .collect();
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self { keyframes }
+    }
+
+    /// Creates an envelope holding `intensity` for `duration` seconds.
+    pub fn constant(intensity: f32, duration: f32) -> Self {
+        Self::new([(0.0, intensity), (duration, intensity)])
+    }
+
+    /// Returns the time of the last keyframe, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// Returns the intensity at `time`, in seconds.
+    pub fn sample(&self, time: f32) -> f32 {
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        let intensity = match (next.checked_sub(1), self.keyframes.get(next)) {
+            (None, None) => 0.0,
+            (None, Some(after)) => after.intensity,
+            (Some(before), None) => self.keyframes[before].intensity,
+            (Some(before), Some(after)) => {
+                let before = self.keyframes[before];
+                let t = (time - before.time) / (after.time - before.time);
+                before.intensity + (after.intensity - before.intensity) * t
+            }
+        };
+        intensity.clamp(0.0, 1.0)
+    }
+}
+
+/// How many times a [`HapticPattern`] plays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HapticRepeat {
+    /// The pattern plays once.
+    #[default]
+    Once,
+    /// The pattern plays the given number of times.
+    Times(u32),
+    /// The pattern loops until it is stopped.
+    Forever,
+}
+
+/// How the motors of a gamepad rumble over time.
+///
+/// The duration of the pattern is the duration of its longest envelope.
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HapticPattern {
+    /// The envelope of the strong, usually low-frequency, motor.
+    #[serde(default)]
+    pub strong_motor: HapticEnvelope,
+    /// The envelope of the weak, usually high-frequency, motor.
+    #[serde(default)]
+    pub weak_motor: HapticEnvelope,
+    /// How many times the pattern plays.
+    #[serde(default)]
+    pub repeat: HapticRepeat,
+}
+
+impl HapticPattern {
+    /// Creates a pattern playing once, from the envelopes of both motors.
+    pub fn new(strong_motor: HapticEnvelope, weak_motor: HapticEnvelope) -> Self {
+        Self {
+            strong_motor,
+            weak_motor,
+            repeat: HapticRepeat::Once,
+        }
+    }
+
+    /// Sets how many times the pattern plays.
+    pub fn with_repeat(mut self, repeat: HapticRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the duration of one play of the pattern, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.strong_motor.duration().max(self.weak_motor.duration())
+    }
+
+    /// Returns the intensity of the motors after playing for `elapsed`, or `None` if the pattern
+    /// is over.
+    pub fn sample(&self, elapsed: Duration) -> Option<GamepadRumbleIntensity> {
+        let duration = self.duration();
+        if duration <= 0.0 {
+            return None;
+        }
+        let elapsed = elapsed.as_secs_f32();
+        let plays = match self.repeat {
+            HapticRepeat::Once => 1.0,
+            HapticRepeat::Times(times) => times as f32,
+            HapticRepeat::Forever => f32::INFINITY,
+        };
+        if elapsed >= duration * plays {
+            return None;
+        }
+        let time = elapsed % duration;
+        Some(GamepadRumbleIntensity {
+            strong_motor: self.strong_motor.sample(time),
+            weak_motor: self.weak_motor.sample(time),
+        })
+    }
+}
+
+/// How a [`HapticPattern`] is played by [`GamepadHaptics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HapticSettings {
+    /// While patterns with a higher priority play, this pattern is not felt.
+    pub priority: i32,
+    /// The factor applied to the intensities of the pattern.
+    pub scale: f32,
+}
+
+impl Default for HapticSettings {
+    fn default() -> Self {
+        Self {
+            priority: 0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl HapticSettings {
+    /// Creates settings with the given `priority`.
+    pub fn with_priority(priority: i32) -> Self {
+        Self {
+            priority,
+            ..Default::default()
+        }
+    }
+}
+
+/// Identifies a pattern played by [`GamepadHaptics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HapticPlaybackId(u32);
+
+#[derive(Debug, Clone)]
+struct HapticPlayback {
+    id: HapticPlaybackId,
+    pattern: Handle<HapticPattern>,
+    settings: HapticSettings,
+    /// When the pattern started playing, once it was loaded.
+    start: Option<Duration>,
+}
+
+/// The [`HapticPattern`]s playing on a gamepad.
+///
+/// It is added to every [`Gamepad`] by the [`GamepadHapticsPlugin`], which mixes the patterns and
+/// rumbles the gamepad with the result.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::prelude::*;
+/// # use bevy_gilrs::haptics::{GamepadHaptics, HapticPattern, HapticSettings};
+/// fn rumble_on_hit(
+///     mut gamepads: Query<&mut GamepadHaptics>,
+///     asset_server: Res<AssetServer>,
+/// ) {
+///     let hit = asset_server.load::<HapticPattern>("haptics/hit.haptic.ron");
+///     for mut haptics in &mut gamepads {
+///         haptics.play_with(hit.clone(), HapticSettings::with_priority(10));
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Default)]
+pub struct GamepadHaptics {
+    playbacks: Vec<HapticPlayback>,
+    next_id: u32,
+    /// The last intensity requested, and when.
+    requested: Option<(GamepadRumbleIntensity, Duration)>,
+}
+
+impl GamepadHaptics {
+    /// Plays `pattern` with the default [`HapticSettings`].
+    pub fn play(&mut self, pattern: Handle<HapticPattern>) -> HapticPlaybackId {
+        self.play_with(pattern, HapticSettings::default())
+    }
+
+    /// Plays `pattern` with `settings`.
+    ///
+    /// The pattern starts once it is loaded.
+    pub fn play_with(
+        &mut self,
+        pattern: Handle<HapticPattern>,
+        settings: HapticSettings,
+    ) -> HapticPlaybackId {
+        let id = HapticPlaybackId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.playbacks.push(HapticPlayback {
+            id,
+            pattern,
+            settings,
+            start: None,
+        });
+        id
+    }
+
+    /// Stops the pattern played as `id`, and returns whether it was playing.
+    pub fn stop(&mut self, id: HapticPlaybackId) -> bool {
+        let len = self.playbacks.len();
+        self.playbacks.retain(|playback| playback.id != id);
+        self.playbacks.len() != len
+    }
+
+    /// Stops all patterns.
+    pub fn stop_all(&mut self) {
+        self.playbacks.clear();
+    }
+
+    /// Returns `true` if the pattern played as `id` has not finished yet.
+    ///
+    /// A pattern that failed to load is not playing anymore.
+    pub fn is_playing(&self, id: HapticPlaybackId) -> bool {
+        self.playbacks.iter().any(|playback| playback.id == id)
+    }
+
+    /// Returns `true` if no pattern is playing.
+    pub fn is_empty(&self) -> bool {
+        self.playbacks.is_empty()
+    }
+
+    /// Stops the patterns that failed to load, as they would never start otherwise.
+    fn stop_failed(&mut self, is_failed: impl Fn(&Handle<HapticPattern>) -> bool) {
+        self.playbacks.retain(|playback| {
+            let failed = is_failed(&playback.pattern);
+            if failed {
+                warn!(
+                    "Stopping haptic pattern {:?}, as it failed to load",
+                    playback.pattern.path()
+                );
+            }
+            !failed
+        });
+    }
+
+    /// Advances the patterns to `now`, forgetting the finished ones, and returns the mixed
+    /// intensity of the motors.
+    pub fn update(
+        &mut self,
+        now: Duration,
+        patterns: &Assets<HapticPattern>,
+    ) -> GamepadRumbleIntensity {
+        let mut samples = Vec::new();
+        self.playbacks.retain_mut(|playback| {
+            let Some(pattern) = patterns.get(&playback.pattern) else {
+                return true;
+            };
+            let start = *playback.start.get_or_insert(now);
+            let Some(intensity) = pattern.sample(now.saturating_sub(start)) else {
+                return false;
+            };
+            samples.push((playback.settings, intensity));
+            true
+        });
+
+        let Some(priority) = samples.iter().map(|(settings, _)| settings.priority).max() else {
+            return GamepadRumbleIntensity {
+                strong_motor: 0.0,
+                weak_motor: 0.0,
+            };
+        };
+        let (strong_motor, weak_motor) = samples
+            .iter()
+            .filter(|(settings, _)| settings.priority == priority)
+            .fold((0.0, 0.0), |(strong, weak), (settings, intensity)| {
+                (
+                    strong + intensity.strong_motor * settings.scale,
+                    weak + intensity.weak_motor * settings.scale,
+                )
+            });
+        GamepadRumbleIntensity {
+            strong_motor: f32::clamp(strong_motor, 0.0, 1.0),
+            weak_motor: f32::clamp(weak_motor, 0.0, 1.0),
+        }
+    }
+
+    /// Returns the rumble request to send for `intensity` at `now`, if it differs from the last
+    /// requested one or the last request is about to run out.
+    fn request(
+        &mut self,
+        gamepad: Entity,
+        intensity: GamepadRumbleIntensity,
+        now: Duration,
+    ) -> Option<HapticRumbleRequest> {
+        let silent = intensity.strong_motor <= 0.0 && intensity.weak_motor <= 0.0;
+        match self.requested {
+            None if silent => return None,
+            Some((requested, at))
+                if !silent
+                    && (requested.strong_motor - intensity.strong_motor).abs() < 1.0 / 256.0
+                    && (requested.weak_motor - intensity.weak_motor).abs() < 1.0 / 256.0
+                    && now < at + HAPTICS_REFRESH_INTERVAL / 2 =>
+            {
+                return None;
+            }
+            _ => {}
+        }
+
+        self.requested = (!silent).then_some((intensity, now));
+        Some(HapticRumbleRequest {
+            gamepad,
+            intensity: (!silent).then_some(intensity),
+        })
+    }
+}
+
+/// Replaces the rumble played for the [`GamepadHaptics`] of a gamepad, without affecting the
+/// rumbles requested with [`GamepadRumbleRequest`](bevy_input::gamepad::GamepadRumbleRequest)s.
+#[derive(BufferedEvent, Debug, Clone, Copy)]
+pub(crate) struct HapticRumbleRequest {
+    pub(crate) gamepad: Entity,
+    /// The intensity to rumble with for [`HAPTICS_REFRESH_INTERVAL`], or `None` to stop rumbling.
+    pub(crate) intensity: Option<GamepadRumbleIntensity>,
+}
+
+fn add_gamepad_haptics(event: On<Add, Gamepad>, mut commands: Commands) {
+    commands
+        .entity(event.entity())
+        .insert_if_new(GamepadHaptics::default());
+}
+
+/// Mixes the patterns of every [`GamepadHaptics`] at the current [`Time<Real>`], and requests the
+/// resulting rumble.
+pub fn play_haptic_patterns(
+    time: Res<Time<Real>>,
+    patterns: Res<Assets<HapticPattern>>,
+    asset_server: Res<AssetServer>,
+    mut gamepads: Query<(Entity, &mut GamepadHaptics)>,
+    mut requests: EventWriter<HapticRumbleRequest>,
+) {
+    let now = time.elapsed();
+    for (gamepad, mut haptics) in &mut gamepads {
+        if haptics.playbacks.is_empty() && haptics.requested.is_none() {
+            continue;
+        }
+        haptics.stop_failed(|pattern| asset_server.load_state(pattern).is_failed());
+        let intensity = haptics.update(now, &patterns);
+        requests.write_batch(haptics.request(gamepad, intensity, now));
+    }
+}
+
+/// An error when loading a [`HapticPattern`].
+#[derive(Error, Debug)]
+pub enum HapticPatternLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The RON file is invalid.
+    #[error(transparent)]
+    Ron(#[from] SpannedError),
+}
+
+/// Loads [`HapticPattern`]s from `.haptic.ron` files.
+#[derive(Default)]
+pub struct HapticPatternLoader;
+
+impl AssetLoader for HapticPatternLoader {
+    type Asset = HapticPattern;
+    type Settings = ();
+    type Error = HapticPatternLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<HapticPattern, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["haptic.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn envelopes_interpolate_and_repeat() {
+        let pattern = HapticPattern::new(
+            HapticEnvelope::new([(0.0, 1.0), (0.2, 0.0)]),
+            HapticEnvelope::constant(0.5, 0.4),
+        )
+        .with_repeat(HapticRepeat::Times(2));
+        assert_eq!(pattern.duration(), 0.4);
+
+        let intensity = pattern.sample(millis(100)).unwrap();
+        assert!((intensity.strong_motor - 0.5).abs() < 1e-5);
+        assert_eq!(intensity.weak_motor, 0.5);
+        assert_eq!(pattern.sample(millis(300)).unwrap().strong_motor, 0.0);
+        let intensity = pattern.sample(millis(450)).unwrap();
+        assert!((intensity.strong_motor - 0.75).abs() < 1e-5);
+        assert_eq!(pattern.sample(millis(900)), None);
+
+        let pattern: HapticPattern = ron::from_str(
+            "(strong_motor: [(time: 0.0, intensity: 1.0), (time: 0.1, intensity: 1.0)], repeat: Forever)",
+        )
+        .unwrap();
+        assert_eq!(pattern.weak_motor, HapticEnvelope::default());
+        assert!(pattern.sample(Duration::from_secs(60)).is_some());
+    }
+
+    #[test]
+    fn highest_priority_patterns_are_mixed() {
+        let mut patterns = Assets::<HapticPattern>::default();
+        let strong = patterns.add(HapticPattern::new(
+            HapticEnvelope::constant(0.6, 1.0),
+            HapticEnvelope::default(),
+        ));
+        let short = patterns.add(HapticPattern::new(
+            HapticEnvelope::default(),
+            HapticEnvelope::constant(0.3, 0.1),
+        ));
+
+        let mut haptics = GamepadHaptics::default();
+        haptics.play(strong.clone());
+        let low = haptics.play(strong);
+        let high = haptics.play_with(
+            short,
+            HapticSettings {
+                priority: 1,
+                scale: 2.0,
+            },
+        );
+
+        let intensity = haptics.update(millis(1000), &patterns);
+        assert_eq!(intensity.strong_motor, 0.0);
+        assert_eq!(intensity.weak_motor, 0.6);
+
+        // Once the high priority pattern is over, the others add up.
+        let intensity = haptics.update(millis(1200), &patterns);
+        assert!(!haptics.is_playing(high));
+        assert_eq!(intensity.strong_motor, 1.0);
+        assert_eq!(intensity.weak_motor, 0.0);
+
+        assert!(haptics.stop(low));
+        assert_eq!(haptics.update(millis(1300), &patterns).strong_motor, 0.6);
+
+        let gamepad = Entity::PLACEHOLDER;
+        let intensity = haptics.update(millis(1300), &patterns);
+        assert!(haptics
+            .request(gamepad, intensity, millis(1300))
+            .is_some_and(|request| request.intensity == Some(intensity)));
+        assert!(haptics.request(gamepad, intensity, millis(1350)).is_none());
+        assert!(haptics.request(gamepad, intensity, millis(1400)).is_some());
+
+        haptics.stop_all();
+        let intensity = haptics.update(millis(1450), &patterns);
+        assert!(haptics
+            .request(gamepad, intensity, millis(1450))
+            .is_some_and(|request| request.intensity.is_none()));
+        assert!(haptics.request(gamepad, intensity, millis(1500)).is_none());
+    }
+
+    #[test]
+    fn failed_patterns_stop() {
+        let mut patterns = Assets::<HapticPattern>::default();
+        let loaded = patterns.add(HapticPattern::new(
+            HapticEnvelope::constant(1.0, 1.0),
+            HapticEnvelope::default(),
+        ));
+        let failed = patterns.reserve_handle();
+
+        let mut haptics = GamepadHaptics::default();
+        let playing = haptics.play(loaded);
+        let stopped = haptics.play(failed.clone());
+        haptics.stop_failed(|pattern| *pattern == failed);
+
+        assert!(haptics.is_playing(playing));
+        assert!(!haptics.is_playing(stopped));
+        assert_eq!(haptics.update(millis(0), &patterns).strong_motor, 1.0);
+    }
+}
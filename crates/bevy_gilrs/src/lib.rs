@@ -9,9 +9,14 @@
 //!
 //! This crate is built on top of [GilRs](gilrs), a library
 //! that handles abstracting over platform-specific gamepad APIs.
+//!
+//! Besides single rumble requests, gamepads can play authored haptic patterns with the `haptics`
+//! feature.
 
 mod converter;
 mod gilrs_system;
+#[cfg(feature = "haptics")]
+pub mod haptics;
 mod rumble;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -30,6 +35,9 @@ use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
 use rumble::{play_gilrs_rumble, RunningRumbleEffects};
 use tracing::error;
 
+#[cfg(feature = "haptics")]
+pub use haptics::GamepadHapticsPlugin;
+
 #[cfg(target_arch = "wasm32")]
 thread_local! {
     /// Temporary storage of gilrs data to replace usage of `!Send` resources. This will be replaced with proper
@@ -109,6 +117,8 @@ impl Plugin for GilrsPlugin {
                 });
                 app.insert_resource(g);
                 app.init_resource::<GilrsGamepads>();
+                #[cfg(feature = "haptics")]
+                app.add_event::<haptics::HapticRumbleRequest>();
                 app.init_resource::<RunningRumbleEffects>()
                     .add_systems(PreStartup, gilrs_event_startup_system)
                     .add_systems(PreUpdate, gilrs_event_system.before(InputSystems))
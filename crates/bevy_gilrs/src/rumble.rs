@@ -1,6 +1,8 @@
 //! Handle user specified rumble request events.
+#[cfg(feature = "haptics")]
+use crate::haptics::{HapticRumbleRequest, HAPTICS_REFRESH_INTERVAL};
 use crate::{Gilrs, GilrsGamepads};
-use bevy_ecs::prelude::{EventReader, Res, ResMut, Resource};
+use bevy_ecs::prelude::{Entity, EventReader, Res, ResMut, Resource};
 use bevy_input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
 use bevy_platform::cell::SyncCell;
 use bevy_platform::collections::HashMap;
@@ -41,6 +43,10 @@ pub(crate) struct RunningRumbleEffects {
     /// If multiple rumbles are running at the same time, their resulting rumble
     /// will be the saturated sum of their strengths up until [`u16::MAX`]
     rumbles: HashMap<GamepadId, Vec<RunningRumble>>,
+    /// The rumble played for the haptic patterns of each gamepad, which
+    /// [`GamepadRumbleRequest::Stop`] doesn't stop
+    #[cfg(feature = "haptics")]
+    haptics: HashMap<GamepadId, RunningRumble>,
 }
 
 /// gilrs uses magnitudes from 0 to [`u16::MAX`], while ours go from `0.0` to `1.0` ([`f32`])
@@ -79,6 +85,41 @@ fn get_base_effects(
     effects
 }
 
+fn find_gamepad_id(
+    gilrs: &gilrs::Gilrs,
+    gamepads: &GilrsGamepads,
+    gamepad: Entity,
+) -> Result<GamepadId, RumbleError> {
+    let (gamepad_id, _) = gilrs
+        .gamepads()
+        .find(|(pad_id, _)| *pad_id == gamepads.get_gamepad_id(gamepad).unwrap())
+        .ok_or(RumbleError::GamepadNotFound)?;
+    Ok(gamepad_id)
+}
+
+fn play_rumble(
+    gilrs: &mut gilrs::Gilrs,
+    gamepad_id: GamepadId,
+    intensity: GamepadRumbleIntensity,
+    duration: Duration,
+    current_time: Duration,
+) -> Result<RunningRumble, RumbleError> {
+    let mut effect_builder = ff::EffectBuilder::new();
+
+    for effect in get_base_effects(intensity, duration) {
+        effect_builder.add_effect(effect);
+        effect_builder.repeat(Repeat::For(duration.into()));
+    }
+
+    let effect = effect_builder.gamepads(&[gamepad_id]).finish(gilrs)?;
+    effect.play()?;
+
+    Ok(RunningRumble {
+        deadline: current_time + duration,
+        effect: SyncCell::new(effect),
+    })
+}
+
 fn handle_rumble_request(
     running_rumbles: &mut RunningRumbleEffects,
     gilrs: &mut gilrs::Gilrs,
@@ -86,12 +127,7 @@ fn handle_rumble_request(
     rumble: GamepadRumbleRequest,
     current_time: Duration,
 ) -> Result<(), RumbleError> {
-    let gamepad = rumble.gamepad();
-
-    let (gamepad_id, _) = gilrs
-        .gamepads()
-        .find(|(pad_id, _)| *pad_id == gamepads.get_gamepad_id(gamepad).unwrap())
-        .ok_or(RumbleError::GamepadNotFound)?;
+    let gamepad_id = find_gamepad_id(gilrs, gamepads, rumble.gamepad())?;
 
     match rumble {
         GamepadRumbleRequest::Stop { .. } => {
@@ -103,32 +139,72 @@ fn handle_rumble_request(
             intensity,
             ..
         } => {
-            let mut effect_builder = ff::EffectBuilder::new();
+            let rumble = play_rumble(gilrs, gamepad_id, intensity, duration, current_time)?;
+            running_rumbles
+                .rumbles
+                .entry(gamepad_id)
+                .or_default()
+                .push(rumble);
+        }
+    }
 
-            for effect in get_base_effects(intensity, duration) {
-                effect_builder.add_effect(effect);
-                effect_builder.repeat(Repeat::For(duration.into()));
-            }
+    Ok(())
+}
 
-            let effect = effect_builder.gamepads(&[gamepad_id]).finish(gilrs)?;
-            effect.play()?;
+#[cfg(feature = "haptics")]
+fn handle_haptic_rumble_request(
+    running_rumbles: &mut RunningRumbleEffects,
+    gilrs: &mut gilrs::Gilrs,
+    gamepads: &GilrsGamepads,
+    request: HapticRumbleRequest,
+    current_time: Duration,
+) -> Result<(), RumbleError> {
+    let gamepad_id = find_gamepad_id(gilrs, gamepads, request.gamepad)?;
 
-            let gamepad_rumbles = running_rumbles.rumbles.entry(gamepad_id).or_default();
-            let deadline = current_time + duration;
-            gamepad_rumbles.push(RunningRumble {
-                deadline,
-                effect: SyncCell::new(effect),
-            });
+    // Only the previous haptics rumble is replaced, the requested rumbles keep playing.
+    // `ff::Effect` uses RAII, dropping = deactivating
+    match request.intensity {
+        Some(intensity) => {
+            let rumble = play_rumble(
+                gilrs,
+                gamepad_id,
+                intensity,
+                HAPTICS_REFRESH_INTERVAL,
+                current_time,
+            )?;
+            running_rumbles.haptics.insert(gamepad_id, rumble);
+        }
+        None => {
+            running_rumbles.haptics.remove(&gamepad_id);
         }
     }
 
     Ok(())
 }
+
+fn log_rumble_error(gamepad: Entity, result: Result<(), RumbleError>) {
+    match result {
+        Ok(()) => {}
+        Err(RumbleError::GilrsError(err)) => {
+            if let ff::Error::FfNotSupported(_) = err {
+                debug!("Tried to rumble {gamepad:?}, but it doesn't support force feedback");
+            } else {
+                warn!(
+                    "Tried to handle rumble request for {gamepad:?} but an error occurred: {err}"
+                );
+            }
+        }
+        Err(RumbleError::GamepadNotFound) => {
+            warn!("Tried to handle rumble request {gamepad:?} but it doesn't exist!");
+        }
+    };
+}
 pub(crate) fn play_gilrs_rumble(
     time: Res<Time<Real>>,
     mut gilrs: ResMut<Gilrs>,
     gamepads: Res<GilrsGamepads>,
     mut requests: EventReader<GamepadRumbleRequest>,
+    #[cfg(feature = "haptics")] mut haptic_requests: EventReader<HapticRumbleRequest>,
     mut running_rumbles: ResMut<RunningRumbleEffects>,
 ) {
     gilrs.with(|gilrs| {
@@ -141,25 +217,28 @@ pub(crate) fn play_gilrs_rumble(
         running_rumbles
             .rumbles
             .retain(|_gamepad, rumbles| !rumbles.is_empty());
+        #[cfg(feature = "haptics")]
+        running_rumbles
+            .haptics
+            .retain(|_gamepad, rumble| rumble.deadline >= current_time);
 
         // Add new effects.
         for rumble in requests.read().cloned() {
             let gamepad = rumble.gamepad();
-            match handle_rumble_request(&mut running_rumbles, gilrs, &gamepads, rumble, current_time) {
-                Ok(()) => {}
-                Err(RumbleError::GilrsError(err)) => {
-                    if let ff::Error::FfNotSupported(_) = err {
-                        debug!("Tried to rumble {gamepad:?}, but it doesn't support force feedback");
-                    } else {
-                        warn!(
-                        "Tried to handle rumble request for {gamepad:?} but an error occurred: {err}"
-                        );
-                    }
-                }
-                Err(RumbleError::GamepadNotFound) => {
-                    warn!("Tried to handle rumble request {gamepad:?} but it doesn't exist!");
-                }
-            };
+            let result =
+                handle_rumble_request(&mut running_rumbles, gilrs, &gamepads, rumble, current_time);
+            log_rumble_error(gamepad, result);
+        }
+        #[cfg(feature = "haptics")]
+        for request in haptic_requests.read().copied() {
+            let result = handle_haptic_rumble_request(
+                &mut running_rumbles,
+                gilrs,
+                &gamepads,
+                request,
+                current_time,
+            );
+            log_rumble_error(request.gamepad, result);
         }
     });
 }
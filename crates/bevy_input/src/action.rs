@@ -0,0 +1,659 @@
+//! Mapping of raw inputs to user-defined actions.
+//!
+//! Games rarely care about which key or button was pressed, but rather about what the player
+//! wanted to do: jump, fire, move. An [`InputMap`] binds each action of a user-defined type to any
+//! number of [`InputBinding`]s, and the [`ActionPlugin`] updates the matching [`ActionState`] every frame.
+//!
+//! Each entity with an [`InputMap`] is a separate player, with its own [`ActionState`].
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{action::{ActionPlugin, ActionState, InputBinding, InputMap}, prelude::*};
+//! # use bevy_reflect::Reflect;
+//! #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+//! enum Action {
+//!     Jump,
+//!     Crouch,
+//! }
+//!
+//! fn spawn_player(mut commands: Commands) {
+//!     commands.spawn(
+//!         InputMap::default()
+//!             .with(Action::Jump, KeyCode::Space)
+//!             .with(Action::Jump, GamepadButton::South)
+//!             .with(Action::Crouch, KeyCode::ControlLeft),
+//!     );
+//! }
+//!
+//! fn jump(players: Query<&ActionState<Action>>) {
+//!     for actions in &players {
+//!         if actions.just_pressed(Action::Jump) {
+//!             // Jump!
+//!         }
+//!     }
+//! }
+//!
+//! App::new()
+//!     .add_plugins(ActionPlugin::<Action>::default())
+//!     .add_systems(Startup, spawn_player)
+//!     .add_systems(Update, jump);
+//! ```
+
+use crate::{
+    gamepad::{Gamepad, GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    ButtonInput, InputSystems,
+};
+use alloc::vec::Vec;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_platform::collections::HashMap;
+use core::{hash::Hash, marker::PhantomData};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, Reflectable};
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A type whose values are actions performed by players, usually a field-less enum.
+///
+/// This is implemented for all types satisfying its bounds.
+#[cfg(not(feature = "bevy_reflect"))]
+pub trait InputAction: Clone + Eq + Hash + Send + Sync + 'static {}
+
+#[cfg(not(feature = "bevy_reflect"))]
+impl<T: Clone + Eq + Hash + Send + Sync + 'static> InputAction for T {}
+
+/// A type whose values are actions performed by players, usually a field-less enum.
+///
+/// This is implemented for all types satisfying its bounds.
+/// With the `bevy_reflect` feature, these include [`Reflect`], usually derived.
+#[cfg(feature = "bevy_reflect")]
+pub trait InputAction:
+    Clone + Eq + Hash + Send + Sync + FromReflect + Reflectable + 'static
+{
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl<T: Clone + Eq + Hash + Send + Sync + FromReflect + Reflectable + 'static> InputAction for T {}
+
+/// Adds the [`ActionState`] updates of the actions of type `A`.
+pub struct ActionPlugin<A: InputAction>(PhantomData<A>);
+
+impl<A: InputAction> Default for ActionPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: InputAction> Plugin for ActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<InputMap<A>>()
+            .register_type::<ActionState<A>>();
+
+        app.add_systems(
+            PreUpdate,
+            update_action_states::<A>
+                .in_set(ActionSystems)
+                .after(InputSystems),
+        );
+    }
+}
+
+/// Label for the systems updating [`ActionState`]s, which run after [`InputSystems`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct ActionSystems;
+
+/// A raw input which an action can be bound to.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputBinding {
+    /// A key of the keyboard.
+    Key(KeyCode),
+    /// A button of the mouse.
+    Mouse(MouseButton),
+    /// A button of the gamepad.
+    GamepadButton(GamepadButton),
+    /// An axis of the gamepad, pressed when it goes beyond `threshold`.
+    ///
+    /// A negative threshold is crossed by going below it, such as when pushing a stick to the left.
+    GamepadAxis {
+        /// The bound axis.
+        axis: GamepadAxis,
+        /// The value beyond which the binding is pressed.
+        threshold: f32,
+    },
+    /// A combination of bindings, pressed while all of them are.
+    ///
+    /// Its value is the one of its least pressed part, negative if any part is negative.
+    Chord(Vec<InputBinding>),
+}
+
+impl InputBinding {
+    /// Creates a binding pressed while all of `bindings` are.
+    pub fn chord(bindings: impl IntoIterator<Item = impl Into<InputBinding>>) -> Self {
+        Self::Chord(bindings.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the value of this binding, or `None` if it isn't pressed.
+    ///
+    /// Buttons have a value of `1.0` when pressed, or their analog value if they have one.
+    fn value(&self, inputs: &InputSources) -> Option<f32> {
+        match self {
+            InputBinding::Key(key) => inputs
+                .keys
+                .is_some_and(|keys| keys.pressed(*key))
+                .then_some(1.0),
+            InputBinding::Mouse(button) => inputs
+                .mouse_buttons
+                .is_some_and(|buttons| buttons.pressed(*button))
+                .then_some(1.0),
+            InputBinding::GamepadButton(button) => inputs
+                .gamepads
+                .iter()
+                .filter(|gamepad| gamepad.pressed(*button))
+                .map(|gamepad| {
+                    gamepad
+                        .get(*button)
+                        .filter(|value| *value > 0.0)
+                        .unwrap_or(1.0)
+                })
+                .reduce(f32::max),
+            InputBinding::GamepadAxis { axis, threshold } => inputs
+                .gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad.get(*axis))
+                .filter(|value| {
+                    if *threshold < 0.0 {
+                        value <= threshold
+                    } else {
+                        value >= threshold
+                    }
+                })
+                .reduce(|a, b| if a.abs() >= b.abs() { a } else { b }),
+            InputBinding::Chord(bindings) => {
+                // The chord is as far from zero as its least pressed part,
+                // in the direction of its negative parts, such as an axis pushed to the left.
+                let mut magnitude = 1.0_f32;
+                let mut negative = false;
+                for binding in bindings {
+                    let value = binding.value(inputs)?;
+                    magnitude = magnitude.min(value.abs());
+                    negative |= value < 0.0;
+                }
+                Some(if negative { -magnitude } else { magnitude })
+            }
+        }
+    }
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl From<GamepadButton> for InputBinding {
+    fn from(button: GamepadButton) -> Self {
+        Self::GamepadButton(button)
+    }
+}
+
+/// The bindings of the actions of type `A` for a single player.
+///
+/// The [`ActionState`] of the player is updated from these bindings by the [`ActionPlugin`].
+/// An action is pressed while any of its bindings is.
+///
+/// With the `serialize` feature, the bindings can be saved and loaded, such as from a rebinding menu.
+/// The gamepad of the player isn't serialized, since entities don't persist across runs.
+#[derive(Component, Debug, Clone)]
+#[require(ActionState<A>)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap<A: InputAction> {
+    bindings: HashMap<A, Vec<InputBinding>>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    gamepad: Option<Entity>,
+}
+
+impl<A: InputAction> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::default(),
+            gamepad: None,
+        }
+    }
+}
+
+impl<A: InputAction> InputMap<A> {
+    /// Adds `binding` to the bindings of `action`.
+    pub fn with(mut self, action: A, binding: impl Into<InputBinding>) -> Self {
+        self.insert(action, binding);
+        self
+    }
+
+    /// Restricts the gamepad inputs of this map to `gamepad`.
+    pub fn with_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
+    /// Adds `binding` to the bindings of `action`, unless it's already bound to it.
+    pub fn insert(&mut self, action: A, binding: impl Into<InputBinding>) {
+        let binding = binding.into();
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Removes `binding` from the bindings of `action`, returning whether it was bound to it.
+    pub fn remove(&mut self, action: &A, binding: &InputBinding) -> bool {
+        let Some(bindings) = self.bindings.get_mut(action) else {
+            return false;
+        };
+        let len = bindings.len();
+        bindings.retain(|bound| bound != binding);
+        len != bindings.len()
+    }
+
+    /// Replaces the bindings of `action`, returning the previous ones.
+    pub fn set(
+        &mut self,
+        action: A,
+        bindings: impl IntoIterator<Item = impl Into<InputBinding>>,
+    ) -> Vec<InputBinding> {
+        self.bindings
+            .insert(action, bindings.into_iter().map(Into::into).collect())
+            .unwrap_or_default()
+    }
+
+    /// Removes all the bindings of `action`, returning them.
+    pub fn clear(&mut self, action: &A) -> Vec<InputBinding> {
+        self.bindings.remove(action).unwrap_or_default()
+    }
+
+    /// Returns the bindings of `action`.
+    pub fn get(&self, action: &A) -> &[InputBinding] {
+        self.bindings
+            .get(action)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the actions of this map and their bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &[InputBinding])> {
+        self.bindings
+            .iter()
+            .map(|(action, bindings)| (action, bindings.as_slice()))
+    }
+
+    /// Returns the gamepad this map is restricted to, if any.
+    ///
+    /// When `None`, gamepad bindings are pressed by any gamepad.
+    pub fn gamepad(&self) -> Option<Entity> {
+        self.gamepad
+    }
+
+    /// Sets the gamepad this map is restricted to.
+    pub fn set_gamepad(&mut self, gamepad: Option<Entity>) {
+        self.gamepad = gamepad;
+    }
+}
+
+/// The state of the actions of type `A` for a single player, updated from its [`InputMap`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Clone)
+)]
+pub struct ActionState<A: InputAction> {
+    buttons: ButtonInput<A>,
+    values: HashMap<A, f32>,
+}
+
+impl<A: InputAction> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            buttons: ButtonInput::default(),
+            values: HashMap::default(),
+        }
+    }
+}
+
+impl<A: InputAction> ActionState<A> {
+    /// Returns `true` if `action` is pressed.
+    pub fn pressed(&self, action: A) -> bool {
+        self.buttons.pressed(action)
+    }
+
+    /// Returns `true` if `action` has been pressed during the current frame.
+    pub fn just_pressed(&self, action: A) -> bool {
+        self.buttons.just_pressed(action)
+    }
+
+    /// Returns `true` if `action` has been released during the current frame.
+    pub fn just_released(&self, action: A) -> bool {
+        self.buttons.just_released(action)
+    }
+
+    /// Returns the value of `action`, or `0.0` if it isn't pressed.
+    ///
+    /// This is `1.0` for buttons, the analog value of gamepad buttons supporting it,
+    /// and the position of gamepad axes. When several bindings are pressed,
+    /// the value furthest from zero is used.
+    pub fn value(&self, action: &A) -> f32 {
+        self.values.get(action).copied().unwrap_or(0.0)
+    }
+
+    /// Returns an iterator over the pressed actions.
+    pub fn get_pressed(&self) -> impl ExactSizeIterator<Item = &A> {
+        self.buttons.get_pressed()
+    }
+
+    /// Returns an iterator over the actions pressed during the current frame.
+    pub fn get_just_pressed(&self) -> impl ExactSizeIterator<Item = &A> {
+        self.buttons.get_just_pressed()
+    }
+
+    /// Returns an iterator over the actions released during the current frame.
+    pub fn get_just_released(&self) -> impl ExactSizeIterator<Item = &A> {
+        self.buttons.get_just_released()
+    }
+
+    /// Presses `action` with the given `value`, as if one of its bindings was.
+    ///
+    /// This is useful to trigger actions from other sources, such as UI buttons or networked players.
+    /// It is overwritten by the [`ActionPlugin`] on the next update, which also releases the
+    /// actions without bindings: call it every frame the action should stay pressed.
+    pub fn press(&mut self, action: A, value: f32) {
+        self.buttons.press(action.clone());
+        self.values.insert(action, value);
+    }
+
+    /// Releases `action`, as if none of its bindings was pressed.
+    pub fn release(&mut self, action: A) {
+        self.values.remove(&action);
+        self.buttons.release(action);
+    }
+
+    /// Releases all actions, such as when a menu captures the input.
+    pub fn release_all(&mut self) {
+        self.buttons.release_all();
+        self.values.clear();
+    }
+}
+
+/// The raw inputs bindings are read from.
+struct InputSources<'a> {
+    keys: Option<&'a ButtonInput<KeyCode>>,
+    mouse_buttons: Option<&'a ButtonInput<MouseButton>>,
+    gamepads: Vec<&'a Gamepad>,
+}
+
+/// Updates the [`ActionState`] of every player from its [`InputMap`].
+pub fn update_action_states<A: InputAction>(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut players: Query<(&InputMap<A>, &mut ActionState<A>)>,
+) {
+    for (input_map, mut action_state) in &mut players {
+        let inputs = InputSources {
+            keys: keys.as_deref(),
+            mouse_buttons: mouse_buttons.as_deref(),
+            gamepads: gamepads
+                .iter()
+                .filter(|(entity, _)| input_map.gamepad.is_none_or(|gamepad| gamepad == *entity))
+                .map(|(_, gamepad)| gamepad)
+                .collect(),
+        };
+
+        let action_state = action_state.as_mut();
+        action_state.buttons.clear();
+        action_state.values.clear();
+        for (action, bindings) in &input_map.bindings {
+            let value = bindings
+                .iter()
+                .filter_map(|binding| binding.value(&inputs))
+                .reduce(|a, b| if a.abs() >= b.abs() { a } else { b });
+            match value {
+                Some(value) => action_state.press(action.clone(), value),
+                None => action_state.release(action.clone()),
+            }
+        }
+        // Release the actions pressed manually, which no binding keeps pressed.
+        let unbound = action_state
+            .get_pressed()
+            .filter(|action| !input_map.bindings.contains_key(*action))
+            .cloned()
+            .collect::<Vec<_>>();
+        for action in unbound {
+            action_state.release(action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gamepad::GamepadInput, InputPlugin};
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+    enum Action {
+        Jump,
+        Move,
+        Dash,
+    }
+
+    fn create_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, ActionPlugin::<Action>::default()));
+        app
+    }
+
+    #[test]
+    fn keyboard_and_chord_bindings() {
+        let mut app = create_app();
+        let player = app
+            .world_mut()
+            .spawn(
+                InputMap::default()
+                    .with(Action::Jump, KeyCode::Space)
+                    .with(Action::Jump, MouseButton::Left)
+                    .with(
+                        Action::Dash,
+                        InputBinding::chord([KeyCode::ShiftLeft, KeyCode::KeyD]),
+                    ),
+            )
+            .id();
+
+        let world = app.world_mut();
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+        app.update();
+
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(actions.just_pressed(Action::Jump));
+        assert_eq!(actions.value(&Action::Jump), 1.0);
+        assert!(!actions.pressed(Action::Dash));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+        app.update();
+
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(actions.pressed(Action::Jump));
+        assert!(!actions.just_pressed(Action::Jump));
+        assert!(actions.just_pressed(Action::Dash));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release_all();
+        app.update();
+
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(actions.just_released(Action::Jump));
+        assert!(actions.just_released(Action::Dash));
+        assert_eq!(actions.get_pressed().len(), 0);
+    }
+
+    #[test]
+    fn gamepad_bindings_per_player() {
+        let mut app = create_app();
+        let world = app.world_mut();
+        let first_gamepad = world.spawn(Gamepad::default()).id();
+        let second_gamepad = world.spawn(Gamepad::default()).id();
+
+        let map = InputMap::default()
+            .with(Action::Jump, GamepadButton::South)
+            .with(
+                Action::Move,
+                InputBinding::GamepadAxis {
+                    axis: GamepadAxis::LeftStickX,
+                    threshold: 0.1,
+                },
+            )
+            .with(
+                Action::Move,
+                InputBinding::GamepadAxis {
+                    axis: GamepadAxis::LeftStickX,
+                    threshold: -0.1,
+                },
+            );
+        let first_player = world.spawn(map.clone().with_gamepad(first_gamepad)).id();
+        let second_player = world.spawn(map.with_gamepad(second_gamepad)).id();
+
+        let mut gamepad = world.get_mut::<Gamepad>(second_gamepad).unwrap();
+        gamepad.digital_mut().press(GamepadButton::South);
+        gamepad
+            .analog_mut()
+            .set(GamepadInput::Axis(GamepadAxis::LeftStickX), -0.5);
+        app.update();
+
+        let actions = app
+            .world()
+            .get::<ActionState<Action>>(first_player)
+            .unwrap();
+        assert!(!actions.pressed(Action::Jump));
+        assert!(!actions.pressed(Action::Move));
+
+        let actions = app
+            .world()
+            .get::<ActionState<Action>>(second_player)
+            .unwrap();
+        assert!(actions.pressed(Action::Jump));
+        assert!(actions.pressed(Action::Move));
+        assert_eq!(actions.value(&Action::Move), -0.5);
+    }
+
+    #[test]
+    fn chords_keep_the_axis_direction() {
+        let mut app = create_app();
+        let world = app.world_mut();
+        let gamepad = world.spawn(Gamepad::default()).id();
+        let player = world
+            .spawn(InputMap::default().with(
+                Action::Dash,
+                InputBinding::chord([
+                    InputBinding::Key(KeyCode::ShiftLeft),
+                    InputBinding::GamepadAxis {
+                        axis: GamepadAxis::LeftStickX,
+                        threshold: -0.1,
+                    },
+                ]),
+            ))
+            .id();
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+        world
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(GamepadInput::Axis(GamepadAxis::LeftStickX), -0.5);
+        app.update();
+
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(actions.pressed(Action::Dash));
+        assert_eq!(actions.value(&Action::Dash), -0.5);
+    }
+
+    #[test]
+    fn rebinding() {
+        let mut map = InputMap::default().with(Action::Jump, KeyCode::Space);
+        map.insert(Action::Jump, KeyCode::Space);
+        assert_eq!(map.get(&Action::Jump), [InputBinding::Key(KeyCode::Space)]);
+
+        let previous = map.set(Action::Jump, [KeyCode::KeyW, KeyCode::ArrowUp]);
+        assert_eq!(previous, [InputBinding::Key(KeyCode::Space)]);
+        assert!(map.remove(&Action::Jump, &InputBinding::Key(KeyCode::KeyW)));
+        assert!(!map.remove(&Action::Jump, &InputBinding::Key(KeyCode::KeyW)));
+        assert_eq!(
+            map.clear(&Action::Jump),
+            [InputBinding::Key(KeyCode::ArrowUp)]
+        );
+        assert!(map.get(&Action::Jump).is_empty());
+    }
+
+    #[test]
+    fn manual_presses_last_one_update() {
+        let mut app = create_app();
+        let player = app
+            .world_mut()
+            .spawn(InputMap::default().with(Action::Jump, KeyCode::Space))
+            .id();
+        app.update();
+
+        let mut actions = app
+            .world_mut()
+            .get_mut::<ActionState<Action>>(player)
+            .unwrap();
+        actions.press(Action::Jump, 1.0);
+        actions.press(Action::Dash, 0.5);
+        assert!(actions.pressed(Action::Dash));
+        assert_eq!(actions.value(&Action::Dash), 0.5);
+        app.update();
+
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(!actions.pressed(Action::Jump));
+        assert!(!actions.pressed(Action::Dash));
+        assert!(actions.just_released(Action::Dash));
+        assert_eq!(actions.value(&Action::Dash), 0.0);
+
+        app.update();
+        let actions = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(!actions.just_released(Action::Dash));
+    }
+}
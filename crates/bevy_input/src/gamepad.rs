@@ -2,8 +2,13 @@
 
 use core::{ops::RangeInclusive, time::Duration};
 
-use crate::{Axis, ButtonInput, ButtonState};
-use alloc::string::String;
+use crate::{
+    keyboard::{keyboard_input_system, KeyCode},
+    mouse::{mouse_button_input_system, MouseButton},
+    Axis, ButtonInput, ButtonState, InputSystems,
+};
+use alloc::{string::String, vec, vec::Vec};
+use bevy_app::{App, Plugin, PreUpdate};
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::prelude::ReflectComponent;
 use bevy_ecs::{
@@ -12,7 +17,9 @@ use bevy_ecs::{
     entity::Entity,
     event::{BufferedEvent, EventReader, EventWriter},
     name::Name,
-    system::{Commands, Query},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
 };
 use bevy_math::ops;
 use bevy_math::Vec2;
@@ -1811,6 +1818,351 @@ impl GamepadRumbleRequest {
     }
 }
 
+/// A device a player can play with, as assigned by the [`PlayerSlots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum PlayerDevice {
+    /// The keyboard and mouse, which count as a single device.
+    KeyboardMouse,
+    /// The gamepad entity.
+    Gamepad(Entity),
+}
+
+/// What identifies a physical gamepad across reconnections, as reported on connection.
+///
+/// Identical controllers share the same identity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadIdentity {
+    /// The name of the gamepad.
+    pub name: String,
+    /// The USB vendor ID, if available.
+    pub vendor_id: Option<u16>,
+    /// The USB product ID, if available.
+    pub product_id: Option<u16>,
+}
+
+/// A change in the device of a player slot, sent by the [`player_slot_system`].
+#[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum PlayerSlotEvent {
+    /// A device was assigned to a free slot.
+    Joined {
+        /// The index of the slot.
+        player: usize,
+        /// The assigned device.
+        device: PlayerDevice,
+    },
+    /// A gamepad matching the identity of the gamepad a player lost was connected, and got its slot
+    /// back.
+    Reconnected {
+        /// The index of the slot.
+        player: usize,
+        /// The newly connected device.
+        device: PlayerDevice,
+    },
+    /// The device of a player was disconnected. The slot stays reserved until a gamepad with the
+    /// same [`GamepadIdentity`] connects, or a device joins while no other slot is free.
+    DeviceLost {
+        /// The index of the slot.
+        player: usize,
+        /// The disconnected device.
+        device: PlayerDevice,
+    },
+}
+
+/// Adds the [`PlayerSlots`] resource and assigns devices to it as they connect.
+#[derive(Debug, Clone)]
+pub struct PlayerSlotPlugin {
+    /// The number of player slots.
+    pub max_players: usize,
+    /// Whether the keyboard and mouse join a free slot when one of their buttons is pressed.
+    pub keyboard_mouse_joins: bool,
+}
+
+impl Default for PlayerSlotPlugin {
+    fn default() -> Self {
+        Self {
+            max_players: 4,
+            keyboard_mouse_joins: true,
+        }
+    }
+}
+
+impl Plugin for PlayerSlotPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(
+            PlayerSlots::new(self.max_players).with_keyboard_mouse_joins(self.keyboard_mouse_joins),
+        )
+        .add_event::<PlayerSlotEvent>()
+        .add_systems(
+            PreUpdate,
+            player_slot_system
+                .in_set(InputSystems)
+                .after(gamepad_connection_system)
+                .after(keyboard_input_system)
+                .after(mouse_button_input_system),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct PlayerSlotEntry {
+    device: Option<PlayerDevice>,
+    reserved_for: Option<GamepadIdentity>,
+    /// Orders the reservations, to give up the oldest one first.
+    reserved_since: u64,
+}
+
+/// Which device each player plays with.
+///
+/// Players are numbered from `0` to [`max_players`](Self::max_players) excluded. The
+/// [`player_slot_system`] assigns each connecting gamepad to the first free slot, and the keyboard
+/// and mouse as soon as one of their buttons is pressed, if enabled. When a gamepad disconnects,
+/// its slot is reserved for the next gamepad connecting with the same [`GamepadIdentity`], so that
+/// players keep their slot when a controller is unplugged or its batteries run out. If a device
+/// joins while no slot is free, it takes the slot reserved for the longest time instead. Gamepads
+/// connecting while every slot has a device wait for one to become available, in connection order.
+///
+/// Slots can also be changed manually with [`assign`](Self::assign) and
+/// [`unassign`](Self::unassign), which do not send [`PlayerSlotEvent`]s.
+#[derive(Resource, Debug, Clone)]
+pub struct PlayerSlots {
+    slots: Vec<PlayerSlotEntry>,
+    /// The identities of the connected gamepads.
+    identities: HashMap<Entity, GamepadIdentity>,
+    /// The connected gamepads which found no slot, in connection order.
+    waiting: Vec<Entity>,
+    reservations: u64,
+    keyboard_mouse_joins: bool,
+}
+
+impl PlayerSlots {
+    /// Creates `max_players` free slots, that the keyboard and mouse can join.
+    pub fn new(max_players: usize) -> Self {
+        Self {
+            slots: vec![PlayerSlotEntry::default(); max_players],
+            identities: HashMap::default(),
+            waiting: Vec::new(),
+            reservations: 0,
+            keyboard_mouse_joins: true,
+        }
+    }
+
+    /// Sets whether the keyboard and mouse join a free slot when one of their buttons is pressed.
+    pub fn with_keyboard_mouse_joins(mut self, keyboard_mouse_joins: bool) -> Self {
+        self.keyboard_mouse_joins = keyboard_mouse_joins;
+        self
+    }
+
+    /// Returns the number of slots.
+    pub fn max_players(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the device of `player`, if any.
+    pub fn device(&self, player: usize) -> Option<PlayerDevice> {
+        self.slots.get(player).and_then(|slot| slot.device)
+    }
+
+    /// Returns the player `device` is assigned to, if any.
+    pub fn player(&self, device: PlayerDevice) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|slot| slot.device == Some(device))
+    }
+
+    /// Returns the identity of the gamepad `player` lost and waits for, if any.
+    pub fn reserved_for(&self, player: usize) -> Option<&GamepadIdentity> {
+        self.slots
+            .get(player)
+            .and_then(|slot| slot.reserved_for.as_ref())
+    }
+
+    /// Returns `true` if `player` has no device and is not waiting for a gamepad to reconnect.
+    pub fn is_free(&self, player: usize) -> bool {
+        self.slots
+            .get(player)
+            .is_some_and(|slot| slot.device.is_none() && slot.reserved_for.is_none())
+    }
+
+    /// An iterator over the players with a device, and their device.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, PlayerDevice)> + '_ {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(player, slot)| Some((player, slot.device?)))
+    }
+
+    /// Assigns `device` to `player`, removing it from any other slot, and returns the device
+    /// `player` previously had.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `player` is not lower than [`max_players`](Self::max_players).
+    pub fn assign(&mut self, player: usize, device: PlayerDevice) -> Option<PlayerDevice> {
+        if let Some(previous_player) = self.player(device) {
+            self.slots[previous_player].device = None;
+        }
+        let slot = &mut self.slots[player];
+        slot.reserved_for = None;
+        slot.device.replace(device)
+    }
+
+    /// Frees the slot of `player`, including any reservation, and returns its device.
+    pub fn unassign(&mut self, player: usize) -> Option<PlayerDevice> {
+        let slot = self.slots.get_mut(player)?;
+        slot.reserved_for = None;
+        slot.device.take()
+    }
+
+    fn connect(&mut self, gamepad: Entity, identity: GamepadIdentity) -> Option<PlayerSlotEvent> {
+        let device = PlayerDevice::Gamepad(gamepad);
+        self.identities.insert(gamepad, identity);
+        if self.player(device).is_some() {
+            return None;
+        }
+        let identity = &self.identities[&gamepad];
+        if let Some(player) = self
+            .slots
+            .iter()
+            .position(|slot| slot.device.is_none() && slot.reserved_for.as_ref() == Some(identity))
+        {
+            self.assign(player, device);
+            return Some(PlayerSlotEvent::Reconnected { player, device });
+        }
+        let event = self.join(device);
+        if event.is_none() && !self.waiting.contains(&gamepad) {
+            self.waiting.push(gamepad);
+        }
+        event
+    }
+
+    fn disconnect(&mut self, gamepad: Entity) -> Option<PlayerSlotEvent> {
+        let device = PlayerDevice::Gamepad(gamepad);
+        let identity = self.identities.remove(&gamepad);
+        self.waiting.retain(|&waiting| waiting != gamepad);
+        let player = self.player(device)?;
+        let slot = &mut self.slots[player];
+        slot.device = None;
+        slot.reserved_for = identity;
+        slot.reserved_since = self.reservations;
+        self.reservations += 1;
+        Some(PlayerSlotEvent::DeviceLost { player, device })
+    }
+
+    fn join(&mut self, device: PlayerDevice) -> Option<PlayerSlotEvent> {
+        let player = (0..self.slots.len())
+            .find(|&player| self.is_free(player))
+            .or_else(|| {
+                self.slots
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, slot)| slot.device.is_none())
+                    .min_by_key(|(_, slot)| slot.reserved_since)
+                    .map(|(player, _)| player)
+            })?;
+        self.assign(player, device);
+        Some(PlayerSlotEvent::Joined { player, device })
+    }
+
+    /// Returns `true` if some gamepads wait for a slot and a slot has no device.
+    fn can_join_waiting(&self) -> bool {
+        !self.waiting.is_empty() && self.slots.iter().any(|slot| slot.device.is_none())
+    }
+
+    /// Lets the waiting gamepads join the slots which became available, in connection order.
+    fn join_waiting(&mut self) -> Vec<PlayerSlotEvent> {
+        let mut events = Vec::new();
+        while let Some(&gamepad) = self.waiting.first() {
+            let device = PlayerDevice::Gamepad(gamepad);
+            // The gamepad may have been assigned manually in the meantime.
+            if self.player(device).is_none() {
+                let Some(event) = self.join(device) else {
+                    break;
+                };
+                events.push(event);
+            }
+            self.waiting.remove(0);
+        }
+        events
+    }
+}
+
+/// Assigns connecting gamepads and the keyboard and mouse to the [`PlayerSlots`], and sends
+/// [`PlayerSlotEvent`]s when they join, reconnect or get disconnected.
+///
+/// Gamepads which found no slot join as soon as one becomes available, such as when a device
+/// disconnects or a slot is [unassigned](PlayerSlots::unassign).
+pub fn player_slot_system(
+    mut slots: ResMut<PlayerSlots>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut slot_events: EventWriter<PlayerSlotEvent>,
+) {
+    for connection_event in connection_events.read() {
+        let event = match &connection_event.connection {
+            GamepadConnection::Connected {
+                name,
+                vendor_id,
+                product_id,
+            } => {
+                let identity = GamepadIdentity {
+                    name: name.clone(),
+                    vendor_id: *vendor_id,
+                    product_id: *product_id,
+                };
+                slots.connect(connection_event.gamepad, identity)
+            }
+            GamepadConnection::Disconnected => slots.disconnect(connection_event.gamepad),
+        };
+        if let Some(event) = event {
+            slot_events.write(event);
+        }
+    }
+
+    if slots.keyboard_mouse_joins
+        && slots.player(PlayerDevice::KeyboardMouse).is_none()
+        && (keys.get_just_pressed().next().is_some()
+            || mouse_buttons.get_just_pressed().next().is_some())
+    {
+        slot_events.write_batch(slots.join(PlayerDevice::KeyboardMouse));
+    }
+
+    if slots.can_join_waiting() {
+        let events = slots.join_waiting();
+        slot_events.write_batch(events);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -2975,4 +3327,197 @@ mod tests {
             4
         );
     }
+
+    #[test]
+    fn player_slots_keep_reconnecting_gamepads() {
+        use super::{PlayerDevice, PlayerSlotEvent, PlayerSlotPlugin, PlayerSlots};
+        use crate::{
+            keyboard::KeyCode,
+            virtual_input::{VirtualInput, VirtualInputPlugin},
+            InputPlugin,
+        };
+        use alloc::vec::Vec;
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, VirtualInputPlugin, PlayerSlotPlugin::default()));
+        let first = app.world_mut().spawn_empty().id();
+        let second = app.world_mut().spawn_empty().id();
+        let replugged = app.world_mut().spawn_empty().id();
+        let other = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .connect_named_gamepad(first, "Pad", Some(1), Some(2))
+            .connect_named_gamepad(second, "Pad", Some(1), Some(3))
+            .wait(1)
+            .disconnect_gamepad(first)
+            .wait(1)
+            .connect_gamepad(other)
+            .tap_key(KeyCode::Enter)
+            .connect_named_gamepad(replugged, "Pad", Some(1), Some(2));
+
+        let mut events = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            events.extend(
+                app.world()
+                    .resource::<Events<PlayerSlotEvent>>()
+                    .iter_current_update_events()
+                    .copied(),
+            );
+        }
+
+        let [first, second, replugged, other] =
+            [first, second, replugged, other].map(PlayerDevice::Gamepad);
+        assert_eq!(
+            events,
+            [
+                PlayerSlotEvent::Joined {
+                    player: 0,
+                    device: first
+                },
+                PlayerSlotEvent::Joined {
+                    player: 1,
+                    device: second
+                },
+                PlayerSlotEvent::DeviceLost {
+                    player: 0,
+                    device: first
+                },
+                PlayerSlotEvent::Joined {
+                    player: 2,
+                    device: other
+                },
+                PlayerSlotEvent::Joined {
+                    player: 3,
+                    device: PlayerDevice::KeyboardMouse
+                },
+                PlayerSlotEvent::Reconnected {
+                    player: 0,
+                    device: replugged
+                },
+            ]
+        );
+        let slots = app.world().resource::<PlayerSlots>();
+        assert_eq!(slots.device(0), Some(replugged));
+        assert_eq!(slots.player(PlayerDevice::KeyboardMouse), Some(3));
+    }
+
+    #[test]
+    fn waiting_gamepads_join_freed_player_slots() {
+        use super::{PlayerDevice, PlayerSlotEvent, PlayerSlotPlugin, PlayerSlots};
+        use crate::{
+            virtual_input::{VirtualInput, VirtualInputPlugin},
+            InputPlugin,
+        };
+        use alloc::vec::Vec;
+
+        let mut app = App::new();
+        app.add_plugins((
+            InputPlugin,
+            VirtualInputPlugin,
+            PlayerSlotPlugin {
+                max_players: 2,
+                keyboard_mouse_joins: false,
+            },
+        ));
+        let [first, second, extra] = [(); 3].map(|_| app.world_mut().spawn_empty().id());
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .connect_gamepad(first)
+            .connect_gamepad(second)
+            .connect_gamepad(extra)
+            .wait(1)
+            .disconnect_gamepad(second);
+
+        let mut events = Vec::new();
+        for _ in 0..2 {
+            app.update();
+            events.extend(
+                app.world()
+                    .resource::<Events<PlayerSlotEvent>>()
+                    .iter_current_update_events()
+                    .copied(),
+            );
+        }
+
+        let [first, second, extra] = [first, second, extra].map(PlayerDevice::Gamepad);
+        assert_eq!(
+            events,
+            [
+                PlayerSlotEvent::Joined {
+                    player: 0,
+                    device: first
+                },
+                PlayerSlotEvent::Joined {
+                    player: 1,
+                    device: second
+                },
+                PlayerSlotEvent::DeviceLost {
+                    player: 1,
+                    device: second
+                },
+                PlayerSlotEvent::Joined {
+                    player: 1,
+                    device: extra
+                },
+            ]
+        );
+
+        // Unassigned slots are taken too.
+        let waiting = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .connect_gamepad(waiting);
+        app.update();
+        assert_eq!(app.world().resource::<PlayerSlots>().device(1), Some(extra));
+        app.world_mut().resource_mut::<PlayerSlots>().unassign(0);
+        app.update();
+        assert_eq!(
+            app.world().resource::<PlayerSlots>().device(0),
+            Some(PlayerDevice::Gamepad(waiting))
+        );
+    }
+
+    #[test]
+    fn full_player_slots_give_up_the_oldest_reservation() {
+        use super::{GamepadIdentity, PlayerDevice, PlayerSlotEvent, PlayerSlots};
+        use bevy_ecs::world::World;
+
+        let identity = |name: &str| GamepadIdentity {
+            name: name.into(),
+            vendor_id: None,
+            product_id: None,
+        };
+        let mut world = World::new();
+        let [a, b, c, d, e] = [(); 5].map(|_| world.spawn_empty().id());
+        let mut slots = PlayerSlots::new(2);
+        assert!(slots.connect(a, identity("A")).is_some());
+        assert!(slots.connect(b, identity("B")).is_some());
+
+        // No slot is free.
+        assert_eq!(slots.connect(c, identity("C")), None);
+        assert_eq!(slots.disconnect(c), None);
+
+        slots.disconnect(b);
+        slots.disconnect(a);
+        assert!(slots.identities.is_empty());
+
+        // The slot reserved for the longest time is given up.
+        assert_eq!(
+            slots.connect(d, identity("D")),
+            Some(PlayerSlotEvent::Joined {
+                player: 1,
+                device: PlayerDevice::Gamepad(d)
+            })
+        );
+        assert_eq!(
+            slots.connect(e, identity("A")),
+            Some(PlayerSlotEvent::Reconnected {
+                player: 0,
+                device: PlayerDevice::Gamepad(e)
+            })
+        );
+        assert_eq!(slots.reserved_for(1), None);
+        assert_eq!(slots.join(PlayerDevice::KeyboardMouse), None);
+    }
 }
@@ -1,9 +1,20 @@
 //! Gestures functionality, from touchscreens and touchpads.
+//!
+//! Gesture events are forwarded from the platform when it supports them. The [`TouchGesturePlugin`]
+//! additionally recognizes them from the raw [`Touches`], which works on every platform.
 
-use bevy_ecs::event::BufferedEvent;
+use crate::{
+    touch::{touch_screen_input_system, Touches},
+    InputSystems,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
 use bevy_math::Vec2;
+use bevy_platform::collections::HashMap;
 #[cfg(feature = "bevy_reflect")]
-use bevy_reflect::Reflect;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::{Real, Time};
+use core::time::Duration;
 
 #[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -15,7 +26,8 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 ///
 /// ## Platform-specific
 ///
-/// - Only available on **`macOS`** and **`iOS`**.
+/// - Only available on **`macOS`** and **`iOS`**, unless recognized from touches by the
+///   [`TouchGesturePlugin`].
 /// - On **`iOS`**, must be enabled first
 #[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -37,7 +49,8 @@ pub struct PinchGesture(pub f32);
 ///
 /// ## Platform-specific
 ///
-/// - Only available on **`macOS`** and **`iOS`**.
+/// - Only available on **`macOS`** and **`iOS`**, unless recognized from touches by the
+///   [`TouchGesturePlugin`].
 /// - On **`iOS`**, must be enabled first
 #[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -56,7 +69,8 @@ pub struct RotationGesture(pub f32);
 ///
 /// ## Platform-specific
 ///
-/// - Only available on **`macOS`** and **`iOS`**.
+/// - Only available on **`macOS`** and **`iOS`**, unless recognized from touches by the
+///   [`TouchGesturePlugin`].
 /// - On **`iOS`**, must be enabled first
 #[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -75,6 +89,8 @@ pub struct DoubleTapGesture;
 ///
 /// ## Platform-specific
 ///
+/// - Not emitted by `winit` on every platform, but two-finger pans can be recognized from touches
+///   by the [`TouchGesturePlugin`].
 /// - On **`iOS`**, must be enabled first
 #[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
@@ -88,3 +104,473 @@ pub struct DoubleTapGesture;
     reflect(Serialize, Deserialize)
 )]
 pub struct PanGesture(pub Vec2);
+
+/// Single-finger tap gesture, a short touch that barely moves.
+///
+/// ## Platform-specific
+///
+/// - Only recognized from touches by the [`TouchGesturePlugin`].
+#[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TapGesture {
+    /// The position of the tap.
+    pub position: Vec2,
+}
+
+/// Single-finger long press gesture, a touch held in place.
+///
+/// Sent once, while the finger is still down.
+///
+/// ## Platform-specific
+///
+/// - Only recognized from touches by the [`TouchGesturePlugin`].
+#[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct LongPressGesture {
+    /// The position of the pressed finger.
+    pub position: Vec2,
+}
+
+/// Single-finger swipe gesture, a quick stroke in one direction.
+///
+/// ## Platform-specific
+///
+/// - Only recognized from touches by the [`TouchGesturePlugin`].
+#[derive(BufferedEvent, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SwipeGesture {
+    /// The main direction of the swipe.
+    pub direction: SwipeDirection,
+    /// The movement of the finger from the start to the end of the swipe, in logical pixels.
+    pub delta: Vec2,
+}
+
+/// The main direction of a [`SwipeGesture`], in window space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SwipeDirection {
+    /// Towards the top of the window.
+    Up,
+    /// Towards the bottom of the window.
+    Down,
+    /// Towards the left of the window.
+    Left,
+    /// Towards the right of the window.
+    Right,
+}
+
+impl SwipeDirection {
+    /// Returns the direction along the dominant axis of `delta`, in window space where `y` points
+    /// down.
+    pub fn from_delta(delta: Vec2) -> Self {
+        if delta.x.abs() >= delta.y.abs() {
+            if delta.x >= 0.0 {
+                SwipeDirection::Right
+            } else {
+                SwipeDirection::Left
+            }
+        } else if delta.y >= 0.0 {
+            SwipeDirection::Down
+        } else {
+            SwipeDirection::Up
+        }
+    }
+}
+
+/// Recognizes gestures from the raw [`Touches`] on every platform.
+///
+/// Single-finger touches are recognized as [`TapGesture`]s, [`DoubleTapGesture`]s,
+/// [`LongPressGesture`]s and [`SwipeGesture`]s. While exactly two fingers are down, their movement
+/// is reported as [`PinchGesture`]s, [`RotationGesture`]s and [`PanGesture`]s.
+///
+/// Thresholds are configured through the [`TouchGestureSettings`] resource. Durations are measured
+/// with the [`Time<Real>`] resource, so that pausing or slowing down the game doesn't affect them,
+/// and the `TimePlugin` is required.
+///
+/// On platforms that already emit gesture events, such as `macOS` and `iOS`, both will be sent.
+#[derive(Default)]
+pub struct TouchGesturePlugin;
+
+impl Plugin for TouchGesturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchGestureSettings>().add_systems(
+            PreUpdate,
+            touch_gesture_system
+                .in_set(InputSystems)
+                .after(touch_screen_input_system),
+        );
+    }
+}
+
+/// Thresholds used by the [`TouchGesturePlugin`] to recognize gestures.
+///
+/// Distances are in logical pixels.
+#[derive(Resource, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default, PartialEq, Clone)
+)]
+pub struct TouchGestureSettings {
+    /// The longest a touch can last to be a tap.
+    pub tap_max_duration: Duration,
+    /// The farthest a touch can move to be a tap or a long press.
+    pub tap_max_distance: f32,
+    /// The longest time between two taps for them to form a double tap.
+    pub double_tap_max_interval: Duration,
+    /// The farthest two taps can be from each other to form a double tap.
+    pub double_tap_max_distance: f32,
+    /// How long a touch must be held in place to be a long press.
+    pub long_press_duration: Duration,
+    /// The shortest distance a touch must move to be a swipe.
+    pub swipe_min_distance: f32,
+    /// The longest a touch can last to be a swipe.
+    pub swipe_max_duration: Duration,
+}
+
+impl Default for TouchGestureSettings {
+    fn default() -> Self {
+        Self {
+            tap_max_duration: Duration::from_millis(300),
+            tap_max_distance: 10.0,
+            double_tap_max_interval: Duration::from_millis(300),
+            double_tap_max_distance: 40.0,
+            long_press_duration: Duration::from_millis(500),
+            swipe_min_distance: 50.0,
+            swipe_max_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+/// The state of a touch tracked by the [`touch_gesture_system`].
+#[derive(Debug, Clone, Copy)]
+struct TrackedTouch {
+    /// When the touch started.
+    start: Duration,
+    /// The position of the touch on the previous frame.
+    last_position: Vec2,
+    /// Whether another finger was down during this touch.
+    multi_touch: bool,
+    /// Whether the touch moved too far to be a tap or a long press.
+    moved: bool,
+    /// Whether the touch was already recognized as a long press.
+    long_pressed: bool,
+}
+
+/// The state kept by the [`touch_gesture_system`] between frames.
+#[derive(Debug, Default)]
+pub struct TouchGestureState {
+    touches: HashMap<u64, TrackedTouch>,
+    last_tap: Option<(Duration, Vec2)>,
+}
+
+/// Recognizes gestures from the [`Touches`], as configured by the [`TouchGestureSettings`].
+pub fn touch_gesture_system(
+    touches: Res<Touches>,
+    settings: Res<TouchGestureSettings>,
+    time: Res<Time<Real>>,
+    mut state: Local<TouchGestureState>,
+    mut taps: EventWriter<TapGesture>,
+    mut double_taps: EventWriter<DoubleTapGesture>,
+    mut long_presses: EventWriter<LongPressGesture>,
+    mut swipes: EventWriter<SwipeGesture>,
+    mut pinches: EventWriter<PinchGesture>,
+    mut rotations: EventWriter<RotationGesture>,
+    mut pans: EventWriter<PanGesture>,
+) {
+    let now = time.elapsed();
+    let state = &mut *state;
+
+    for touch in touches.iter_just_canceled() {
+        state.touches.remove(&touch.id());
+    }
+
+    for touch in touches.iter_just_pressed() {
+        state.touches.insert(
+            touch.id(),
+            TrackedTouch {
+                start: now,
+                last_position: touch.position(),
+                multi_touch: false,
+                moved: false,
+                long_pressed: false,
+            },
+        );
+    }
+
+    if state.touches.len() > 1 {
+        for tracked in state.touches.values_mut() {
+            tracked.multi_touch = true;
+        }
+    }
+
+    for touch in touches.iter() {
+        let Some(tracked) = state.touches.get_mut(&touch.id()) else {
+            continue;
+        };
+        tracked.moved |= touch.distance().length() > settings.tap_max_distance;
+        if !tracked.multi_touch
+            && !tracked.moved
+            && !tracked.long_pressed
+            && now.saturating_sub(tracked.start) >= settings.long_press_duration
+        {
+            tracked.long_pressed = true;
+            long_presses.write(LongPressGesture {
+                position: touch.position(),
+            });
+        }
+    }
+
+    for touch in touches.iter_just_released() {
+        let Some(tracked) = state.touches.remove(&touch.id()) else {
+            continue;
+        };
+        if tracked.multi_touch || tracked.long_pressed {
+            continue;
+        }
+        let duration = now.saturating_sub(tracked.start);
+        let delta = touch.distance();
+        let moved = tracked.moved || delta.length() > settings.tap_max_distance;
+        if !moved && duration <= settings.tap_max_duration {
+            let position = touch.position();
+            taps.write(TapGesture { position });
+            match state.last_tap {
+                Some((time, last_position))
+                    if now.saturating_sub(time) <= settings.double_tap_max_interval
+                        && last_position.distance(position) <= settings.double_tap_max_distance =>
+                {
+                    double_taps.write(DoubleTapGesture);
+                    state.last_tap = None;
+                }
+                _ => state.last_tap = Some((now, position)),
+            }
+        } else if delta.length() >= settings.swipe_min_distance
+            && duration <= settings.swipe_max_duration
+        {
+            swipes.write(SwipeGesture {
+                direction: SwipeDirection::from_delta(delta),
+                delta,
+            });
+        }
+    }
+
+    let mut fingers = touches.iter();
+    if let (Some(first), Some(second), None) = (fingers.next(), fingers.next(), fingers.next()) {
+        // A finger that was just put down has no previous position to compare with.
+        let tracked = |id| state.touches.get(&id).filter(|_| !touches.just_pressed(id));
+        if let (Some(first_tracked), Some(second_tracked)) =
+            (tracked(first.id()), tracked(second.id()))
+        {
+            let previous = second_tracked.last_position - first_tracked.last_position;
+            let current = second.position() - first.position();
+
+            let previous_length = previous.length();
+            if previous_length > 0.0 && current.length() != previous_length {
+                pinches.write(PinchGesture(current.length() / previous_length - 1.0));
+            }
+
+            // Window space has `y` pointing down, so the angle is flipped to stay
+            // counterclockwise-positive on screen.
+            if previous != Vec2::ZERO && current != Vec2::ZERO {
+                let angle = -previous.angle_to(current);
+                if angle != 0.0 {
+                    rotations.write(RotationGesture(angle));
+                }
+            }
+
+            let pan = (first.position() + second.position()
+                - first_tracked.last_position
+                - second_tracked.last_position)
+                / 2.0;
+            if pan != Vec2::ZERO {
+                pans.write(PanGesture(pan));
+            }
+        }
+    }
+
+    for touch in touches.iter() {
+        if let Some(tracked) = state.touches.get_mut(&touch.id()) {
+            tracked.last_position = touch.position();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        touch::{TouchInput, TouchPhase},
+        InputPlugin,
+    };
+    use alloc::vec::Vec;
+    use core::f32::consts::FRAC_PI_2;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, TouchGesturePlugin))
+            .init_resource::<Time<Real>>();
+        app
+    }
+
+    fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+        app.world_mut().write_event(TouchInput {
+            phase,
+            position,
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id,
+        });
+    }
+
+    fn advance(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn events<E: BufferedEvent + Clone>(app: &App) -> Vec<E> {
+        app.world()
+            .resource::<Events<E>>()
+            .iter_current_update_events()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn tap_and_double_tap() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        advance(&mut app, 16);
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(12.0, 10.0));
+        advance(&mut app, 100);
+        assert_eq!(
+            events::<TapGesture>(&app),
+            [TapGesture {
+                position: Vec2::new(10.0, 10.0)
+            }]
+        );
+        assert!(events::<DoubleTapGesture>(&app).is_empty());
+
+        touch(&mut app, 1, TouchPhase::Started, Vec2::new(15.0, 12.0));
+        advance(&mut app, 50);
+        touch(&mut app, 1, TouchPhase::Ended, Vec2::new(15.0, 12.0));
+        advance(&mut app, 50);
+        assert_eq!(events::<TapGesture>(&app).len(), 1);
+        assert_eq!(events::<DoubleTapGesture>(&app), [DoubleTapGesture]);
+    }
+
+    #[test]
+    fn long_press() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::ZERO);
+        advance(&mut app, 16);
+        advance(&mut app, 400);
+        assert!(events::<LongPressGesture>(&app).is_empty());
+        advance(&mut app, 100);
+        assert_eq!(
+            events::<LongPressGesture>(&app),
+            [LongPressGesture {
+                position: Vec2::ZERO
+            }]
+        );
+
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::ZERO);
+        advance(&mut app, 16);
+        assert!(events::<LongPressGesture>(&app).is_empty());
+        assert!(events::<TapGesture>(&app).is_empty());
+    }
+
+    #[test]
+    fn swipe() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(100.0, 100.0));
+        advance(&mut app, 16);
+        touch(&mut app, 0, TouchPhase::Moved, Vec2::new(100.0, 40.0));
+        advance(&mut app, 100);
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(105.0, 20.0));
+        advance(&mut app, 100);
+        assert_eq!(
+            events::<SwipeGesture>(&app),
+            [SwipeGesture {
+                direction: SwipeDirection::Up,
+                delta: Vec2::new(0.0, -60.0),
+            }]
+        );
+        assert!(events::<TapGesture>(&app).is_empty());
+    }
+
+    #[test]
+    fn two_finger_pinch_rotate_and_pan() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(-10.0, 0.0));
+        touch(&mut app, 1, TouchPhase::Started, Vec2::new(10.0, 0.0));
+        advance(&mut app, 16);
+        assert!(events::<PinchGesture>(&app).is_empty());
+
+        // Spread the fingers apart and turn them a quarter turn counterclockwise on screen.
+        touch(&mut app, 0, TouchPhase::Moved, Vec2::new(0.0, 20.0));
+        touch(&mut app, 1, TouchPhase::Moved, Vec2::new(0.0, -20.0));
+        advance(&mut app, 16);
+        let [PinchGesture(pinch)] = events::<PinchGesture>(&app)[..] else {
+            panic!("expected a single pinch");
+        };
+        assert!((pinch - 1.0).abs() < 1e-5);
+        let [RotationGesture(rotation)] = events::<RotationGesture>(&app)[..] else {
+            panic!("expected a single rotation");
+        };
+        assert!((rotation - FRAC_PI_2).abs() < 1e-5);
+        assert!(events::<PanGesture>(&app).is_empty());
+
+        touch(&mut app, 0, TouchPhase::Moved, Vec2::new(5.0, 20.0));
+        touch(&mut app, 1, TouchPhase::Moved, Vec2::new(5.0, -20.0));
+        advance(&mut app, 16);
+        assert_eq!(
+            events::<PanGesture>(&app),
+            [PanGesture(Vec2::new(5.0, 0.0))]
+        );
+        assert!(events::<PinchGesture>(&app).is_empty());
+
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(5.0, 20.0));
+        touch(&mut app, 1, TouchPhase::Ended, Vec2::new(5.0, -20.0));
+        advance(&mut app, 16);
+        assert!(events::<TapGesture>(&app).is_empty());
+        assert!(events::<SwipeGesture>(&app).is_empty());
+    }
+}
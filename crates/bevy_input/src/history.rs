@@ -0,0 +1,731 @@
+//! Timestamped input history, for input buffering and combo detection.
+//!
+//! Action games often accept an input slightly before it can be acted upon, such as a jump pressed
+//! a few milliseconds before landing, and recognize sequences of inputs, such as the
+//! ↓ ↘ → + punch of fighting games. An [`InputHistory`] remembers recent presses and releases with
+//! the [`Time<Real>`] at which they were seen, so that both can be expressed in time rather than in
+//! frames, independently of the frame rate.
+//!
+//! The [`InputHistoryPlugin`] records the [`ButtonInput<T>`] resource into an [`InputHistory<T>`]
+//! resource, and the [`GamepadInputHistoryPlugin`] records the buttons, axes and stick directions
+//! of each gamepad into a [`GamepadInputHistory`] component. Both require the `TimePlugin`.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{history::{InputDirection, InputSequence}, prelude::*};
+//! # use core::time::Duration;
+//! fn hadouken(gamepads: Query<&bevy_input::history::GamepadInputHistory>) {
+//!     let sequence = InputSequence::new(Duration::from_millis(200))
+//!         .then(InputDirection::Down)
+//!         .then(InputDirection::DownRight)
+//!         .then(InputDirection::Right);
+//!     for history in &gamepads {
+//!         if history.directions().matches(&sequence)
+//!             && history.buttons().just_pressed_within(GamepadButton::West, Duration::from_millis(150))
+//!         {
+//!             // Throw a fireball!
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+    gamepad::{Gamepad, GamepadAxis, GamepadButton},
+    ButtonInput, ButtonState, InputSystems,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_platform::collections::HashMap;
+use bevy_time::{Real, Time};
+use core::{hash::Hash, marker::PhantomData, time::Duration};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// How long histories keep their records by default.
+pub const DEFAULT_HISTORY_RETENTION: Duration = Duration::from_secs(1);
+
+/// Records the [`ButtonInput<T>`] resource into the [`InputHistory<T>`] resource every frame.
+pub struct InputHistoryPlugin<T> {
+    /// How long records are kept.
+    pub retention: Duration,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> InputHistoryPlugin<T> {
+    /// Creates a plugin keeping records for `retention`.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            retention,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for InputHistoryPlugin<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_RETENTION)
+    }
+}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> Plugin for InputHistoryPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputHistory::<T>::new(self.retention))
+            .add_systems(PreUpdate, record_input_history::<T>.after(InputSystems));
+    }
+}
+
+/// Records the buttons, axes and stick directions of every [`Gamepad`] into a
+/// [`GamepadInputHistory`] component, added to the gamepad entities.
+pub struct GamepadInputHistoryPlugin {
+    /// How long records are kept.
+    pub retention: Duration,
+}
+
+impl Default for GamepadInputHistoryPlugin {
+    fn default() -> Self {
+        Self {
+            retention: DEFAULT_HISTORY_RETENTION,
+        }
+    }
+}
+
+impl Plugin for GamepadInputHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        let retention = self.retention;
+        app.add_systems(
+            PreUpdate,
+            (
+                (move |mut commands: Commands,
+                       gamepads: Query<Entity, (With<Gamepad>, Without<GamepadInputHistory>)>| {
+                    for gamepad in &gamepads {
+                        commands
+                            .entity(gamepad)
+                            .insert(GamepadInputHistory::new(retention));
+                    }
+                }),
+                record_gamepad_input_history,
+            )
+                .chain()
+                .after(InputSystems),
+        );
+    }
+}
+
+/// A press or release seen by an [`InputHistory`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputRecord<T> {
+    /// The input.
+    pub input: T,
+    /// Whether the input was pressed or released.
+    pub state: ButtonState,
+    /// The [`Time<Real>`] elapsed when the input was seen.
+    pub time: Duration,
+}
+
+/// The recent presses and releases of inputs of type `T`, with the time they were seen.
+///
+/// Records older than the retention are forgotten on every [`update`](Self::update). Queries are
+/// relative to the time of the last update.
+#[derive(Resource, Debug, Clone)]
+pub struct InputHistory<T: Send + Sync + 'static> {
+    records: VecDeque<InputRecord<T>>,
+    retention: Duration,
+    now: Duration,
+}
+
+impl<T: Send + Sync + 'static> Default for InputHistory<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_RETENTION)
+    }
+}
+
+impl<T: Send + Sync + 'static> InputHistory<T> {
+    /// Creates an empty history keeping records for `retention`.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            records: VecDeque::new(),
+            retention,
+            now: Duration::ZERO,
+        }
+    }
+
+    /// Returns how long records are kept.
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    /// Sets how long records are kept.
+    pub fn set_retention(&mut self, retention: Duration) {
+        self.retention = retention;
+    }
+
+    /// Returns the time of the last [`update`](Self::update).
+    pub fn now(&self) -> Duration {
+        self.now
+    }
+
+    /// Moves the history to `now`, forgetting the records older than the retention.
+    pub fn update(&mut self, now: Duration) {
+        self.now = now;
+        let oldest = now.saturating_sub(self.retention);
+        while self
+            .records
+            .front()
+            .is_some_and(|record| record.time < oldest)
+        {
+            self.records.pop_front();
+        }
+    }
+}
+
+impl<T: Copy + Eq + Send + Sync + 'static> InputHistory<T> {
+    /// Records that `input` changed to `state`, at the time of the last update.
+    pub fn record(&mut self, input: T, state: ButtonState) {
+        self.records.push_back(InputRecord {
+            input,
+            state,
+            time: self.now,
+        });
+    }
+
+    /// An iterator over the records, from the oldest to the most recent.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &InputRecord<T>> + '_ {
+        self.records.iter()
+    }
+
+    /// Returns the time `input` was last pressed, if it was recorded.
+    pub fn last_press(&self, input: T) -> Option<Duration> {
+        self.records
+            .iter()
+            .rev()
+            .find(|record| record.input == input && record.state.is_pressed())
+            .map(|record| record.time)
+    }
+
+    /// Returns `true` if `input` was pressed in the last `window`.
+    pub fn just_pressed_within(&self, input: T, window: Duration) -> bool {
+        self.last_press(input)
+            .is_some_and(|time| self.now.saturating_sub(time) <= window)
+    }
+
+    /// Forgets the last press of `input` if it happened in the last `window`, and returns whether
+    /// it did.
+    ///
+    /// Use this to act upon a buffered input only once.
+    pub fn consume_press(&mut self, input: T, window: Duration) -> bool {
+        let now = self.now;
+        let Some(index) = self.records.iter().rposition(|record| {
+            record.input == input
+                && record.state.is_pressed()
+                && now.saturating_sub(record.time) <= window
+        }) else {
+            return false;
+        };
+        self.records.remove(index);
+        true
+    }
+
+    /// Returns the most recent occurrence of `sequence`, if any.
+    pub fn find(&self, sequence: &InputSequence<T>) -> Option<SequenceMatch> {
+        if sequence.steps.is_empty() {
+            return None;
+        }
+        let presses: Vec<(T, Duration)> = self
+            .records
+            .iter()
+            .filter(|record| record.state.is_pressed())
+            .map(|record| (record.input, record.time))
+            .collect();
+        let window = (self.now.saturating_sub(sequence.buffer), self.now);
+        let (start, end) =
+            sequence.match_step(&presses, sequence.steps.len() - 1, presses.len(), window)?;
+        Some(SequenceMatch { start, end })
+    }
+
+    /// Returns `true` if `sequence` occurred, ending within its buffer.
+    pub fn matches(&self, sequence: &InputSequence<T>) -> bool {
+        self.find(sequence).is_some()
+    }
+
+    /// Forgets the records up to and including `time`.
+    ///
+    /// Use this with [`SequenceMatch::end`] to act upon a sequence only once.
+    pub fn forget_until(&mut self, time: Duration) {
+        while self
+            .records
+            .front()
+            .is_some_and(|record| record.time <= time)
+        {
+            self.records.pop_front();
+        }
+    }
+
+    /// Forgets all records.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+}
+
+/// A sequence of inputs, each step being one input or several pressed together, with timing
+/// windows measured in time rather than frames.
+///
+/// ```
+/// # use bevy_input::{history::InputSequence, prelude::*};
+/// # use core::time::Duration;
+/// // A, then B, then C and D together, with at most 300ms between steps.
+/// let sequence = InputSequence::new(Duration::from_millis(300))
+///     .then(KeyCode::KeyA)
+///     .then(KeyCode::KeyB)
+///     .then_chord([KeyCode::KeyC, KeyCode::KeyD]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputSequence<T> {
+    steps: Vec<Vec<T>>,
+    max_step_interval: Duration,
+    chord_window: Duration,
+    buffer: Duration,
+}
+
+/// How far apart presses of a chord can be by default.
+pub const DEFAULT_CHORD_WINDOW: Duration = Duration::from_millis(50);
+
+impl<T: Copy + Eq> InputSequence<T> {
+    /// Creates an empty sequence allowing at most `max_step_interval` between consecutive steps.
+    ///
+    /// The last step must also have happened within `max_step_interval`, unless changed with
+    /// [`with_buffer`](Self::with_buffer).
+    pub fn new(max_step_interval: Duration) -> Self {
+        Self {
+            steps: Vec::new(),
+            max_step_interval,
+            chord_window: DEFAULT_CHORD_WINDOW,
+            buffer: max_step_interval,
+        }
+    }
+
+    /// Adds a step pressing `input`.
+    pub fn then(mut self, input: T) -> Self {
+        self.steps.push(alloc::vec![input]);
+        self
+    }
+
+    /// Adds a step pressing all of `inputs`, within the chord window of each other and in any
+    /// order.
+    pub fn then_chord(mut self, inputs: impl IntoIterator<Item = T>) -> Self {
+        self.steps.push(inputs.into_iter().collect());
+        self
+    }
+
+    /// Sets how far apart the presses of a chord can be.
+    pub fn with_chord_window(mut self, chord_window: Duration) -> Self {
+        self.chord_window = chord_window;
+        self
+    }
+
+    /// Sets how long ago the last step can have happened for the sequence to match.
+    pub fn with_buffer(mut self, buffer: Duration) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// Returns the steps of the sequence.
+    pub fn steps(&self) -> &[Vec<T>] {
+        &self.steps
+    }
+
+    /// Matches `step` and the steps before it against the presses before `end`, with `step`
+    /// happening within `window`. Returns the time of the first press of the first step, and of
+    /// the last press of `step`.
+    fn match_step(
+        &self,
+        presses: &[(T, Duration)],
+        step: usize,
+        end: usize,
+        window: (Duration, Duration),
+    ) -> Option<(Duration, Duration)> {
+        let inputs = &self.steps[step];
+        for anchor in (0..end).rev() {
+            let (input, time) = presses[anchor];
+            if time < window.0 {
+                break;
+            }
+            if time > window.1 || !inputs.contains(&input) {
+                continue;
+            }
+
+            // Pair the anchor with the closest presses of the rest of the chord.
+            let mut first_index = anchor;
+            let mut first_time = time;
+            let mut last_time = time;
+            let mut complete = true;
+            for other in inputs.iter().filter(|other| **other != input) {
+                let Some(index) = (0..end).rev().find(|&index| {
+                    let (candidate, candidate_time) = presses[index];
+                    index != anchor
+                        && candidate == *other
+                        && candidate_time.abs_diff(time) <= self.chord_window
+                }) else {
+                    complete = false;
+                    break;
+                };
+                first_index = first_index.min(index);
+                first_time = first_time.min(presses[index].1);
+                last_time = last_time.max(presses[index].1);
+            }
+            if !complete {
+                continue;
+            }
+
+            if step == 0 {
+                return Some((first_time, last_time));
+            }
+            let previous_window = (
+                first_time.saturating_sub(self.max_step_interval),
+                first_time,
+            );
+            if let Some((start, _)) =
+                self.match_step(presses, step - 1, first_index, previous_window)
+            {
+                return Some((start, last_time));
+            }
+        }
+        None
+    }
+}
+
+/// When an [`InputSequence`] was performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceMatch {
+    /// The time of the first press of the sequence.
+    pub start: Duration,
+    /// The time of the last press of the sequence.
+    pub end: Duration,
+}
+
+/// One of the eight directions of a stick or directional pad, named after the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputDirection {
+    /// Up.
+    Up,
+    /// Up and right.
+    UpRight,
+    /// Right.
+    Right,
+    /// Down and right.
+    DownRight,
+    /// Down.
+    Down,
+    /// Down and left.
+    DownLeft,
+    /// Left.
+    Left,
+    /// Up and left.
+    UpLeft,
+}
+
+impl InputDirection {
+    /// Returns the direction of `vector`, with `y` pointing up, or `None` if its length is not
+    /// above `dead_zone`.
+    pub fn from_vec2(vector: Vec2, dead_zone: f32) -> Option<Self> {
+        if vector.length() <= dead_zone {
+            return None;
+        }
+        const DIRECTIONS: [InputDirection; 8] = [
+            InputDirection::Right,
+            InputDirection::UpRight,
+            InputDirection::Up,
+            InputDirection::UpLeft,
+            InputDirection::Left,
+            InputDirection::DownLeft,
+            InputDirection::Down,
+            InputDirection::DownRight,
+        ];
+        let eighths = vector.to_angle() / core::f32::consts::FRAC_PI_4;
+        let sector = (bevy_math::ops::round(eighths) as i32).rem_euclid(8);
+        Some(DIRECTIONS[sector as usize])
+    }
+}
+
+/// The recent input of a gamepad, recorded by the [`GamepadInputHistoryPlugin`].
+///
+/// Besides buttons, it records the changes of every axis and the direction of the left stick,
+/// or of the directional pad while any of its buttons is pressed.
+#[derive(Component, Debug, Clone)]
+pub struct GamepadInputHistory {
+    buttons: InputHistory<GamepadButton>,
+    directions: InputHistory<InputDirection>,
+    axes: HashMap<GamepadAxis, VecDeque<(Duration, f32)>>,
+    direction: Option<InputDirection>,
+    /// How far the left stick must be pushed to have a direction.
+    pub direction_dead_zone: f32,
+}
+
+impl Default for GamepadInputHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_RETENTION)
+    }
+}
+
+impl GamepadInputHistory {
+    /// Creates an empty history keeping records for `retention`.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            buttons: InputHistory::new(retention),
+            directions: InputHistory::new(retention),
+            axes: HashMap::default(),
+            direction: None,
+            direction_dead_zone: 0.5,
+        }
+    }
+
+    /// Returns the history of the buttons.
+    pub fn buttons(&self) -> &InputHistory<GamepadButton> {
+        &self.buttons
+    }
+
+    /// Returns the history of the buttons, mutably.
+    pub fn buttons_mut(&mut self) -> &mut InputHistory<GamepadButton> {
+        &mut self.buttons
+    }
+
+    /// Returns the history of the directions, where each new direction is a press.
+    pub fn directions(&self) -> &InputHistory<InputDirection> {
+        &self.directions
+    }
+
+    /// Returns the history of the directions, mutably.
+    pub fn directions_mut(&mut self) -> &mut InputHistory<InputDirection> {
+        &mut self.directions
+    }
+
+    /// An iterator over the recorded values of `axis` and the time they were seen, from the
+    /// oldest to the most recent.
+    pub fn axis(&self, axis: GamepadAxis) -> impl DoubleEndedIterator<Item = (Duration, f32)> + '_ {
+        self.axes.get(&axis).into_iter().flatten().copied()
+    }
+
+    /// Returns the current direction, if any.
+    pub fn direction(&self) -> Option<InputDirection> {
+        self.direction
+    }
+
+    fn update(&mut self, now: Duration, gamepad: &Gamepad) {
+        self.buttons.update(now);
+        self.directions.update(now);
+        let oldest = now.saturating_sub(self.buttons.retention());
+        for samples in self.axes.values_mut() {
+            while samples.front().is_some_and(|(time, _)| *time < oldest) {
+                samples.pop_front();
+            }
+        }
+
+        for button in gamepad.get_just_pressed() {
+            self.buttons.record(*button, ButtonState::Pressed);
+        }
+        for button in gamepad.get_just_released() {
+            self.buttons.record(*button, ButtonState::Released);
+        }
+
+        for axis in GamepadAxis::all() {
+            let Some(value) = gamepad.get_unclamped(axis) else {
+                continue;
+            };
+            let samples = self.axes.entry(axis).or_default();
+            if samples.back().is_none_or(|(_, last)| *last != value) {
+                samples.push_back((now, value));
+            }
+        }
+
+        let dpad = Vec2::new(
+            f32::from(gamepad.pressed(GamepadButton::DPadRight))
+                - f32::from(gamepad.pressed(GamepadButton::DPadLeft)),
+            f32::from(gamepad.pressed(GamepadButton::DPadUp))
+                - f32::from(gamepad.pressed(GamepadButton::DPadDown)),
+        );
+        let direction = if dpad != Vec2::ZERO {
+            InputDirection::from_vec2(dpad, 0.0)
+        } else {
+            InputDirection::from_vec2(gamepad.left_stick(), self.direction_dead_zone)
+        };
+        if direction != self.direction {
+            if let Some(previous) = self.direction {
+                self.directions.record(previous, ButtonState::Released);
+            }
+            if let Some(direction) = direction {
+                self.directions.record(direction, ButtonState::Pressed);
+            }
+            self.direction = direction;
+        }
+    }
+}
+
+/// Records the presses and releases of the [`ButtonInput<T>`] resource into the
+/// [`InputHistory<T>`] resource, at the current [`Time<Real>`].
+pub fn record_input_history<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    input: Res<ButtonInput<T>>,
+    time: Res<Time<Real>>,
+    mut history: ResMut<InputHistory<T>>,
+) {
+    history.update(time.elapsed());
+    for pressed in input.get_just_pressed() {
+        history.record(*pressed, ButtonState::Pressed);
+    }
+    for released in input.get_just_released() {
+        history.record(*released, ButtonState::Released);
+    }
+}
+
+/// Records the input of every [`Gamepad`] into its [`GamepadInputHistory`], at the current
+/// [`Time<Real>`].
+pub fn record_gamepad_input_history(
+    time: Res<Time<Real>>,
+    mut gamepads: Query<(&Gamepad, &mut GamepadInputHistory)>,
+) {
+    let now = time.elapsed();
+    for (gamepad, mut history) in &mut gamepads {
+        history.update(now, gamepad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        keyboard::KeyCode,
+        virtual_input::{VirtualInput, VirtualInputPlugin},
+        InputPlugin,
+    };
+
+    fn update(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            InputPlugin,
+            VirtualInputPlugin,
+            InputHistoryPlugin::<KeyCode>::default(),
+            GamepadInputHistoryPlugin::default(),
+        ))
+        .insert_resource(Time::<Real>::default());
+        update(&mut app, 0);
+        app
+    }
+
+    #[test]
+    fn buffered_press() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .tap_key(KeyCode::Space);
+        update(&mut app, 16);
+        update(&mut app, 100);
+
+        let history = app.world().resource::<InputHistory<KeyCode>>();
+        assert!(history.just_pressed_within(KeyCode::Space, Duration::from_millis(150)));
+        assert!(!history.just_pressed_within(KeyCode::Space, Duration::from_millis(50)));
+
+        update(&mut app, 100);
+        let mut history = app.world_mut().resource_mut::<InputHistory<KeyCode>>();
+        assert!(!history.just_pressed_within(KeyCode::Space, Duration::from_millis(150)));
+        assert!(history.consume_press(KeyCode::Space, Duration::from_millis(250)));
+        assert!(!history.consume_press(KeyCode::Space, Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn sequence_timing_is_independent_of_frames() {
+        let mut app = app();
+        let sequence = InputSequence::new(Duration::from_millis(200))
+            .then(KeyCode::KeyA)
+            .then(KeyCode::KeyB)
+            .then_chord([KeyCode::KeyC, KeyCode::KeyD]);
+
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .tap_key(KeyCode::KeyA)
+            .tap_key(KeyCode::KeyB)
+            .press_key(KeyCode::KeyC)
+            .wait(1)
+            .press_key(KeyCode::KeyD);
+        update(&mut app, 150);
+        update(&mut app, 150);
+        update(&mut app, 150);
+        update(&mut app, 30);
+        let history = app.world().resource::<InputHistory<KeyCode>>();
+        let found = history.find(&sequence).unwrap();
+        assert_eq!(found.end - found.start, Duration::from_millis(330));
+
+        // The same sequence, but too slow.
+        let mut app = self::app();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .tap_key(KeyCode::KeyA)
+            .tap_key(KeyCode::KeyB)
+            .press_key(KeyCode::KeyC)
+            .press_key(KeyCode::KeyD);
+        update(&mut app, 16);
+        update(&mut app, 250);
+        update(&mut app, 16);
+        let history = app.world().resource::<InputHistory<KeyCode>>();
+        assert!(!history.matches(&sequence));
+    }
+
+    #[test]
+    fn gamepad_stick_directions() {
+        let mut app = app();
+        let gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .connect_gamepad(gamepad)
+            .wait(1)
+            .set_axis(gamepad, GamepadAxis::LeftStickY, -1.0)
+            .wait(1)
+            .set_axis(gamepad, GamepadAxis::LeftStickX, 1.0)
+            .wait(1)
+            .set_axis(gamepad, GamepadAxis::LeftStickY, 0.0)
+            .press_button(gamepad, GamepadButton::West);
+        for _ in 0..5 {
+            update(&mut app, 16);
+        }
+
+        let history = app.world().get::<GamepadInputHistory>(gamepad).unwrap();
+        let sequence = InputSequence::new(Duration::from_millis(100))
+            .then(InputDirection::Down)
+            .then(InputDirection::DownRight)
+            .then_chord([InputDirection::Right]);
+        assert!(history
+            .directions()
+            .matches(&sequence.clone().with_buffer(Duration::from_millis(50))));
+        assert!(history
+            .buttons()
+            .just_pressed_within(GamepadButton::West, Duration::from_millis(50)));
+        assert_eq!(history.direction(), Some(InputDirection::Right));
+        assert_eq!(
+            history
+                .axis(GamepadAxis::LeftStickY)
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>(),
+            [0.0, -1.0, 0.0]
+        );
+    }
+}
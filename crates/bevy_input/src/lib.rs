@@ -17,15 +17,18 @@ extern crate std;
 
 extern crate alloc;
 
+pub mod action;
 mod axis;
 mod button_input;
 /// Common run conditions
 pub mod common_conditions;
 pub mod gamepad;
 pub mod gestures;
+pub mod history;
 pub mod keyboard;
 pub mod mouse;
 pub mod touch;
+pub mod virtual_input;
 
 pub use axis::*;
 pub use button_input::*;
@@ -106,6 +109,9 @@ impl Plugin for InputPlugin {
             .add_event::<RotationGesture>()
             .add_event::<DoubleTapGesture>()
             .add_event::<PanGesture>()
+            .add_event::<TapGesture>()
+            .add_event::<LongPressGesture>()
+            .add_event::<SwipeGesture>()
             // gamepad
             .add_event::<GamepadEvent>()
             .add_event::<GamepadConnectionEvent>()
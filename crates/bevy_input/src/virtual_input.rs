@@ -0,0 +1,715 @@
+//! Synthetic input devices for driving an app without a windowing or gamepad backend.
+//!
+//! The [`VirtualInput`] resource schedules keyboard, mouse and gamepad input over the coming
+//! frames, and the [`VirtualInputPlugin`] writes it as the exact same events `bevy_winit` and
+//! `bevy_gilrs` would. Everything downstream of those events, from [`ButtonInput`](crate::ButtonInput)
+//! to the [`Gamepad`](crate::gamepad::Gamepad) component, behaves as if a real device was used,
+//! which makes gameplay and UI input testable in headless apps.
+//!
+//! Scheduling starts at the next frame and moves forward with [`VirtualInput::wait`] and the
+//! methods spanning several frames, such as [`VirtualInput::hold_key`] or [`VirtualInput::sweep_axis`].
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::{prelude::*, virtual_input::{VirtualInput, VirtualInputPlugin}, InputPlugin};
+//! let mut app = App::new();
+//! app.add_plugins((InputPlugin, VirtualInputPlugin));
+//!
+//! let gamepad = app.world_mut().spawn_empty().id();
+//! app.world_mut()
+//!     .resource_mut::<VirtualInput>()
+//!     .connect_gamepad(gamepad)
+//!     .wait(1)
+//!     .hold_key(KeyCode::Space, 2)
+//!     .press_button(gamepad, GamepadButton::South);
+//!
+//! app.update(); // The gamepad connects.
+//! app.update(); // Space is pressed.
+//! assert!(app.world().resource::<ButtonInput<KeyCode>>().just_pressed(KeyCode::Space));
+//!
+//! app.update();
+//! app.update(); // Space is released and the south button pressed.
+//! assert!(app.world().resource::<ButtonInput<KeyCode>>().just_released(KeyCode::Space));
+//! assert!(app.world().get::<Gamepad>(gamepad).unwrap().just_pressed(GamepadButton::South));
+//! ```
+
+use crate::{
+    gamepad::{
+        GamepadAxis, GamepadButton, GamepadConnection, GamepadConnectionEvent,
+        RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent, RawGamepadEvent,
+    },
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey, NativeKeyCode},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    ButtonState, InputSystems,
+};
+use alloc::{collections::VecDeque, string::String, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+
+#[cfg(not(feature = "smol_str"))]
+use alloc::string::String as SmolStr;
+
+#[cfg(feature = "smol_str")]
+use smol_str::SmolStr;
+
+/// The name reported by gamepads connected through [`VirtualInput::connect_gamepad`].
+pub const VIRTUAL_GAMEPAD_NAME: &str = "Virtual Gamepad";
+
+/// Adds the [`VirtualInput`] resource and writes its scheduled input every frame,
+/// right before the [`InputSystems`].
+#[derive(Default)]
+pub struct VirtualInputPlugin;
+
+impl Plugin for VirtualInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VirtualInput>()
+            .add_systems(PreUpdate, write_virtual_input.before(InputSystems));
+    }
+}
+
+/// A single input scheduled by [`VirtualInput`].
+#[derive(Debug, Clone)]
+enum VirtualEvent {
+    Keyboard(KeyboardInput),
+    MouseButton(MouseButtonInput),
+    MouseMotion(MouseMotion),
+    MouseWheel(MouseWheel),
+    Gamepad(RawGamepadEvent),
+}
+
+/// Synthetic keyboard, mouse and gamepad input, scheduled frame by frame.
+///
+/// Every method schedules its input at the current frame of the schedule, which starts at the next
+/// frame to run. Methods spanning several frames, like [`hold_key`](Self::hold_key) or
+/// [`sweep_axis`](Self::sweep_axis), move the current frame to their last frame, so calls can be
+/// chained to describe a sequence. [`wait`](Self::wait) moves it by a given number of frames.
+///
+/// Keyboard and mouse events are sent to the [`window`](Self::window) entity. Gamepads are
+/// identified by an entity that the caller spawns, exactly like `bevy_gilrs` spawns an empty
+/// entity for each gamepad it detects.
+///
+/// Key repeats and modifiers are not synthesized: typing an uppercase letter does not press
+/// shift, and holding a key sends a single press and a single release.
+#[derive(Resource, Debug, Clone)]
+pub struct VirtualInput {
+    /// The window keyboard and mouse events are sent to.
+    pub window: Entity,
+    frames: VecDeque<Vec<VirtualEvent>>,
+    cursor: usize,
+}
+
+impl Default for VirtualInput {
+    fn default() -> Self {
+        Self {
+            window: Entity::PLACEHOLDER,
+            frames: VecDeque::new(),
+            cursor: 0,
+        }
+    }
+}
+
+impl VirtualInput {
+    /// Creates an empty schedule whose keyboard and mouse events are sent to `window`.
+    pub fn new(window: Entity) -> Self {
+        Self {
+            window,
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if no input remains to be written.
+    pub fn is_idle(&self) -> bool {
+        self.frames.iter().all(Vec::is_empty)
+    }
+
+    /// Returns the number of frames until the current frame of the schedule.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Drops all scheduled input and moves the current frame back to the next frame.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.cursor = 0;
+    }
+
+    /// Moves the current frame `frames` frames forward.
+    pub fn wait(&mut self, frames: usize) -> &mut Self {
+        self.cursor += frames;
+        self
+    }
+
+    /// Presses `key_code`, with the logical key and text of a US keyboard layout.
+    pub fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        let logical_key = logical_key(key_code);
+        let text = key_text(&logical_key);
+        self.key_event(key_code, logical_key, ButtonState::Pressed, text)
+    }
+
+    /// Releases `key_code`, with the logical key of a US keyboard layout.
+    pub fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.key_event(key_code, logical_key(key_code), ButtonState::Released, None)
+    }
+
+    /// Presses the physical key `key_code`, producing `logical_key`.
+    ///
+    /// Use this to simulate a keyboard layout other than US.
+    pub fn press_logical_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self {
+        let text = key_text(&logical_key);
+        self.key_event(key_code, logical_key, ButtonState::Pressed, text)
+    }
+
+    /// Releases the physical key `key_code`, which produced `logical_key`.
+    pub fn release_logical_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self {
+        self.key_event(key_code, logical_key, ButtonState::Released, None)
+    }
+
+    /// Presses `key_code` and releases it `frames` frames later, which becomes the current frame.
+    pub fn hold_key(&mut self, key_code: KeyCode, frames: usize) -> &mut Self {
+        self.press_key(key_code).wait(frames).release_key(key_code)
+    }
+
+    /// Presses `key_code` and releases it on the following frame, which becomes the current frame.
+    pub fn tap_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.hold_key(key_code, 1)
+    }
+
+    /// Types `text`, one character every two frames.
+    ///
+    /// Each character is pressed, then released on the next frame. The current frame ends up on the
+    /// frame after the last release. Characters without a key on a US keyboard are sent with
+    /// [`KeyCode::Unidentified`]. Line breaks press [`KeyCode::Enter`], whose text is `"\r"` as
+    /// with `winit`.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for character in text.chars() {
+            let key_code = char_key_code(character);
+            let logical_key = match character {
+                ' ' => Key::Space,
+                '\n' | '\r' => Key::Enter,
+                '\t' => Key::Tab,
+                _ => Key::Character(char_str(character)),
+            };
+            let text = key_text(&logical_key);
+            self.key_event(key_code, logical_key.clone(), ButtonState::Pressed, text)
+                .wait(1)
+                .key_event(key_code, logical_key, ButtonState::Released, None)
+                .wait(1);
+        }
+        self
+    }
+
+    /// Presses the mouse `button`.
+    pub fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.mouse_button_event(button, ButtonState::Pressed)
+    }
+
+    /// Releases the mouse `button`.
+    pub fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.mouse_button_event(button, ButtonState::Released)
+    }
+
+    /// Presses the mouse `button` and releases it on the following frame, which becomes the
+    /// current frame.
+    pub fn click_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.press_mouse_button(button)
+            .wait(1)
+            .release_mouse_button(button)
+    }
+
+    /// Moves the mouse by `delta`, in the units [`MouseMotion`] uses.
+    pub fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.push(VirtualEvent::MouseMotion(MouseMotion { delta }))
+    }
+
+    /// Scrolls the mouse wheel by `x` and `y`, in `unit`s.
+    pub fn scroll(&mut self, unit: MouseScrollUnit, x: f32, y: f32) -> &mut Self {
+        let window = self.window;
+        self.push(VirtualEvent::MouseWheel(MouseWheel { unit, x, y, window }))
+    }
+
+    /// Connects the `gamepad` entity as a gamepad named [`VIRTUAL_GAMEPAD_NAME`].
+    pub fn connect_gamepad(&mut self, gamepad: Entity) -> &mut Self {
+        self.connect_named_gamepad(gamepad, VIRTUAL_GAMEPAD_NAME, None, None)
+    }
+
+    /// Connects the `gamepad` entity with the given name and USB identifiers.
+    pub fn connect_named_gamepad(
+        &mut self,
+        gamepad: Entity,
+        name: impl Into<String>,
+        vendor_id: Option<u16>,
+        product_id: Option<u16>,
+    ) -> &mut Self {
+        let connection = GamepadConnection::Connected {
+            name: name.into(),
+            vendor_id,
+            product_id,
+        };
+        self.push(VirtualEvent::Gamepad(RawGamepadEvent::Connection(
+            GamepadConnectionEvent::new(gamepad, connection),
+        )))
+    }
+
+    /// Disconnects the `gamepad` entity.
+    pub fn disconnect_gamepad(&mut self, gamepad: Entity) -> &mut Self {
+        self.push(VirtualEvent::Gamepad(RawGamepadEvent::Connection(
+            GamepadConnectionEvent::new(gamepad, GamepadConnection::Disconnected),
+        )))
+    }
+
+    /// Sets the raw value of a `button` of `gamepad`, between `0.0` and `1.0`.
+    pub fn set_button(&mut self, gamepad: Entity, button: GamepadButton, value: f32) -> &mut Self {
+        self.push(VirtualEvent::Gamepad(RawGamepadEvent::Button(
+            RawGamepadButtonChangedEvent::new(gamepad, button, value),
+        )))
+    }
+
+    /// Fully presses a `button` of `gamepad`.
+    pub fn press_button(&mut self, gamepad: Entity, button: GamepadButton) -> &mut Self {
+        self.set_button(gamepad, button, 1.0)
+    }
+
+    /// Fully releases a `button` of `gamepad`.
+    pub fn release_button(&mut self, gamepad: Entity, button: GamepadButton) -> &mut Self {
+        self.set_button(gamepad, button, 0.0)
+    }
+
+    /// Presses a `button` of `gamepad` and releases it `frames` frames later, which becomes the
+    /// current frame.
+    pub fn hold_button(
+        &mut self,
+        gamepad: Entity,
+        button: GamepadButton,
+        frames: usize,
+    ) -> &mut Self {
+        self.press_button(gamepad, button)
+            .wait(frames)
+            .release_button(gamepad, button)
+    }
+
+    /// Sets the raw value of an `axis` of `gamepad`, between `-1.0` and `1.0`.
+    pub fn set_axis(&mut self, gamepad: Entity, axis: GamepadAxis, value: f32) -> &mut Self {
+        self.push(VirtualEvent::Gamepad(RawGamepadEvent::Axis(
+            RawGamepadAxisChangedEvent::new(gamepad, axis, value),
+        )))
+    }
+
+    /// Moves an `axis` of `gamepad` linearly from `from` to `to` over `frames` frames.
+    ///
+    /// The axis is set to `from` on the current frame and reaches `to` `frames` frames later,
+    /// which becomes the current frame.
+    pub fn sweep_axis(
+        &mut self,
+        gamepad: Entity,
+        axis: GamepadAxis,
+        from: f32,
+        to: f32,
+        frames: usize,
+    ) -> &mut Self {
+        if frames == 0 {
+            return self.set_axis(gamepad, axis, to);
+        }
+        for frame in 0..frames {
+            let value = from + (to - from) * frame as f32 / frames as f32;
+            self.set_axis(gamepad, axis, value).wait(1);
+        }
+        self.set_axis(gamepad, axis, to)
+    }
+
+    /// Moves the stick made of `x_axis` and `y_axis` of `gamepad` linearly from `from` to `to`
+    /// over `frames` frames, like [`sweep_axis`](Self::sweep_axis).
+    pub fn sweep_stick(
+        &mut self,
+        gamepad: Entity,
+        (x_axis, y_axis): (GamepadAxis, GamepadAxis),
+        from: Vec2,
+        to: Vec2,
+        frames: usize,
+    ) -> &mut Self {
+        let start = self.cursor;
+        self.sweep_axis(gamepad, x_axis, from.x, to.x, frames);
+        self.cursor = start;
+        self.sweep_axis(gamepad, y_axis, from.y, to.y, frames)
+    }
+
+    fn key_event(
+        &mut self,
+        key_code: KeyCode,
+        logical_key: Key,
+        state: ButtonState,
+        text: Option<SmolStr>,
+    ) -> &mut Self {
+        let window = self.window;
+        self.push(VirtualEvent::Keyboard(KeyboardInput {
+            key_code,
+            logical_key,
+            state,
+            text,
+            repeat: false,
+            window,
+        }))
+    }
+
+    fn mouse_button_event(&mut self, button: MouseButton, state: ButtonState) -> &mut Self {
+        let window = self.window;
+        self.push(VirtualEvent::MouseButton(MouseButtonInput {
+            button,
+            state,
+            window,
+        }))
+    }
+
+    fn push(&mut self, event: VirtualEvent) -> &mut Self {
+        if self.frames.len() <= self.cursor {
+            self.frames.resize_with(self.cursor + 1, Vec::new);
+        }
+        self.frames[self.cursor].push(event);
+        self
+    }
+
+    fn next_frame(&mut self) -> Vec<VirtualEvent> {
+        self.cursor = self.cursor.saturating_sub(1);
+        self.frames.pop_front().unwrap_or_default()
+    }
+}
+
+/// Writes the input [`VirtualInput`] scheduled for this frame.
+///
+/// Gamepad events are written both as [`RawGamepadEvent`]s and as their specific event, like
+/// `bevy_gilrs` does.
+pub fn write_virtual_input(
+    mut virtual_input: ResMut<VirtualInput>,
+    mut keyboard_events: EventWriter<KeyboardInput>,
+    mut mouse_button_events: EventWriter<MouseButtonInput>,
+    mut mouse_motion_events: EventWriter<MouseMotion>,
+    mut mouse_wheel_events: EventWriter<MouseWheel>,
+    mut gamepad_events: EventWriter<RawGamepadEvent>,
+    mut connection_events: EventWriter<GamepadConnectionEvent>,
+    mut button_events: EventWriter<RawGamepadButtonChangedEvent>,
+    mut axis_events: EventWriter<RawGamepadAxisChangedEvent>,
+) {
+    for event in virtual_input.next_frame() {
+        match event {
+            VirtualEvent::Keyboard(event) => {
+                keyboard_events.write(event);
+            }
+            VirtualEvent::MouseButton(event) => {
+                mouse_button_events.write(event);
+            }
+            VirtualEvent::MouseMotion(event) => {
+                mouse_motion_events.write(event);
+            }
+            VirtualEvent::MouseWheel(event) => {
+                mouse_wheel_events.write(event);
+            }
+            VirtualEvent::Gamepad(event) => {
+                match &event {
+                    RawGamepadEvent::Connection(event) => {
+                        connection_events.write(event.clone());
+                    }
+                    RawGamepadEvent::Button(event) => {
+                        button_events.write(*event);
+                    }
+                    RawGamepadEvent::Axis(event) => {
+                        axis_events.write(*event);
+                    }
+                }
+                gamepad_events.write(event);
+            }
+        }
+    }
+}
+
+/// Returns the logical key `key_code` produces on a US keyboard layout, without modifiers.
+fn logical_key(key_code: KeyCode) -> Key {
+    let character = match key_code {
+        KeyCode::KeyA => 'a',
+        KeyCode::KeyB => 'b',
+        KeyCode::KeyC => 'c',
+        KeyCode::KeyD => 'd',
+        KeyCode::KeyE => 'e',
+        KeyCode::KeyF => 'f',
+        KeyCode::KeyG => 'g',
+        KeyCode::KeyH => 'h',
+        KeyCode::KeyI => 'i',
+        KeyCode::KeyJ => 'j',
+        KeyCode::KeyK => 'k',
+        KeyCode::KeyL => 'l',
+        KeyCode::KeyM => 'm',
+        KeyCode::KeyN => 'n',
+        KeyCode::KeyO => 'o',
+        KeyCode::KeyP => 'p',
+        KeyCode::KeyQ => 'q',
+        KeyCode::KeyR => 'r',
+        KeyCode::KeyS => 's',
+        KeyCode::KeyT => 't',
+        KeyCode::KeyU => 'u',
+        KeyCode::KeyV => 'v',
+        KeyCode::KeyW => 'w',
+        KeyCode::KeyX => 'x',
+        KeyCode::KeyY => 'y',
+        KeyCode::KeyZ => 'z',
+        KeyCode::Digit0 | KeyCode::Numpad0 => '0',
+        KeyCode::Digit1 | KeyCode::Numpad1 => '1',
+        KeyCode::Digit2 | KeyCode::Numpad2 => '2',
+        KeyCode::Digit3 | KeyCode::Numpad3 => '3',
+        KeyCode::Digit4 | KeyCode::Numpad4 => '4',
+        KeyCode::Digit5 | KeyCode::Numpad5 => '5',
+        KeyCode::Digit6 | KeyCode::Numpad6 => '6',
+        KeyCode::Digit7 | KeyCode::Numpad7 => '7',
+        KeyCode::Digit8 | KeyCode::Numpad8 => '8',
+        KeyCode::Digit9 | KeyCode::Numpad9 => '9',
+        KeyCode::Minus | KeyCode::NumpadSubtract => '-',
+        KeyCode::Equal => '=',
+        KeyCode::BracketLeft => '[',
+        KeyCode::BracketRight => ']',
+        KeyCode::Backslash => '\\',
+        KeyCode::Semicolon => ';',
+        KeyCode::Quote => '\'',
+        KeyCode::Backquote => '`',
+        KeyCode::Comma => ',',
+        KeyCode::Period | KeyCode::NumpadDecimal => '.',
+        KeyCode::Slash | KeyCode::NumpadDivide => '/',
+        KeyCode::NumpadAdd => '+',
+        KeyCode::NumpadMultiply => '*',
+        KeyCode::Space => return Key::Space,
+        KeyCode::Enter | KeyCode::NumpadEnter => return Key::Enter,
+        KeyCode::Tab => return Key::Tab,
+        KeyCode::Backspace => return Key::Backspace,
+        KeyCode::Delete => return Key::Delete,
+        KeyCode::Escape => return Key::Escape,
+        KeyCode::ArrowUp => return Key::ArrowUp,
+        KeyCode::ArrowDown => return Key::ArrowDown,
+        KeyCode::ArrowLeft => return Key::ArrowLeft,
+        KeyCode::ArrowRight => return Key::ArrowRight,
+        KeyCode::Home => return Key::Home,
+        KeyCode::End => return Key::End,
+        KeyCode::PageUp => return Key::PageUp,
+        KeyCode::PageDown => return Key::PageDown,
+        KeyCode::Insert => return Key::Insert,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => return Key::Shift,
+        KeyCode::ControlLeft | KeyCode::ControlRight => return Key::Control,
+        KeyCode::AltLeft | KeyCode::AltRight => return Key::Alt,
+        KeyCode::SuperLeft | KeyCode::SuperRight => return Key::Super,
+        KeyCode::CapsLock => return Key::CapsLock,
+        KeyCode::F1 => return Key::F1,
+        KeyCode::F2 => return Key::F2,
+        KeyCode::F3 => return Key::F3,
+        KeyCode::F4 => return Key::F4,
+        KeyCode::F5 => return Key::F5,
+        KeyCode::F6 => return Key::F6,
+        KeyCode::F7 => return Key::F7,
+        KeyCode::F8 => return Key::F8,
+        KeyCode::F9 => return Key::F9,
+        KeyCode::F10 => return Key::F10,
+        KeyCode::F11 => return Key::F11,
+        KeyCode::F12 => return Key::F12,
+        _ => return Key::Unidentified(NativeKey::Unidentified),
+    };
+    Key::Character(char_str(character))
+}
+
+/// Returns the text a key press producing `logical_key` inserts, if any.
+fn key_text(logical_key: &Key) -> Option<SmolStr> {
+    match logical_key {
+        Key::Character(text) => Some(text.clone()),
+        Key::Space => Some(" ".into()),
+        Key::Enter => Some("\r".into()),
+        Key::Tab => Some("\t".into()),
+        _ => None,
+    }
+}
+
+/// Returns the physical key typing `character` uses on a US keyboard layout.
+fn char_key_code(character: char) -> KeyCode {
+    match character.to_ascii_lowercase() {
+        'a' => KeyCode::KeyA,
+        'b' => KeyCode::KeyB,
+        'c' => KeyCode::KeyC,
+        'd' => KeyCode::KeyD,
+        'e' => KeyCode::KeyE,
+        'f' => KeyCode::KeyF,
+        'g' => KeyCode::KeyG,
+        'h' => KeyCode::KeyH,
+        'i' => KeyCode::KeyI,
+        'j' => KeyCode::KeyJ,
+        'k' => KeyCode::KeyK,
+        'l' => KeyCode::KeyL,
+        'm' => KeyCode::KeyM,
+        'n' => KeyCode::KeyN,
+        'o' => KeyCode::KeyO,
+        'p' => KeyCode::KeyP,
+        'q' => KeyCode::KeyQ,
+        'r' => KeyCode::KeyR,
+        's' => KeyCode::KeyS,
+        't' => KeyCode::KeyT,
+        'u' => KeyCode::KeyU,
+        'v' => KeyCode::KeyV,
+        'w' => KeyCode::KeyW,
+        'x' => KeyCode::KeyX,
+        'y' => KeyCode::KeyY,
+        'z' => KeyCode::KeyZ,
+        '0' | ')' => KeyCode::Digit0,
+        '1' | '!' => KeyCode::Digit1,
+        '2' | '@' => KeyCode::Digit2,
+        '3' | '#' => KeyCode::Digit3,
+        '4' | '$' => KeyCode::Digit4,
+        '5' | '%' => KeyCode::Digit5,
+        '6' | '^' => KeyCode::Digit6,
+        '7' | '&' => KeyCode::Digit7,
+        '8' | '*' => KeyCode::Digit8,
+        '9' | '(' => KeyCode::Digit9,
+        '-' | '_' => KeyCode::Minus,
+        '=' | '+' => KeyCode::Equal,
+        '[' | '{' => KeyCode::BracketLeft,
+        ']' | '}' => KeyCode::BracketRight,
+        '\\' | '|' => KeyCode::Backslash,
+        ';' | ':' => KeyCode::Semicolon,
+        '\'' | '"' => KeyCode::Quote,
+        '`' | '~' => KeyCode::Backquote,
+        ',' | '<' => KeyCode::Comma,
+        '.' | '>' => KeyCode::Period,
+        '/' | '?' => KeyCode::Slash,
+        ' ' => KeyCode::Space,
+        '\n' | '\r' => KeyCode::Enter,
+        '\t' => KeyCode::Tab,
+        _ => KeyCode::Unidentified(NativeKeyCode::Unidentified),
+    }
+}
+
+fn char_str(character: char) -> SmolStr {
+    let mut buffer = [0; 4];
+    SmolStr::from(&*character.encode_utf8(&mut buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{VirtualInput, VirtualInputPlugin};
+    use crate::{
+        gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadConnectionEvent},
+        keyboard::{Key, KeyCode, KeyboardInput},
+        ButtonInput, InputPlugin,
+    };
+    use alloc::{string::String, vec::Vec};
+    use bevy_app::{App, Update};
+    use bevy_ecs::prelude::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, VirtualInputPlugin));
+        app
+    }
+
+    #[test]
+    fn hold_key_over_frames() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .hold_key(KeyCode::KeyA, 3);
+
+        app.update();
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_pressed(KeyCode::KeyA));
+        let logical_keys = app.world().resource::<ButtonInput<Key>>();
+        assert!(logical_keys.just_pressed(Key::Character("a".into())));
+
+        app.update();
+        app.update();
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.pressed(KeyCode::KeyA));
+        assert!(!keys.just_pressed(KeyCode::KeyA));
+
+        app.update();
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_released(KeyCode::KeyA));
+        assert!(!app
+            .world()
+            .resource::<ButtonInput<Key>>()
+            .get_pressed()
+            .any(|_| true));
+        assert!(app.world().resource::<VirtualInput>().is_idle());
+    }
+
+    #[derive(Resource, Default)]
+    struct Typed(String);
+
+    fn collect_text(mut events: EventReader<KeyboardInput>, mut typed: ResMut<Typed>) {
+        for event in events.read() {
+            if let Some(text) = &event.text {
+                typed.0.push_str(text);
+            }
+        }
+    }
+
+    #[test]
+    fn type_text() {
+        let mut app = app();
+        app.init_resource::<Typed>()
+            .add_systems(Update, collect_text);
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .type_text("Hi, 42!");
+
+        while !app.world().resource::<VirtualInput>().is_idle() {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Typed>().0, "Hi, 42!");
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.get_pressed().next().is_none());
+    }
+
+    #[test]
+    fn enter_text_matches_winit() {
+        let mut app = app();
+        app.init_resource::<Typed>()
+            .add_systems(Update, collect_text);
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .type_text("a\n")
+            .tap_key(KeyCode::Enter);
+
+        while !app.world().resource::<VirtualInput>().is_idle() {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Typed>().0, "a\r\r");
+    }
+
+    #[test]
+    fn gamepad_connect_press_and_sweep() {
+        let mut app = app();
+        let gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<VirtualInput>()
+            .connect_gamepad(gamepad)
+            .wait(1)
+            .press_button(gamepad, GamepadButton::South)
+            .sweep_axis(gamepad, GamepadAxis::LeftStickX, 0.0, 1.0, 4)
+            .wait(1)
+            .disconnect_gamepad(gamepad);
+
+        app.update();
+        let connections = app
+            .world()
+            .resource::<Events<GamepadConnectionEvent>>()
+            .iter_current_update_events()
+            .filter(|event| event.gamepad == gamepad)
+            .count();
+        assert_eq!(connections, 1);
+        assert!(app.world().get::<Gamepad>(gamepad).is_some());
+
+        let mut values = Vec::new();
+        for _ in 0..5 {
+            app.update();
+            let state = app.world().get::<Gamepad>(gamepad).unwrap();
+            assert!(state.pressed(GamepadButton::South));
+            values.push(state.get(GamepadAxis::LeftStickX).unwrap());
+        }
+        assert_eq!(values.last(), Some(&1.0));
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        app.update();
+        assert!(app.world().get::<Gamepad>(gamepad).is_none());
+    }
+}
@@ -13,6 +13,7 @@
 //! * Methods for getting and setting input focus via [`InputFocus`] and [`IsFocusedHelper`].
 //! * A generic [`FocusedInput`] event for input events which bubble up from the focused entity.
 //! * Various navigation frameworks for moving input focus between entities based on user input, such as [`tab_navigation`] and [`directional_navigation`].
+//! * A [`text_edit`] model for text fields, which handles keyboard and IME input for the focused entity.
 //!
 //! This crate does *not* provide any integration with UI widgets: this is the responsibility of the widget crate,
 //! which should depend on [`bevy_input_focus`](crate).
@@ -24,6 +25,7 @@ extern crate alloc;
 
 pub mod directional_navigation;
 pub mod tab_navigation;
+pub mod text_edit;
 
 // This module is too small / specific to be exported by the crate,
 // but it's nice to have it separate for code organization.
@@ -0,0 +1,895 @@
+//! A text editing model for text fields, with support for IME composition.
+//!
+//! [`TextEdit`] holds the text of an editable field along with its cursor, selection, IME preedit
+//! and undo history. It knows nothing about how the text is laid out or rendered: widgets read it
+//! to display the text, the cursor, the selection and the preedit, and may position the IME
+//! candidate window with [`Window::ime_position`].
+//!
+//! The [`TextEditPlugin`] edits the [`TextEdit`] of the focused entity from the [`KeyboardInput`]
+//! and [`Ime`] events dispatched by the [`InputDispatchPlugin`](crate::InputDispatchPlugin), which
+//! it requires. It also enables IME on the primary window when an entity with a [`TextEdit`]
+//! gains [`InputFocus`], and disables it when the focus moves elsewhere.
+//!
+//! Keyboard input is translated into [`TextEditAction`]s, which can also be applied directly,
+//! for instance from an input-action-mapping framework:
+//!
+//! | Keys                                 | Action                                     |
+//! |--------------------------------------|--------------------------------------------|
+//! | Left, Right                          | Move by one character                      |
+//! | Ctrl/Alt + Left, Right               | Move by one word                           |
+//! | Home, End                            | Move to the start or end of the line       |
+//! | Ctrl + Home, End                     | Move to the start or end of the text       |
+//! | Shift + any of the above             | Extend the selection                       |
+//! | Backspace, Delete                    | Delete one character, or the selection     |
+//! | Ctrl/Alt + Backspace, Delete         | Delete one word                            |
+//! | Enter                                | Insert a line break, or submit             |
+//! | Ctrl + A                             | Select all                                 |
+//! | Ctrl + C, X, V                       | Copy, cut and paste with [`TextClipboard`] |
+//! | Ctrl + Z, Ctrl + Shift + Z, Ctrl + Y | Undo and redo                              |
+//!
+//! The Super (Command) key can be used instead of Ctrl for shortcuts. Ctrl + Alt, which is how
+//! `AltGr` is reported on Windows, types the text of the key instead when it has some.
+//!
+//! Positions in the text are byte offsets, and the cursor moves by [`char`]s rather than grapheme
+//! clusters.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_window::{Ime, PrimaryWindow, Window};
+use core::ops::Range;
+
+use crate::{dispatch_focused_input, FocusedInput, InputFocus, InputFocusSystems};
+
+/// Plugin which edits the [`TextEdit`] of the focused entity from keyboard and IME input.
+///
+/// See the [module docs](self) for more details.
+pub struct TextEditPlugin;
+
+impl Plugin for TextEditPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Ime>()
+            .init_resource::<TextClipboard>()
+            .add_systems(
+                PreUpdate,
+                (
+                    dispatch_focused_input::<Ime>.in_set(InputFocusSystems::Dispatch),
+                    enable_ime_for_text_edit
+                        .after(InputFocusSystems::Dispatch)
+                        .run_if(resource_changed::<InputFocus>),
+                ),
+            )
+            .add_observer(handle_text_edit_keyboard_input)
+            .add_observer(handle_text_edit_ime);
+    }
+}
+
+/// The IME composition in progress in a [`TextEdit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextPreedit {
+    /// The text being composed.
+    pub text: String,
+    /// The byte range of the cursor within [`text`](Self::text), or `None` if the cursor should be
+    /// hidden.
+    pub cursor: Option<Range<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TextEditSnapshot {
+    text: String,
+    cursor: usize,
+    anchor: usize,
+}
+
+/// The kind of the last edit, used to merge consecutive edits into a single undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+    Other,
+}
+
+/// The state of an editable text field: its text, cursor, selection, IME preedit and undo
+/// history.
+///
+/// Add it to an entity that can receive [`InputFocus`], and the [`TextEditPlugin`] will edit it
+/// while the entity is focused.
+///
+/// The selection extends from the anchor to the cursor, and is empty when they are equal.
+/// Consecutive insertions, or deletions of single characters, are merged into a single undo step
+/// per word.
+#[derive(Component, Debug, Clone)]
+pub struct TextEdit {
+    text: String,
+    cursor: usize,
+    anchor: usize,
+    preedit: Option<TextPreedit>,
+    undo: Vec<TextEditSnapshot>,
+    redo: Vec<TextEditSnapshot>,
+    last_edit: Option<EditKind>,
+    /// Whether Enter inserts a line break. Otherwise, it triggers a [`TextSubmit`] event, and line
+    /// breaks are replaced by spaces when pasting.
+    pub multiline: bool,
+    /// How many undo steps are kept.
+    pub undo_limit: usize,
+}
+
+impl Default for TextEdit {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl TextEdit {
+    /// Creates a single-line text edit containing `text`, with the cursor at its end.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = text.len();
+        Self {
+            text,
+            cursor,
+            anchor: cursor,
+            preedit: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+            multiline: false,
+            undo_limit: 100,
+        }
+    }
+
+    /// Makes Enter insert line breaks.
+    pub fn multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
+    /// Returns the text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the whole text, moving the cursor to its end.
+    ///
+    /// This can be undone.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        self.edit(0..self.text.len(), &text, EditKind::Other);
+    }
+
+    /// Returns the position of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the position of the selection anchor.
+    pub fn anchor(&self) -> usize {
+        self.anchor
+    }
+
+    /// Returns the selected range, which is empty if nothing is selected.
+    pub fn selection(&self) -> Range<usize> {
+        self.cursor.min(self.anchor)..self.cursor.max(self.anchor)
+    }
+
+    /// Returns `true` if some text is selected.
+    pub fn has_selection(&self) -> bool {
+        self.cursor != self.anchor
+    }
+
+    /// Returns the selected text.
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selection()]
+    }
+
+    /// Moves the cursor to `position`, clearing the selection.
+    ///
+    /// `position` is clamped to the text, and moved back to the closest character boundary.
+    pub fn set_cursor(&mut self, position: usize) {
+        self.cursor = self.clamp(position);
+        self.anchor = self.cursor;
+        self.last_edit = None;
+    }
+
+    /// Selects the text from `anchor` to `cursor`.
+    ///
+    /// Positions are clamped to the text, and moved back to the closest character boundary.
+    pub fn select(&mut self, anchor: usize, cursor: usize) {
+        self.anchor = self.clamp(anchor);
+        self.cursor = self.clamp(cursor);
+        self.last_edit = None;
+    }
+
+    /// Returns the IME composition in progress, if any.
+    pub fn preedit(&self) -> Option<&TextPreedit> {
+        self.preedit.as_ref()
+    }
+
+    /// Sets the IME composition in progress, or clears it if `text` is empty.
+    pub fn set_preedit(&mut self, text: impl Into<String>, cursor: Option<Range<usize>>) {
+        let text = text.into();
+        self.preedit = (!text.is_empty()).then_some(TextPreedit { text, cursor });
+    }
+
+    /// Returns the text to display, with the IME composition in progress in place of the
+    /// selection.
+    pub fn display_text(&self) -> String {
+        match &self.preedit {
+            Some(preedit) => {
+                let selection = self.selection();
+                let mut text = self.text.clone();
+                text.replace_range(selection, &preedit.text);
+                text
+            }
+            None => self.text.clone(),
+        }
+    }
+
+    /// Returns the byte range of the IME composition in progress within the
+    /// [`display_text`](Self::display_text), if any.
+    pub fn preedit_range(&self) -> Option<Range<usize>> {
+        let start = self.selection().start;
+        self.preedit
+            .as_ref()
+            .map(|preedit| start..start + preedit.text.len())
+    }
+
+    /// Replaces the selection with `text`, as if it was typed.
+    pub fn insert(&mut self, text: &str) {
+        let text = if self.multiline {
+            text.to_string()
+        } else {
+            text.replace("\r\n", " ").replace(['\n', '\r'], " ")
+        };
+        if self.has_selection() {
+            self.edit(self.selection(), &text, EditKind::Other);
+            return;
+        }
+        // Start a new undo step with each word.
+        if !text.starts_with(char::is_whitespace)
+            && self.text[..self.cursor].ends_with(char::is_whitespace)
+        {
+            self.last_edit = None;
+        }
+        self.edit(self.selection(), &text, EditKind::Insert);
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Undoes the last edit, and returns whether there was one.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.redo.push(current);
+        true
+    }
+
+    /// Redoes the last undone edit, and returns whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.undo.push(current);
+        true
+    }
+
+    /// Applies `action`, using `clipboard` to copy, cut and paste.
+    ///
+    /// [`TextEditAction::Submit`] does nothing: submitting is up to the owner of the text.
+    pub fn apply(&mut self, action: TextEditAction, clipboard: &mut TextClipboard) {
+        match action {
+            TextEditAction::Insert(text) => self.insert(&text),
+            TextEditAction::Move { motion, select } => self.move_cursor(motion, select),
+            TextEditAction::Backspace => self.delete_towards(TextMotion::Left),
+            TextEditAction::BackspaceWord => self.delete_towards(TextMotion::WordLeft),
+            TextEditAction::Delete => self.delete_towards(TextMotion::Right),
+            TextEditAction::DeleteWord => self.delete_towards(TextMotion::WordRight),
+            TextEditAction::SelectAll => self.select(0, self.text.len()),
+            TextEditAction::Copy => {
+                if self.has_selection() {
+                    clipboard.set_text(self.selected_text().to_string());
+                }
+            }
+            TextEditAction::Cut => {
+                if self.has_selection() {
+                    clipboard.set_text(self.selected_text().to_string());
+                    self.edit(self.selection(), "", EditKind::Other);
+                }
+            }
+            TextEditAction::Paste => {
+                if let Some(text) = clipboard.get_text() {
+                    self.insert(&text);
+                    self.last_edit = Some(EditKind::Other);
+                }
+            }
+            TextEditAction::Undo => {
+                self.undo();
+            }
+            TextEditAction::Redo => {
+                self.redo();
+            }
+            TextEditAction::Submit => {}
+        }
+    }
+
+    /// Moves the cursor by `motion`, extending the selection if `select` is `true`.
+    pub fn move_cursor(&mut self, motion: TextMotion, select: bool) {
+        let selection = self.selection();
+        let position = match motion {
+            TextMotion::Left if !select && !selection.is_empty() => selection.start,
+            TextMotion::Right if !select && !selection.is_empty() => selection.end,
+            motion => self.target(motion),
+        };
+        self.cursor = position;
+        if !select {
+            self.anchor = position;
+        }
+        self.last_edit = None;
+    }
+
+    /// Returns the position `motion` leads to from the cursor.
+    fn target(&self, motion: TextMotion) -> usize {
+        let (text, cursor) = (self.text.as_str(), self.cursor);
+        match motion {
+            TextMotion::Left => text[..cursor]
+                .chars()
+                .next_back()
+                .map_or(cursor, |c| cursor - c.len_utf8()),
+            TextMotion::Right => text[cursor..]
+                .chars()
+                .next()
+                .map_or(cursor, |c| cursor + c.len_utf8()),
+            TextMotion::WordLeft => previous_word_boundary(text, cursor),
+            TextMotion::WordRight => next_word_boundary(text, cursor),
+            TextMotion::LineStart => text[..cursor].rfind('\n').map_or(0, |index| index + 1),
+            TextMotion::LineEnd => text[cursor..]
+                .find('\n')
+                .map_or(text.len(), |index| cursor + index),
+            TextMotion::Start => 0,
+            TextMotion::End => text.len(),
+        }
+    }
+
+    /// Deletes the selection, or the text between the cursor and the target of `motion`.
+    fn delete_towards(&mut self, motion: TextMotion) {
+        if self.has_selection() {
+            self.edit(self.selection(), "", EditKind::Other);
+            return;
+        }
+        let target = self.target(motion);
+        let range = self.cursor.min(target)..self.cursor.max(target);
+        let kind = match motion {
+            TextMotion::Left | TextMotion::Right => EditKind::Delete,
+            _ => EditKind::Other,
+        };
+        // Start a new undo step with each word.
+        if self.text[range.clone()].contains(char::is_whitespace) {
+            self.last_edit = None;
+        }
+        self.edit(range, "", kind);
+    }
+
+    /// Replaces `range` with `replacement`, recording an undo step unless the edit continues the
+    /// previous one.
+    fn edit(&mut self, range: Range<usize>, replacement: &str, kind: EditKind) {
+        if range.is_empty() && replacement.is_empty() {
+            return;
+        }
+        if kind == EditKind::Other || self.last_edit != Some(kind) {
+            self.undo.push(self.snapshot());
+            if self.undo.len() > self.undo_limit {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.text.replace_range(range.clone(), replacement);
+        self.cursor = range.start + replacement.len();
+        self.anchor = self.cursor;
+        self.last_edit = Some(kind);
+    }
+
+    fn snapshot(&self) -> TextEditSnapshot {
+        TextEditSnapshot {
+            text: self.text.clone(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+        }
+    }
+
+    /// Restores `snapshot`, and returns the state it replaced.
+    fn restore(&mut self, snapshot: TextEditSnapshot) -> TextEditSnapshot {
+        let current = self.snapshot();
+        self.text = snapshot.text;
+        self.cursor = snapshot.cursor;
+        self.anchor = snapshot.anchor;
+        self.last_edit = None;
+        current
+    }
+
+    fn clamp(&self, mut position: usize) -> usize {
+        position = position.min(self.text.len());
+        while !self.text.is_char_boundary(position) {
+            position -= 1;
+        }
+        position
+    }
+}
+
+/// Classifies characters for word-wise navigation: whitespace, word characters and punctuation.
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Returns the end of the word after `position`, skipping whitespace.
+fn next_word_boundary(text: &str, position: usize) -> usize {
+    let mut chars = text[position..]
+        .char_indices()
+        .skip_while(|(_, c)| c.is_whitespace());
+    let Some((_, first)) = chars.next() else {
+        return text.len();
+    };
+    let class = char_class(first);
+    chars
+        .find(|(_, c)| char_class(*c) != class)
+        .map_or(text.len(), |(index, _)| position + index)
+}
+
+/// Returns the start of the word before `position`, skipping whitespace.
+fn previous_word_boundary(text: &str, position: usize) -> usize {
+    let mut chars = text[..position]
+        .char_indices()
+        .rev()
+        .skip_while(|(_, c)| c.is_whitespace());
+    let Some((mut start, first)) = chars.next() else {
+        return 0;
+    };
+    let class = char_class(first);
+    for (index, c) in chars {
+        if char_class(c) != class {
+            break;
+        }
+        start = index;
+    }
+    start
+}
+
+/// A movement of the cursor of a [`TextEdit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextMotion {
+    /// One character to the left.
+    Left,
+    /// One character to the right.
+    Right,
+    /// To the start of the previous word.
+    WordLeft,
+    /// To the end of the next word.
+    WordRight,
+    /// To the start of the line.
+    LineStart,
+    /// To the end of the line.
+    LineEnd,
+    /// To the start of the text.
+    Start,
+    /// To the end of the text.
+    End,
+}
+
+/// An editing command for a [`TextEdit`], applied with [`TextEdit::apply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextEditAction {
+    /// Replaces the selection with text.
+    Insert(String),
+    /// Moves the cursor.
+    Move {
+        /// How the cursor moves.
+        motion: TextMotion,
+        /// Whether the selection is extended, rather than cleared.
+        select: bool,
+    },
+    /// Deletes the selection, or the character before the cursor.
+    Backspace,
+    /// Deletes the selection, or the word before the cursor.
+    BackspaceWord,
+    /// Deletes the selection, or the character after the cursor.
+    Delete,
+    /// Deletes the selection, or the word after the cursor.
+    DeleteWord,
+    /// Selects the whole text.
+    SelectAll,
+    /// Copies the selection to the clipboard.
+    Copy,
+    /// Copies the selection to the clipboard and deletes it.
+    Cut,
+    /// Replaces the selection with the content of the clipboard.
+    Paste,
+    /// Undoes the last edit.
+    Undo,
+    /// Redoes the last undone edit.
+    Redo,
+    /// Submits the text, as Enter does in a single-line text field.
+    Submit,
+}
+
+impl TextEditAction {
+    /// Returns the action performed by a key press, given the pressed modifier `keys` and whether
+    /// the text field is `multiline`.
+    ///
+    /// See the [module docs](self) for the key bindings.
+    pub fn from_keyboard_input(
+        input: &KeyboardInput,
+        keys: &ButtonInput<KeyCode>,
+        multiline: bool,
+    ) -> Option<Self> {
+        if input.state != ButtonState::Pressed {
+            return None;
+        }
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let control = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+        let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+        let text = input
+            .text
+            .as_ref()
+            .filter(|text| !text.is_empty() && !text.chars().any(char::is_control));
+        // Windows reports AltGr as Ctrl + Alt, which types characters on many keyboard layouts.
+        let shortcut = (control && !(alt && text.is_some()))
+            || keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]);
+        let word = keys.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::AltLeft,
+            KeyCode::AltRight,
+        ]);
+        let move_to = |motion| {
+            Some(Self::Move {
+                motion,
+                select: shift,
+            })
+        };
+
+        match &input.logical_key {
+            Key::ArrowLeft if word => move_to(TextMotion::WordLeft),
+            Key::ArrowLeft => move_to(TextMotion::Left),
+            Key::ArrowRight if word => move_to(TextMotion::WordRight),
+            Key::ArrowRight => move_to(TextMotion::Right),
+            Key::Home if shortcut => move_to(TextMotion::Start),
+            Key::Home => move_to(TextMotion::LineStart),
+            Key::End if shortcut => move_to(TextMotion::End),
+            Key::End => move_to(TextMotion::LineEnd),
+            Key::Backspace if word => Some(Self::BackspaceWord),
+            Key::Backspace => Some(Self::Backspace),
+            Key::Delete if word => Some(Self::DeleteWord),
+            Key::Delete => Some(Self::Delete),
+            Key::Enter if multiline && !shortcut => Some(Self::Insert("\n".into())),
+            Key::Enter => Some(Self::Submit),
+            Key::Character(character) if shortcut => match character.to_lowercase().as_str() {
+                "a" => Some(Self::SelectAll),
+                "c" => Some(Self::Copy),
+                "x" => Some(Self::Cut),
+                "v" => Some(Self::Paste),
+                "z" if shift => Some(Self::Redo),
+                "z" => Some(Self::Undo),
+                "y" => Some(Self::Redo),
+                _ => None,
+            },
+            _ if shortcut => None,
+            _ => text.map(|text| Self::Insert(text.as_str().into())),
+        }
+    }
+}
+
+/// A source and destination of copied text, such as the system clipboard.
+pub trait ClipboardProvider: Send + Sync + 'static {
+    /// Returns the text in the clipboard, if any.
+    fn get_text(&mut self) -> Option<String>;
+
+    /// Puts `text` in the clipboard.
+    fn set_text(&mut self, text: String);
+}
+
+/// A clipboard local to the app.
+#[derive(Debug, Clone, Default)]
+pub struct LocalClipboard(pub Option<String>);
+
+impl ClipboardProvider for LocalClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        self.0.clone()
+    }
+
+    fn set_text(&mut self, text: String) {
+        self.0 = Some(text);
+    }
+}
+
+/// The clipboard used by [`TextEdit`]s to copy, cut and paste.
+///
+/// By default, it is a [`LocalClipboard`]. Replace it with a [`ClipboardProvider`] accessing the
+/// system clipboard to exchange text with other applications.
+#[derive(Resource)]
+pub struct TextClipboard(Box<dyn ClipboardProvider>);
+
+impl Default for TextClipboard {
+    fn default() -> Self {
+        Self::new(LocalClipboard::default())
+    }
+}
+
+impl TextClipboard {
+    /// Creates a clipboard backed by `provider`.
+    pub fn new(provider: impl ClipboardProvider) -> Self {
+        Self(Box::new(provider))
+    }
+
+    /// Returns the text in the clipboard, if any.
+    pub fn get_text(&mut self) -> Option<String> {
+        self.0.get_text()
+    }
+
+    /// Puts `text` in the clipboard.
+    pub fn set_text(&mut self, text: String) {
+        self.0.set_text(text);
+    }
+}
+
+/// An event triggered on an entity with a single-line [`TextEdit`] when Enter is pressed while it
+/// has focus.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TextSubmit;
+
+/// Observer function which edits the focused [`TextEdit`] from keyboard input.
+///
+/// Handled keys stop propagating, so that they do not also trigger shortcuts of ancestors. Keys
+/// are ignored while an IME composition is in progress.
+pub fn handle_text_edit_keyboard_input(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    mut text_edits: Query<&mut TextEdit>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut clipboard: ResMut<TextClipboard>,
+    mut commands: Commands,
+) {
+    let entity = event.entity();
+    let Ok(mut text_edit) = text_edits.get_mut(entity) else {
+        return;
+    };
+    if text_edit.preedit.is_some() {
+        event.propagate(false);
+        return;
+    }
+    let Some(action) =
+        TextEditAction::from_keyboard_input(&event.input, &keys, text_edit.multiline)
+    else {
+        return;
+    };
+    event.propagate(false);
+    if action == TextEditAction::Submit {
+        commands.trigger_targets(TextSubmit, entity);
+    } else {
+        text_edit.apply(action, &mut clipboard);
+    }
+}
+
+/// Observer function which updates the preedit of the focused [`TextEdit`], and inserts the text
+/// committed by the IME.
+pub fn handle_text_edit_ime(
+    mut event: On<FocusedInput<Ime>>,
+    mut text_edits: Query<&mut TextEdit>,
+) {
+    let Ok(mut text_edit) = text_edits.get_mut(event.entity()) else {
+        return;
+    };
+    event.propagate(false);
+    match &event.input {
+        Ime::Preedit { value, cursor, .. } => {
+            text_edit.set_preedit(value.clone(), cursor.map(|(start, end)| start..end));
+        }
+        Ime::Commit { value, .. } => {
+            text_edit.preedit = None;
+            text_edit.insert(value);
+        }
+        Ime::Enabled { .. } => {}
+        Ime::Disabled { .. } => text_edit.preedit = None,
+    }
+}
+
+/// Enables IME on the primary window when the focused entity has a [`TextEdit`], and disables it
+/// otherwise.
+///
+/// This only runs when [`InputFocus`] changes, so that apps can still toggle IME themselves.
+pub fn enable_ime_for_text_edit(
+    focus: Res<InputFocus>,
+    text_edits: Query<(), With<TextEdit>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let enabled = focus
+        .get()
+        .is_some_and(|entity| text_edits.contains(entity));
+    for mut window in &mut windows {
+        if window.ime_enabled != enabled {
+            window.ime_enabled = enabled;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputDispatchPlugin;
+    use bevy_input::InputPlugin;
+
+    fn key(logical_key: Key, text: Option<&str>) -> KeyboardInput {
+        KeyboardInput {
+            key_code: KeyCode::Unidentified(bevy_input::keyboard::NativeKeyCode::Unidentified),
+            logical_key,
+            state: ButtonState::Pressed,
+            text: text.map(Into::into),
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            let character = c.to_string();
+            app.world_mut().write_event(key(
+                Key::Character(character.as_str().into()),
+                Some(&character),
+            ));
+        }
+        app.update();
+    }
+
+    #[test]
+    fn word_navigation_and_deletion() {
+        let mut clipboard = TextClipboard::default();
+        let mut edit = TextEdit::new("hello, wide world");
+        edit.move_cursor(TextMotion::WordLeft, false);
+        assert_eq!(edit.cursor(), 12);
+        edit.move_cursor(TextMotion::WordLeft, true);
+        assert_eq!(edit.selected_text(), "wide ");
+        edit.move_cursor(TextMotion::WordLeft, true);
+        assert_eq!(edit.selected_text(), ", wide ");
+        edit.move_cursor(TextMotion::Left, false);
+        assert_eq!(edit.cursor(), 5);
+        edit.move_cursor(TextMotion::WordRight, false);
+        assert_eq!(edit.cursor(), 6);
+
+        edit.apply(TextEditAction::DeleteWord, &mut clipboard);
+        assert_eq!(edit.text(), "hello, world");
+        edit.apply(TextEditAction::BackspaceWord, &mut clipboard);
+        assert_eq!(edit.text(), "hello world");
+        edit.apply(TextEditAction::Undo, &mut clipboard);
+        assert_eq!(edit.text(), "hello, world");
+        edit.apply(TextEditAction::Undo, &mut clipboard);
+        assert_eq!(edit.text(), "hello, wide world");
+        edit.apply(TextEditAction::Redo, &mut clipboard);
+        assert_eq!(edit.text(), "hello, world");
+    }
+
+    #[test]
+    fn clipboard_and_multiline() {
+        let mut clipboard = TextClipboard::default();
+        let mut edit = TextEdit::new("one");
+        edit.apply(TextEditAction::SelectAll, &mut clipboard);
+        edit.apply(TextEditAction::Cut, &mut clipboard);
+        assert_eq!(edit.text(), "");
+        clipboard.set_text("a\nb".into());
+        edit.apply(TextEditAction::Paste, &mut clipboard);
+        assert_eq!(edit.text(), "a b");
+
+        let mut edit = TextEdit::new("first\nsecond").multiline();
+        edit.move_cursor(TextMotion::LineStart, true);
+        assert_eq!(edit.selected_text(), "second");
+        edit.apply(TextEditAction::Copy, &mut clipboard);
+        edit.set_cursor(2);
+        edit.move_cursor(TextMotion::LineEnd, false);
+        edit.apply(TextEditAction::Paste, &mut clipboard);
+        assert_eq!(edit.text(), "firstsecond\nsecond");
+    }
+
+    #[test]
+    fn alt_gr_types_text_instead_of_shortcuts() {
+        let mut keys = ButtonInput::default();
+        keys.press(KeyCode::ControlLeft);
+        let copy = key(Key::Character("c".into()), Some("c"));
+        assert_eq!(
+            TextEditAction::from_keyboard_input(&copy, &keys, false),
+            Some(TextEditAction::Copy)
+        );
+
+        keys.press(KeyCode::AltRight);
+        let at = key(Key::Character("@".into()), Some("@"));
+        assert_eq!(
+            TextEditAction::from_keyboard_input(&at, &keys, false),
+            Some(TextEditAction::Insert("@".into()))
+        );
+        assert_eq!(
+            TextEditAction::from_keyboard_input(&key(Key::Home, None), &keys, false),
+            Some(TextEditAction::Move {
+                motion: TextMotion::Start,
+                select: false
+            })
+        );
+    }
+
+    #[test]
+    fn focused_text_edit_receives_keys_and_ime() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, InputDispatchPlugin, TextEditPlugin));
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app.update();
+
+        let entity = app.world_mut().spawn(TextEdit::default()).id();
+        app.world_mut()
+            .insert_resource(InputFocus::from_entity(entity));
+        type_text(&mut app, "ab c");
+        app.world_mut().write_event(key(Key::Backspace, None));
+        app.update();
+
+        let window = app
+            .world_mut()
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(app.world())
+            .unwrap();
+        assert!(window.ime_enabled);
+        let text_edit = app.world().get::<TextEdit>(entity).unwrap();
+        assert_eq!(text_edit.text(), "ab ");
+
+        // Typing "ab c" took one undo step per word, and Backspace another one.
+        let mut text_edit = app.world_mut().get_mut::<TextEdit>(entity).unwrap();
+        text_edit.undo();
+        assert_eq!(text_edit.text(), "ab c");
+        text_edit.undo();
+        assert_eq!(text_edit.text(), "ab ");
+        text_edit.redo();
+        assert_eq!(text_edit.text(), "ab c");
+        text_edit.set_text("ab");
+
+        let window = Entity::PLACEHOLDER;
+        app.world_mut().write_event(Ime::Preedit {
+            window,
+            value: "にほ".into(),
+            cursor: Some((6, 6)),
+        });
+        app.update();
+        let text_edit = app.world().get::<TextEdit>(entity).unwrap();
+        assert_eq!(text_edit.display_text(), "abにほ");
+        assert_eq!(text_edit.preedit_range(), Some(2..8));
+
+        // Keys go to the IME while composing.
+        app.world_mut().write_event(key(Key::Backspace, None));
+        app.update();
+        app.world_mut().write_event(Ime::Commit {
+            window,
+            value: "日本".into(),
+        });
+        app.update();
+        let text_edit = app.world().get::<TextEdit>(entity).unwrap();
+        assert_eq!(text_edit.text(), "ab日本");
+        assert_eq!(text_edit.preedit(), None);
+        assert_eq!(text_edit.cursor(), 8);
+    }
+}
@@ -68,6 +68,8 @@ plugin_group! {
         bevy_audio:::AudioPlugin,
         #[cfg(feature = "bevy_gilrs")]
         bevy_gilrs:::GilrsPlugin,
+        #[cfg(feature = "gamepad_haptics")]
+        bevy_gilrs:::GamepadHapticsPlugin,
         #[cfg(feature = "bevy_animation")]
         bevy_animation:::AnimationPlugin,
         #[cfg(feature = "bevy_gizmos")]
@@ -0,0 +1,700 @@
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use thiserror::Error;
+
+use crate::{
+    Access, ApplyError, Enum, ParsedPath, PartialReflect, ReflectKindMismatchError, ReflectPath,
+    ReflectRef, TypeInfo, VariantType,
+};
+
+/// A single change between two reflected values, as found by [`ReflectDiff::new`].
+///
+/// The `path` of a change is the [`ParsedPath`] to the changed element, or to the list, map or set which changed.
+#[derive(Debug)]
+pub enum ReflectChange {
+    /// The element at `path` was replaced from `old` to `new`.
+    ///
+    /// This is the change found for opaque values, enums which changed variant,
+    /// and values which don't have the same type or structure.
+    Modified {
+        /// The path to the element.
+        path: ParsedPath,
+        /// The previous value of the element.
+        old: Box<dyn PartialReflect>,
+        /// The new value of the element.
+        new: Box<dyn PartialReflect>,
+    },
+    /// `value` was pushed at the end of the [list](crate::List) at `path`.
+    ListPushed {
+        /// The path to the list.
+        path: ParsedPath,
+        /// The pushed element.
+        value: Box<dyn PartialReflect>,
+    },
+    /// `value` was popped from the end of the [list](crate::List) at `path`.
+    ListPopped {
+        /// The path to the list.
+        path: ParsedPath,
+        /// The popped element.
+        value: Box<dyn PartialReflect>,
+    },
+    /// The entry of `key` in the [map](crate::Map) at `path` changed from `old` to `new`.
+    ///
+    /// A `None` value means the entry doesn't exist.
+    MapEntry {
+        /// The path to the map.
+        path: ParsedPath,
+        /// The key of the entry.
+        key: Box<dyn PartialReflect>,
+        /// The previous value of the entry, if any.
+        old: Option<Box<dyn PartialReflect>>,
+        /// The new value of the entry, if any.
+        new: Option<Box<dyn PartialReflect>>,
+    },
+    /// `value` was inserted in the [set](crate::Set) at `path`.
+    SetInserted {
+        /// The path to the set.
+        path: ParsedPath,
+        /// The inserted value.
+        value: Box<dyn PartialReflect>,
+    },
+    /// `value` was removed from the [set](crate::Set) at `path`.
+    SetRemoved {
+        /// The path to the set.
+        path: ParsedPath,
+        /// The removed value.
+        value: Box<dyn PartialReflect>,
+    },
+}
+
+impl ReflectChange {
+    /// Returns the path to the element changed by this change.
+    pub fn path(&self) -> &ParsedPath {
+        match self {
+            Self::Modified { path, .. }
+            | Self::ListPushed { path, .. }
+            | Self::ListPopped { path, .. }
+            | Self::MapEntry { path, .. }
+            | Self::SetInserted { path, .. }
+            | Self::SetRemoved { path, .. } => path,
+        }
+    }
+
+    /// Returns the change undoing this change.
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Modified { path, old, new } => Self::Modified {
+                path,
+                old: new,
+                new: old,
+            },
+            Self::ListPushed { path, value } => Self::ListPopped { path, value },
+            Self::ListPopped { path, value } => Self::ListPushed { path, value },
+            Self::MapEntry {
+                path,
+                key,
+                old,
+                new,
+            } => Self::MapEntry {
+                path,
+                key,
+                old: new,
+                new: old,
+            },
+            Self::SetInserted { path, value } => Self::SetRemoved { path, value },
+            Self::SetRemoved { path, value } => Self::SetInserted { path, value },
+        }
+    }
+
+    /// Applies this change to `target`.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        let path = self.path();
+        let element =
+            path.reflect_element_mut(target)
+                .map_err(|error| DiffApplyError::InvalidPath {
+                    path: path.clone(),
+                    error: error.to_string(),
+                })?;
+        let mismatched_kinds = |error| DiffApplyError::MismatchedKinds {
+            path: path.clone(),
+            error,
+        };
+
+        match self {
+            Self::Modified { new, .. } => {
+                element
+                    .try_apply(new.as_ref())
+                    .map_err(|error| DiffApplyError::Apply {
+                        path: path.clone(),
+                        error,
+                    })?;
+            }
+            Self::ListPushed { value, .. } => {
+                let list = element.reflect_mut().as_list().map_err(mismatched_kinds)?;
+                list.push(value.to_dynamic());
+            }
+            Self::ListPopped { .. } => {
+                let list = element.reflect_mut().as_list().map_err(mismatched_kinds)?;
+                list.pop()
+                    .ok_or_else(|| DiffApplyError::EmptyList { path: path.clone() })?;
+            }
+            Self::MapEntry { key, new, .. } => {
+                let map = element.reflect_mut().as_map().map_err(mismatched_kinds)?;
+                match new {
+                    Some(new) => {
+                        map.insert_boxed(key.to_dynamic(), new.to_dynamic());
+                    }
+                    None => {
+                        map.remove(key.as_ref());
+                    }
+                }
+            }
+            Self::SetInserted { value, .. } => {
+                let set = element.reflect_mut().as_set().map_err(mismatched_kinds)?;
+                set.insert_boxed(value.to_dynamic());
+            }
+            Self::SetRemoved { value, .. } => {
+                let set = element.reflect_mut().as_set().map_err(mismatched_kinds)?;
+                set.remove(value.as_ref());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Clone for ReflectChange {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Modified { path, old, new } => Self::Modified {
+                path: path.clone(),
+                old: old.to_dynamic(),
+                new: new.to_dynamic(),
+            },
+            Self::ListPushed { path, value } => Self::ListPushed {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            Self::ListPopped { path, value } => Self::ListPopped {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            Self::MapEntry {
+                path,
+                key,
+                old,
+                new,
+            } => Self::MapEntry {
+                path: path.clone(),
+                key: key.to_dynamic(),
+                old: old.as_deref().map(PartialReflect::to_dynamic),
+                new: new.as_deref().map(PartialReflect::to_dynamic),
+            },
+            Self::SetInserted { path, value } => Self::SetInserted {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+            Self::SetRemoved { path, value } => Self::SetRemoved {
+                path: path.clone(),
+                value: value.to_dynamic(),
+            },
+        }
+    }
+}
+
+/// An error returned when applying a [`ReflectDiff`] or a [`ReflectChange`] fails.
+#[derive(Error, Debug)]
+pub enum DiffApplyError {
+    /// The path of the change doesn't lead to an element of the target.
+    #[error("the path `{path}` is invalid: {error}")]
+    InvalidPath {
+        /// The path of the change.
+        path: ParsedPath,
+        /// The reason why the path couldn't be accessed.
+        error: String,
+    },
+    /// The element at the path of the change isn't of the expected kind.
+    #[error("the element at `{path}` has the wrong kind: {error}")]
+    MismatchedKinds {
+        /// The path of the change.
+        path: ParsedPath,
+        /// The kind mismatch.
+        error: ReflectKindMismatchError,
+    },
+    /// The new value couldn't be applied to the element at the path of the change.
+    #[error("failed to apply the change at `{path}`: {error}")]
+    Apply {
+        /// The path of the change.
+        path: ParsedPath,
+        /// The error returned by [`PartialReflect::try_apply`].
+        error: ApplyError,
+    },
+    /// An element was popped from an empty list.
+    #[error("the list at `{path}` is empty")]
+    EmptyList {
+        /// The path of the list.
+        path: ParsedPath,
+    },
+}
+
+/// The structural differences between two reflected values, as a list of [`ReflectChange`]s.
+///
+/// Structs, tuples, arrays and enums which kept the same variant are compared field by field,
+/// lists element by element, and maps and sets entry by entry.
+/// Other values are compared with [`PartialReflect::reflect_partial_eq`], and considered
+/// [modified](ReflectChange::Modified) when they can't be compared.
+///
+/// Applying the diff of `old` and `new` to `old` makes it equal to `new`,
+/// while applying its [inverse](ReflectDiff::inverse) to `new` makes it equal to `old`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectChange, ReflectDiff};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Inventory {
+///     gold: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let old = Inventory {
+///     gold: 10,
+///     items: vec!["sword".to_string()],
+/// };
+/// let new = Inventory {
+///     gold: 5,
+///     items: vec!["sword".to_string(), "shield".to_string()],
+/// };
+///
+/// let diff = ReflectDiff::new(&old, &new);
+/// assert_eq!(diff.len(), 2);
+/// assert_eq!(diff.changes()[0].path().to_string(), ".gold");
+/// assert!(matches!(diff.changes()[1], ReflectChange::ListPushed { .. }));
+///
+/// let mut value = old.clone();
+/// diff.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+///
+/// diff.inverse().apply(&mut value).unwrap();
+/// assert_eq!(value, old);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReflectDiff {
+    changes: Vec<ReflectChange>,
+}
+
+impl ReflectDiff {
+    /// Computes the changes turning `old` into `new`.
+    pub fn new(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Self {
+        let mut differ = Differ::default();
+        differ.diff(old, new);
+        Self {
+            changes: differ.changes,
+        }
+    }
+
+    /// Returns the changes of this diff, in the order they are applied.
+    pub fn changes(&self) -> &[ReflectChange] {
+        &self.changes
+    }
+
+    /// Returns an iterator over the changes of this diff, in the order they are applied.
+    pub fn iter(&self) -> core::slice::Iter<'_, ReflectChange> {
+        self.changes.iter()
+    }
+
+    /// Returns the number of changes in this diff.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the compared values were equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the diff undoing this diff.
+    pub fn inverse(self) -> Self {
+        Self {
+            changes: self
+                .changes
+                .into_iter()
+                .rev()
+                .map(ReflectChange::inverse)
+                .collect(),
+        }
+    }
+
+    /// Applies the changes of this diff to `target`, in order.
+    ///
+    /// On error, the changes preceding the failing one have already been applied.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        self.changes
+            .iter()
+            .try_for_each(|change| change.apply(target))
+    }
+}
+
+impl FromIterator<ReflectChange> for ReflectDiff {
+    fn from_iter<T: IntoIterator<Item = ReflectChange>>(iter: T) -> Self {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for ReflectDiff {
+    type Item = ReflectChange;
+    type IntoIter = alloc::vec::IntoIter<ReflectChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ReflectDiff {
+    type Item = &'a ReflectChange;
+    type IntoIter = core::slice::Iter<'a, ReflectChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+#[derive(Default)]
+struct Differ {
+    path: Vec<Access<'static>>,
+    changes: Vec<ReflectChange>,
+}
+
+impl Differ {
+    fn path(&self) -> ParsedPath {
+        ParsedPath::from(self.path.clone())
+    }
+
+    fn diff_at(
+        &mut self,
+        access: Access<'static>,
+        old: &dyn PartialReflect,
+        new: &dyn PartialReflect,
+    ) {
+        self.path.push(access);
+        self.diff(old, new);
+        self.path.pop();
+    }
+
+    fn modified(&mut self, old: &dyn PartialReflect, new: &dyn PartialReflect) {
+        self.changes.push(ReflectChange::Modified {
+            path: self.path(),
+            old: old.to_dynamic(),
+            new: new.to_dynamic(),
+        });
+    }
+
+    fn diff(&mut self, old: &dyn PartialReflect, new: &dyn PartialReflect) {
+        let old_type = old.get_represented_type_info().map(TypeInfo::type_id);
+        let new_type = new.get_represented_type_info().map(TypeInfo::type_id);
+        if old_type.is_some() && new_type.is_some() && old_type != new_type {
+            return self.modified(old, new);
+        }
+
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+                let same_fields = old_struct.field_len() == new_struct.field_len()
+                    && (0..old_struct.field_len())
+                        .all(|index| old_struct.name_at(index) == new_struct.name_at(index));
+                if !same_fields {
+                    return self.modified(old, new);
+                }
+                for (index, (old_field, new_field)) in old_struct
+                    .iter_fields()
+                    .zip(new_struct.iter_fields())
+                    .enumerate()
+                {
+                    let name = old_struct.name_at(index).unwrap().to_owned();
+                    self.diff_at(Access::Field(Cow::Owned(name)), old_field, new_field);
+                }
+            }
+            (ReflectRef::TupleStruct(old_tuple), ReflectRef::TupleStruct(new_tuple)) => {
+                if old_tuple.field_len() != new_tuple.field_len() {
+                    return self.modified(old, new);
+                }
+                for (index, (old_field, new_field)) in old_tuple
+                    .iter_fields()
+                    .zip(new_tuple.iter_fields())
+                    .enumerate()
+                {
+                    self.diff_at(Access::TupleIndex(index), old_field, new_field);
+                }
+            }
+            (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+                if old_tuple.field_len() != new_tuple.field_len() {
+                    return self.modified(old, new);
+                }
+                for (index, (old_field, new_field)) in old_tuple
+                    .iter_fields()
+                    .zip(new_tuple.iter_fields())
+                    .enumerate()
+                {
+                    self.diff_at(Access::TupleIndex(index), old_field, new_field);
+                }
+            }
+            (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+                if old_array.len() != new_array.len() {
+                    return self.modified(old, new);
+                }
+                for (index, (old_item, new_item)) in
+                    old_array.iter().zip(new_array.iter()).enumerate()
+                {
+                    self.diff_at(Access::ListIndex(index), old_item, new_item);
+                }
+            }
+            (ReflectRef::List(old_list), ReflectRef::List(new_list)) => {
+                for (index, (old_item, new_item)) in
+                    old_list.iter().zip(new_list.iter()).enumerate()
+                {
+                    self.diff_at(Access::ListIndex(index), old_item, new_item);
+                }
+                for new_item in new_list.iter().skip(old_list.len()) {
+                    self.changes.push(ReflectChange::ListPushed {
+                        path: self.path(),
+                        value: new_item.to_dynamic(),
+                    });
+                }
+                for index in (new_list.len()..old_list.len()).rev() {
+                    self.changes.push(ReflectChange::ListPopped {
+                        path: self.path(),
+                        value: old_list.get(index).unwrap().to_dynamic(),
+                    });
+                }
+            }
+            (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => {
+                // Map entries can't be reached by a path, so changed entries are replaced as a whole.
+                for (key, old_value) in old_map.iter() {
+                    let new_value = new_map.get(key);
+                    if new_value
+                        .is_some_and(|new_value| ReflectDiff::new(old_value, new_value).is_empty())
+                    {
+                        continue;
+                    }
+                    self.changes.push(ReflectChange::MapEntry {
+                        path: self.path(),
+                        key: key.to_dynamic(),
+                        old: Some(old_value.to_dynamic()),
+                        new: new_value.map(PartialReflect::to_dynamic),
+                    });
+                }
+                for (key, new_value) in new_map.iter() {
+                    if old_map.get(key).is_none() {
+                        self.changes.push(ReflectChange::MapEntry {
+                            path: self.path(),
+                            key: key.to_dynamic(),
+                            old: None,
+                            new: Some(new_value.to_dynamic()),
+                        });
+                    }
+                }
+            }
+            (ReflectRef::Set(old_set), ReflectRef::Set(new_set)) => {
+                for value in old_set.iter().filter(|value| !new_set.contains(*value)) {
+                    self.changes.push(ReflectChange::SetRemoved {
+                        path: self.path(),
+                        value: value.to_dynamic(),
+                    });
+                }
+                for value in new_set.iter().filter(|value| !old_set.contains(*value)) {
+                    self.changes.push(ReflectChange::SetInserted {
+                        path: self.path(),
+                        value: value.to_dynamic(),
+                    });
+                }
+            }
+            (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+                if old_enum.variant_name() != new_enum.variant_name()
+                    || old_enum.field_len() != new_enum.field_len()
+                {
+                    return self.modified(old, new);
+                }
+                self.diff_variant_fields(old_enum, new_enum);
+            }
+            _ => {
+                if old.reflect_partial_eq(new) != Some(true) {
+                    self.modified(old, new);
+                }
+            }
+        }
+    }
+
+    fn diff_variant_fields(&mut self, old_enum: &dyn Enum, new_enum: &dyn Enum) {
+        for (index, (old_field, new_field)) in old_enum
+            .iter_fields()
+            .zip(new_enum.iter_fields())
+            .enumerate()
+        {
+            let access = match old_enum.variant_type() {
+                VariantType::Struct => {
+                    Access::Field(Cow::Owned(old_field.name().unwrap().to_owned()))
+                }
+                VariantType::Tuple | VariantType::Unit => Access::TupleIndex(index),
+            };
+            self.diff_at(access, old_field.value(), new_field.value());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromReflect, Reflect};
+    use alloc::{string::String, vec};
+    use bevy_platform::collections::{HashMap, HashSet};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Scene {
+        name: String,
+        shapes: Vec<Shape>,
+        tags: HashSet<String>,
+        layers: HashMap<u32, Shape>,
+    }
+
+    fn create_scene() -> Scene {
+        Scene {
+            name: "scene".into(),
+            shapes: vec![Shape::Circle { radius: 1.0 }, Shape::Rect(1.0, 2.0)],
+            tags: HashSet::from_iter(["a".to_string(), "b".to_string()]),
+            layers: HashMap::from_iter([(0, Shape::Point), (1, Shape::Rect(0.0, 0.0))]),
+        }
+    }
+
+    fn paths(diff: &ReflectDiff) -> Vec<String> {
+        diff.iter()
+            .map(|change| change.path().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn should_find_no_changes_between_equal_values() {
+        let scene = create_scene();
+        assert!(ReflectDiff::new(&scene, &scene.clone()).is_empty());
+    }
+
+    #[test]
+    fn should_diff_nested_fields() {
+        let old = create_scene();
+        let mut new = old.clone();
+        new.name = "renamed".into();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        new.shapes[1] = Shape::Point;
+
+        let diff = ReflectDiff::new(&old, &new);
+        assert_eq!(
+            vec![".name", ".shapes[0].radius", ".shapes[1]"],
+            paths(&diff)
+        );
+
+        let ReflectChange::Modified { old, new, .. } = &diff.changes()[1] else {
+            panic!("expected a modified value");
+        };
+        assert_eq!(old.try_downcast_ref::<f32>(), Some(&1.0));
+        assert_eq!(new.try_downcast_ref::<f32>(), Some(&2.0));
+    }
+
+    #[test]
+    fn should_diff_collections() {
+        let old = create_scene();
+        let mut new = old.clone();
+        new.shapes.push(Shape::Point);
+        new.tags.remove("a");
+        new.tags.insert("c".into());
+        new.layers.remove(&0);
+        new.layers.insert(1, Shape::Rect(1.0, 0.0));
+        new.layers.insert(2, Shape::Point);
+
+        let diff = ReflectDiff::new(&old, &new);
+        assert_eq!(6, diff.len());
+        assert!(diff.iter().any(|change| matches!(
+            change,
+            ReflectChange::MapEntry { key, old: Some(_), new: None, .. }
+                if key.try_downcast_ref::<u32>() == Some(&0)
+        )));
+        assert!(diff.iter().any(|change| matches!(
+            change,
+            ReflectChange::SetInserted { value, .. }
+                if value.try_downcast_ref::<String>().is_some_and(|value| value == "c")
+        )));
+
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(new, value);
+    }
+
+    #[test]
+    fn should_apply_inverse_diff() {
+        let old = create_scene();
+        let mut new = old.clone();
+        new.shapes.truncate(1);
+        new.shapes.push(Shape::Rect(3.0, 4.0));
+        new.shapes.push(Shape::Point);
+        new.layers.clear();
+        new.tags.insert("c".into());
+
+        let diff = ReflectDiff::new(&old, &new);
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(new, value);
+
+        diff.inverse().apply(&mut value).unwrap();
+        assert_eq!(old, value);
+
+        let mut value = old.clone();
+        ReflectDiff::new(
+            &old,
+            &Scene {
+                shapes: Vec::new(),
+                ..old.clone()
+            },
+        )
+        .apply(&mut value)
+        .unwrap();
+        assert!(value.shapes.is_empty());
+    }
+
+    #[test]
+    fn should_apply_diff_to_dynamic_value() {
+        let old = create_scene();
+        let mut new = old.clone();
+        new.shapes[1] = Shape::Rect(5.0, 2.0);
+
+        let mut value = old.to_dynamic();
+        ReflectDiff::new(&old, &new).apply(value.as_mut()).unwrap();
+        assert_eq!(new, Scene::from_reflect(value.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn should_fail_to_apply_diff_to_other_structure() {
+        #[derive(Reflect)]
+        struct Other {
+            value: u32,
+        }
+
+        let old = create_scene();
+        let mut new = old.clone();
+        new.name = "renamed".into();
+
+        let error = ReflectDiff::new(&old, &new)
+            .apply(&mut Other { value: 0 })
+            .unwrap_err();
+        assert!(matches!(error, DiffApplyError::InvalidPath { .. }));
+    }
+}
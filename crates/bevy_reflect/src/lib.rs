@@ -578,6 +578,7 @@ extern crate alloc;
 extern crate self as bevy_reflect;
 
 mod array;
+mod diff;
 mod error;
 mod fields;
 mod from_reflect;
@@ -650,6 +651,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use error::*;
 pub use fields::*;
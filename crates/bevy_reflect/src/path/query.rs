@@ -0,0 +1,859 @@
+//! Queries matching any number of elements within a type.
+
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Ordering, convert::Infallible};
+use thiserror::Error;
+
+use super::{Access, ParsedPath, ReflectPath};
+use crate::{Enum, PartialReflect, ReflectMut, ReflectRef};
+
+/// An error returned when parsing a [`PathQuery`] fails.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Encountered an error at offset {offset} while parsing `{query}`: {kind}")]
+pub struct PathQueryError {
+    /// Position in `query`.
+    pub offset: usize,
+    /// The query string that the error occurred in.
+    pub query: String,
+    /// The underlying error.
+    pub kind: PathQueryErrorKind,
+}
+
+/// The kind of a [`PathQueryError`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PathQueryErrorKind {
+    /// A field name or index was expected.
+    #[error("expected an identifier")]
+    ExpectedIdent,
+    /// An index couldn't be parsed as an integer.
+    #[error("failed to parse index as integer")]
+    InvalidIndex,
+    /// A `[` wasn't closed.
+    #[error("a '[' wasn't closed")]
+    Unclosed,
+    /// A string literal wasn't closed.
+    #[error("a string literal wasn't closed")]
+    UnclosedString,
+    /// An unexpected character was found.
+    #[error("unexpected character '{0}'")]
+    UnexpectedChar(char),
+    /// The path of a filter couldn't be parsed.
+    #[error("invalid filter path: {0}")]
+    InvalidFilterPath(String),
+    /// The literal of a filter couldn't be parsed.
+    #[error("invalid filter literal `{0}`")]
+    InvalidLiteral(String),
+}
+
+/// A query matching any number of elements within a type, such as all the names of a list of items.
+///
+/// Queries extend the [path syntax] of [`GetPath`] with:
+/// - Field wildcards (`.*`), matching every field of a struct, tuple struct, tuple or enum variant.
+/// - Element wildcards (`[*]`), matching every element of a list, array or set, and every value of a map.
+/// - Map keys (`["key"]`), matching the value of a map whose key is the given string.
+/// - Filters (`[?predicate]`), matching the elements and map values for which `predicate` holds.
+///
+/// A predicate is either an operand alone, which holds if it exists and isn't `false`,
+/// or an operand compared to a literal with `==`, `!=`, `<`, `<=`, `>` or `>=`.
+/// Operands are either a path relative to the element (`.health`, `@` being the element itself),
+/// or `@key` for the key of a map value or the index of a list element.
+/// Literals are numbers, `true`, `false`, or double-quoted strings,
+/// which are also compared to the variant name of enums.
+///
+/// Elements which don't exist, such as the missing field of an enum variant, are skipped rather than
+/// reported as errors.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{PathQuery, Reflect};
+/// #[derive(Reflect)]
+/// struct Inventory {
+///     items: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Item {
+///     name: String,
+///     count: u32,
+/// }
+///
+/// let mut inventory = Inventory {
+///     items: vec![
+///         Item { name: "sword".to_string(), count: 1 },
+///         Item { name: "arrow".to_string(), count: 20 },
+///     ],
+/// };
+///
+/// let names = PathQuery::parse(".items[*].name").unwrap();
+/// let names = names
+///     .reflect_elements(&inventory)
+///     .into_iter()
+///     .filter_map(|name| name.try_downcast_ref::<String>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(names, ["sword", "arrow"]);
+///
+/// let stacks = PathQuery::parse(".items[?.count > 1].count").unwrap();
+/// let count = stacks.for_each_element_mut(&mut inventory, |count| {
+///     count.apply(&10_u32);
+/// });
+/// assert_eq!(count, 1);
+/// assert_eq!(inventory.items[1].count, 10);
+/// ```
+///
+/// [path syntax]: crate::GetPath#syntax
+/// [`GetPath`]: crate::GetPath
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathQuery {
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Access(Access<'static>),
+    AllFields,
+    AllElements,
+    MapKey(String),
+    Filter(Filter),
+}
+
+/// The key of an element of a list, array, set or map.
+#[derive(Clone, Copy)]
+enum ElementKey<'a> {
+    Index(usize),
+    Value(&'a dyn PartialReflect),
+    None,
+}
+
+impl PathQuery {
+    /// Parses a [`PathQuery`] from a string.
+    pub fn parse(query: &str) -> Result<Self, PathQueryError> {
+        QueryParser { query, offset: 0 }
+            .parse()
+            .map_err(|(offset, kind)| PathQueryError {
+                offset,
+                query: query.to_owned(),
+                kind,
+            })
+    }
+
+    /// Returns `true` if this query matches at most one element, like a [`ParsedPath`].
+    pub fn is_concrete(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| matches!(segment, Segment::Access(_)))
+    }
+
+    /// Returns references to all the elements of `root` matched by this query.
+    pub fn reflect_elements<'r>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> Vec<&'r dyn PartialReflect> {
+        let mut elements = Vec::new();
+        visit(&self.segments, root, &mut |element| elements.push(element));
+        elements
+    }
+
+    /// Calls `f` with mutable references to all the elements of `root` matched by this query,
+    /// returning the number of matched elements.
+    ///
+    /// The elements of sets can't be mutated, and are never matched by this method.
+    pub fn for_each_element_mut(
+        &self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect),
+    ) -> usize {
+        let result = self.try_for_each_element_mut(root, |element| {
+            f(element);
+            Ok::<_, Infallible>(())
+        });
+        match result {
+            Ok(count) => count,
+        }
+    }
+
+    /// Calls `f` with mutable references to all the elements of `root` matched by this query,
+    /// stopping at the first error, and returning the number of matched elements otherwise.
+    ///
+    /// The elements of sets can't be mutated, and are never matched by this method.
+    pub fn try_for_each_element_mut<E>(
+        &self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect) -> Result<(), E>,
+    ) -> Result<usize, E> {
+        let mut count = 0;
+        visit_mut(&self.segments, root, &mut |element| {
+            count += 1;
+            f(element)
+        })?;
+        Ok(count)
+    }
+}
+
+impl From<ParsedPath> for PathQuery {
+    fn from(path: ParsedPath) -> Self {
+        Self {
+            segments: path
+                .0
+                .into_iter()
+                .map(|access| Segment::Access(access.access))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PathQuery {
+    type Error = PathQueryError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        PathQuery::parse(value)
+    }
+}
+
+impl Segment {
+    fn accepts(&self, key: ElementKey, element: &dyn PartialReflect) -> bool {
+        match self {
+            Segment::MapKey(name) => {
+                matches!(key, ElementKey::Value(key) if as_str(key) == Some(name))
+            }
+            Segment::Filter(filter) => filter.holds(key, element),
+            _ => true,
+        }
+    }
+}
+
+fn visit<'r>(
+    segments: &[Segment],
+    value: &'r dyn PartialReflect,
+    f: &mut dyn FnMut(&'r dyn PartialReflect),
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        return f(value);
+    };
+
+    match (segment, value.reflect_ref()) {
+        (Segment::Access(access), _) => {
+            if let Ok(element) = access.element(value, None) {
+                visit(rest, element, f);
+            }
+        }
+        (Segment::AllFields, ReflectRef::Struct(struct_ref)) => {
+            struct_ref
+                .iter_fields()
+                .for_each(|field| visit(rest, field, f));
+        }
+        (Segment::AllFields, ReflectRef::TupleStruct(tuple_struct)) => {
+            tuple_struct
+                .iter_fields()
+                .for_each(|field| visit(rest, field, f));
+        }
+        (Segment::AllFields, ReflectRef::Tuple(tuple)) => {
+            tuple.iter_fields().for_each(|field| visit(rest, field, f));
+        }
+        (Segment::AllFields, ReflectRef::Enum(enum_ref)) => {
+            enum_ref
+                .iter_fields()
+                .for_each(|field| visit(rest, field.value(), f));
+        }
+        (Segment::AllFields, _) => {}
+        (_, ReflectRef::List(list)) => {
+            for (index, element) in list.iter().enumerate() {
+                if segment.accepts(ElementKey::Index(index), element) {
+                    visit(rest, element, f);
+                }
+            }
+        }
+        (_, ReflectRef::Array(array)) => {
+            for (index, element) in array.iter().enumerate() {
+                if segment.accepts(ElementKey::Index(index), element) {
+                    visit(rest, element, f);
+                }
+            }
+        }
+        (_, ReflectRef::Set(set)) => {
+            for element in set.iter() {
+                if segment.accepts(ElementKey::None, element) {
+                    visit(rest, element, f);
+                }
+            }
+        }
+        (_, ReflectRef::Map(map)) => {
+            for (key, element) in map.iter() {
+                if segment.accepts(ElementKey::Value(key), element) {
+                    visit(rest, element, f);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn visit_mut<E>(
+    segments: &[Segment],
+    value: &mut dyn PartialReflect,
+    f: &mut dyn FnMut(&mut dyn PartialReflect) -> Result<(), E>,
+) -> Result<(), E> {
+    let Some((segment, rest)) = segments.split_first() else {
+        return f(value);
+    };
+
+    if let Segment::Access(access) = segment {
+        return match access.element_mut(value, None) {
+            Ok(element) => visit_mut(rest, element, f),
+            Err(_) => Ok(()),
+        };
+    }
+
+    match (segment, value.reflect_mut()) {
+        (Segment::AllFields, ReflectMut::Struct(struct_mut)) => {
+            for index in 0..struct_mut.field_len() {
+                visit_mut(rest, struct_mut.field_at_mut(index).unwrap(), f)?;
+            }
+        }
+        (Segment::AllFields, ReflectMut::TupleStruct(tuple_struct)) => {
+            for index in 0..tuple_struct.field_len() {
+                visit_mut(rest, tuple_struct.field_mut(index).unwrap(), f)?;
+            }
+        }
+        (Segment::AllFields, ReflectMut::Tuple(tuple)) => {
+            for index in 0..tuple.field_len() {
+                visit_mut(rest, tuple.field_mut(index).unwrap(), f)?;
+            }
+        }
+        (Segment::AllFields, ReflectMut::Enum(enum_mut)) => {
+            for index in 0..enum_mut.field_len() {
+                visit_mut(rest, enum_mut.field_at_mut(index).unwrap(), f)?;
+            }
+        }
+        (Segment::AllFields, _) => {}
+        (_, ReflectMut::List(list)) => {
+            for index in 0..list.len() {
+                let element = list.get_mut(index).unwrap();
+                if segment.accepts(ElementKey::Index(index), element) {
+                    visit_mut(rest, element, f)?;
+                }
+            }
+        }
+        (_, ReflectMut::Array(array)) => {
+            for index in 0..array.len() {
+                let element = array.get_mut(index).unwrap();
+                if segment.accepts(ElementKey::Index(index), element) {
+                    visit_mut(rest, element, f)?;
+                }
+            }
+        }
+        (_, ReflectMut::Map(map)) => {
+            // `retain` is the only way to mutably iterate over the entries of a map.
+            let mut result = Ok(());
+            map.retain(&mut |key, element| {
+                if result.is_ok() && segment.accepts(ElementKey::Value(key), element) {
+                    result = visit_mut(rest, element, f);
+                }
+                true
+            });
+            result?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Filter {
+    operand: Operand,
+    comparison: Option<(Comparison, Literal)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Key,
+    Path(ParsedPath),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Literal {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl Comparison {
+    const SYMBOLS: [(&'static str, Comparison); 6] = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        match (self, ordering) {
+            (Comparison::Ne, ordering) => ordering != Some(Ordering::Equal),
+            (_, None) => false,
+            (Comparison::Eq, Some(ordering)) => ordering.is_eq(),
+            (Comparison::Lt, Some(ordering)) => ordering.is_lt(),
+            (Comparison::Le, Some(ordering)) => ordering.is_le(),
+            (Comparison::Gt, Some(ordering)) => ordering.is_gt(),
+            (Comparison::Ge, Some(ordering)) => ordering.is_ge(),
+        }
+    }
+}
+
+impl Filter {
+    fn holds(&self, key: ElementKey, element: &dyn PartialReflect) -> bool {
+        let operand = match &self.operand {
+            Operand::Key => key,
+            Operand::Path(path) => match path.reflect_element(element) {
+                Ok(value) => ElementKey::Value(value),
+                Err(_) => return false,
+            },
+        };
+
+        let Some((comparison, literal)) = &self.comparison else {
+            return match operand {
+                ElementKey::Value(value) => value.try_downcast_ref::<bool>() != Some(&false),
+                ElementKey::Index(_) => true,
+                ElementKey::None => false,
+            };
+        };
+
+        let ordering = match (operand, literal) {
+            (ElementKey::Index(index), Literal::Number(number)) => {
+                (index as f64).partial_cmp(number)
+            }
+            (ElementKey::Value(value), Literal::Number(number)) => {
+                as_f64(value).and_then(|value| value.partial_cmp(number))
+            }
+            (ElementKey::Value(value), Literal::String(string)) => as_str(value)
+                .or_else(|| value.reflect_ref().as_enum().ok().map(Enum::variant_name))
+                .map(|value| value.cmp(string.as_str())),
+            (ElementKey::Value(value), Literal::Bool(bool)) => value
+                .try_downcast_ref::<bool>()
+                .filter(|_| matches!(comparison, Comparison::Eq | Comparison::Ne))
+                .map(|value| value.cmp(bool)),
+            _ => None,
+        };
+        comparison.holds(ordering)
+    }
+}
+
+fn as_str(value: &dyn PartialReflect) -> Option<&str> {
+    value
+        .try_downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| value.try_downcast_ref::<&'static str>().copied())
+        .or_else(|| {
+            value
+                .try_downcast_ref::<Cow<'static, str>>()
+                .map(AsRef::as_ref)
+        })
+}
+
+fn as_f64(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! downcast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return Some(*value as f64);
+                }
+            )*
+        };
+    }
+    downcast!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    None
+}
+
+type ParseResult<T> = Result<T, (usize, PathQueryErrorKind)>;
+
+struct QueryParser<'a> {
+    query: &'a str,
+    offset: usize,
+}
+
+impl<'a> QueryParser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.query.as_bytes().get(self.offset).copied()
+    }
+
+    fn error<T>(&self, kind: PathQueryErrorKind) -> ParseResult<T> {
+        Err((self.offset, kind))
+    }
+
+    fn parse(mut self) -> ParseResult<PathQuery> {
+        let mut segments = Vec::new();
+        while let Some(byte) = self.peek() {
+            let segment = match byte {
+                b'.' => {
+                    self.offset += 1;
+                    if self.peek() == Some(b'*') {
+                        self.offset += 1;
+                        Segment::AllFields
+                    } else {
+                        self.parse_field()?
+                    }
+                }
+                b'#' => {
+                    self.offset += 1;
+                    let index = self.parse_ident()?;
+                    Segment::Access(Access::FieldIndex(self.parse_index(index)?))
+                }
+                b'[' => {
+                    self.offset += 1;
+                    self.parse_bracket()?
+                }
+                _ if self.offset == 0 => self.parse_field()?,
+                _ => {
+                    return self.error(PathQueryErrorKind::UnexpectedChar(
+                        self.query[self.offset..].chars().next().unwrap(),
+                    ));
+                }
+            };
+            segments.push(segment);
+        }
+        Ok(PathQuery { segments })
+    }
+
+    fn parse_ident(&mut self) -> ParseResult<&'a str> {
+        let start = self.offset;
+        let len = self.query.as_bytes()[start..]
+            .iter()
+            .position(|byte| b".#[]".contains(byte))
+            .unwrap_or(self.query.len() - start);
+        if len == 0 {
+            return self.error(PathQueryErrorKind::ExpectedIdent);
+        }
+        self.offset += len;
+        Ok(&self.query[start..start + len])
+    }
+
+    fn parse_index(&self, index: &str) -> ParseResult<usize> {
+        index
+            .trim()
+            .parse()
+            .map_err(|_| (self.offset - index.len(), PathQueryErrorKind::InvalidIndex))
+    }
+
+    fn parse_field(&mut self) -> ParseResult<Segment> {
+        let ident = self.parse_ident()?;
+        Ok(Segment::Access(
+            if ident.bytes().all(|byte| byte.is_ascii_digit()) {
+                Access::TupleIndex(self.parse_index(ident)?)
+            } else {
+                Access::Field(Cow::Owned(ident.to_owned()))
+            },
+        ))
+    }
+
+    fn expect_close(&mut self) -> ParseResult<()> {
+        match self.peek() {
+            Some(b']') => {
+                self.offset += 1;
+                Ok(())
+            }
+            Some(_) => self.error(PathQueryErrorKind::UnexpectedChar(
+                self.query[self.offset..].chars().next().unwrap(),
+            )),
+            None => self.error(PathQueryErrorKind::Unclosed),
+        }
+    }
+
+    fn parse_bracket(&mut self) -> ParseResult<Segment> {
+        match self.peek() {
+            Some(b'*') => {
+                self.offset += 1;
+                self.expect_close()?;
+                Ok(Segment::AllElements)
+            }
+            Some(b'"') => {
+                let (key, len) = parse_string(&self.query[self.offset..])
+                    .ok_or((self.offset, PathQueryErrorKind::UnclosedString))?;
+                self.offset += len;
+                self.expect_close()?;
+                Ok(Segment::MapKey(key))
+            }
+            Some(b'?') => {
+                self.offset += 1;
+                let start = self.offset;
+                let len = find_close(&self.query[start..])
+                    .ok_or((self.query.len(), PathQueryErrorKind::Unclosed))?;
+                let filter = parse_filter(&self.query[start..start + len])
+                    .map_err(|(offset, kind)| (start + offset, kind))?;
+                self.offset += len + 1;
+                Ok(Segment::Filter(filter))
+            }
+            _ => {
+                let index = self.parse_ident()?;
+                let index = self.parse_index(index)?;
+                self.expect_close()?;
+                Ok(Segment::Access(Access::ListIndex(index)))
+            }
+        }
+    }
+}
+
+/// Parses the double-quoted string at the start of `input`, returning it along with its length in `input`.
+fn parse_string(input: &str) -> Option<(String, usize)> {
+    let mut string = String::new();
+    let mut chars = input.char_indices().skip(1);
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '"' => return Some((string, index + 1)),
+            '\\' => string.push(chars.next()?.1),
+            char => string.push(char),
+        }
+    }
+    None
+}
+
+/// Returns the position of the `]` closing the filter starting `input`.
+fn find_close(input: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, byte) in input.bytes().enumerate() {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' if in_string => escaped = true,
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            b'[' => depth += 1,
+            b']' if depth == 0 => return Some(index),
+            b']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_filter(input: &str) -> ParseResult<Filter> {
+    // Find the first comparison outside of string literals.
+    let mut in_string = false;
+    let mut comparison = None;
+    for (index, byte) in input.bytes().enumerate() {
+        match byte {
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            _ => {
+                comparison = Comparison::SYMBOLS
+                    .iter()
+                    .find(|(symbol, _)| input[index..].starts_with(symbol))
+                    .map(|(symbol, comparison)| (index, symbol.len(), *comparison));
+                if comparison.is_some() {
+                    break;
+                }
+            }
+        }
+    }
+
+    let (operand, comparison) = match comparison {
+        Some((index, len, comparison)) => {
+            let literal =
+                parse_literal(input[index + len..].trim()).map_err(|kind| (index + len, kind))?;
+            (&input[..index], Some((comparison, literal)))
+        }
+        None => (input, None),
+    };
+
+    let operand = match operand.trim() {
+        "@key" => Operand::Key,
+        path => Operand::Path(
+            ParsedPath::parse(path.strip_prefix('@').unwrap_or(path))
+                .map_err(|error| (0, PathQueryErrorKind::InvalidFilterPath(error.to_string())))?,
+        ),
+    };
+
+    Ok(Filter {
+        operand,
+        comparison,
+    })
+}
+
+fn parse_literal(input: &str) -> Result<Literal, PathQueryErrorKind> {
+    match input {
+        "true" => Ok(Literal::Bool(true)),
+        "false" => Ok(Literal::Bool(false)),
+        _ if input.starts_with('"') => match parse_string(input) {
+            Some((string, len)) if len == input.len() => Ok(Literal::String(string)),
+            Some(_) => Err(PathQueryErrorKind::InvalidLiteral(input.to_owned())),
+            None => Err(PathQueryErrorKind::UnclosedString),
+        },
+        _ => input
+            .parse()
+            .map(Literal::Number)
+            .map_err(|_| PathQueryErrorKind::InvalidLiteral(input.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reflect;
+    use alloc::{string::ToString, vec, vec::Vec};
+    use bevy_platform::collections::{HashMap, HashSet};
+
+    #[derive(Reflect)]
+    struct World {
+        items: Vec<Item>,
+        stock: HashMap<String, u32>,
+        tags: HashSet<u8>,
+        origin: (f32, f32),
+    }
+
+    #[derive(Reflect)]
+    struct Item {
+        name: String,
+        count: u32,
+        kind: Kind,
+    }
+
+    #[derive(Reflect)]
+    enum Kind {
+        Weapon { damage: f32 },
+        Potion(u8),
+    }
+
+    fn create_world() -> World {
+        World {
+            items: vec![
+                Item {
+                    name: "sword".to_string(),
+                    count: 1,
+                    kind: Kind::Weapon { damage: 5.0 },
+                },
+                Item {
+                    name: "potion".to_string(),
+                    count: 3,
+                    kind: Kind::Potion(2),
+                },
+                Item {
+                    name: "bow".to_string(),
+                    count: 2,
+                    kind: Kind::Weapon { damage: 3.0 },
+                },
+            ],
+            stock: HashMap::from_iter([("gold".to_string(), 10), ("wood".to_string(), 4)]),
+            tags: HashSet::from_iter([1, 2]),
+            origin: (1.0, 2.0),
+        }
+    }
+
+    fn query<'r, T: Reflect>(query: &str, root: &'r dyn PartialReflect) -> Vec<&'r T> {
+        PathQuery::parse(query)
+            .unwrap()
+            .reflect_elements(root)
+            .into_iter()
+            .map(|element| element.try_downcast_ref::<T>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_match_wildcards() {
+        let world = create_world();
+        assert_eq!(
+            query::<String>(".items[*].name", &world),
+            ["sword", "potion", "bow"]
+        );
+        assert_eq!(query::<f32>(".origin.*", &world), [&1.0, &2.0]);
+        assert_eq!(query::<f32>("items[*].kind.damage", &world), [&5.0, &3.0]);
+        assert_eq!(query::<u8>(".items[1].kind.*", &world), [&2]);
+        assert_eq!(query::<u8>(".tags[*]", &world).len(), 2);
+
+        let mut stock = query::<u32>(".stock[*]", &world);
+        stock.sort();
+        assert_eq!(stock, [&4, &10]);
+    }
+
+    #[test]
+    fn should_match_map_keys_and_filters() {
+        let world = create_world();
+        assert_eq!(query::<u32>(".stock[\"gold\"]", &world), [&10]);
+        assert_eq!(query::<u32>(".stock[?@key == \"wood\"]", &world), [&4]);
+        assert_eq!(query::<u32>(".stock[?@ > 5]", &world), [&10]);
+        assert_eq!(
+            query::<String>(".items[?.count >= 2].name", &world),
+            ["potion", "bow"]
+        );
+        assert_eq!(
+            query::<String>(".items[?.kind == \"Weapon\"].name", &world),
+            ["sword", "bow"]
+        );
+        assert_eq!(
+            query::<String>(".items[?.kind.damage].name", &world),
+            ["sword", "bow"]
+        );
+        assert_eq!(
+            query::<String>(".items[?@key != 1].name", &world),
+            ["sword", "bow"]
+        );
+        assert!(query::<String>(".items[?.name == \"shield\"].name", &world).is_empty());
+    }
+
+    #[test]
+    fn should_mutate_matches() {
+        let mut world = create_world();
+
+        let count = PathQuery::parse(".items[?.kind == \"Weapon\"].count")
+            .unwrap()
+            .for_each_element_mut(&mut world, |count| count.apply(&0_u32));
+        assert_eq!(2, count);
+        assert_eq!(
+            world
+                .items
+                .iter()
+                .map(|item| item.count)
+                .collect::<Vec<_>>(),
+            [0, 3, 0]
+        );
+
+        let count = PathQuery::parse(".stock[*]")
+            .unwrap()
+            .for_each_element_mut(&mut world, |count| count.apply(&1_u32));
+        assert_eq!(2, count);
+        assert!(world.stock.values().all(|count| *count == 1));
+
+        let result = PathQuery::parse(".items[*].name")
+            .unwrap()
+            .try_for_each_element_mut(&mut world, |_| Err("stop"));
+        assert_eq!(Err("stop"), result);
+    }
+
+    #[test]
+    fn should_be_concrete_for_plain_paths() {
+        assert!(PathQuery::parse(".items[0].name").unwrap().is_concrete());
+        assert!(!PathQuery::parse(".items[*].name").unwrap().is_concrete());
+        assert_eq!(
+            PathQuery::parse(".items[1].kind.0").unwrap(),
+            PathQuery::from(ParsedPath::parse(".items[1].kind.0").unwrap())
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_queries() {
+        let kind = |query: &str| PathQuery::parse(query).unwrap_err().kind;
+        assert_eq!(kind(".items[*"), PathQueryErrorKind::Unclosed);
+        assert_eq!(kind(".items[?.count > 1"), PathQueryErrorKind::Unclosed);
+        assert_eq!(kind(".items[x]"), PathQueryErrorKind::InvalidIndex);
+        assert_eq!(kind(".stock[\"gold]"), PathQueryErrorKind::UnclosedString);
+        assert_eq!(
+            kind(".items[?.count > one]"),
+            PathQueryErrorKind::InvalidLiteral("one".to_string())
+        );
+        assert_eq!(kind(".items."), PathQueryErrorKind::ExpectedIdent);
+    }
+}
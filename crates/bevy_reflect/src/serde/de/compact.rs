@@ -0,0 +1,480 @@
+#[cfg(feature = "debug_stack")]
+use crate::serde::de::error_utils::TYPE_INFO_STACK;
+use crate::{
+    serde::{
+        de::{error_utils::make_custom_error, registration_utils::try_get_registration},
+        ReflectDeserializeWithRegistry, SerializationData, TypeIdManifest,
+    },
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, EnumInfo, Map, PartialReflect, ReflectDeserialize, Set,
+    Type, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+
+/// A compact deserializer for reflected types, meant for binary formats such as [`bincode`] or [`postcard`].
+///
+/// This is the deserializer counterpart to [`CompactReflectSerializer`].
+///
+/// See [`TypedCompactReflectDeserializer`] for a deserializer that expects a known type.
+///
+/// # Input
+///
+/// This deserializer expects a tuple of the numeric id of the type in the given [`TypeIdManifest`],
+/// followed by the value serialized by [`TypedCompactReflectSerializer`].
+///
+/// # Output
+///
+/// Like [`ReflectDeserializer`], this deserializer returns a [`Box<dyn PartialReflect>`] containing either
+/// the concrete type, for types registering [`ReflectDeserialize`], or a dynamic representation of the type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{CompactReflectDeserializer, CompactReflectSerializer, TypeIdManifest};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+/// let manifest = TypeIdManifest::from_registry(&registry);
+///
+/// let input = Position { x: 1.0, y: 2.0 };
+/// let serializer = CompactReflectSerializer::new(&input, &registry, &manifest);
+/// let bytes = bincode::serde::encode_to_vec(&serializer, bincode::config::standard()).unwrap();
+///
+/// let deserializer = CompactReflectDeserializer::new(&registry, &manifest);
+/// let (output, _) =
+///     bincode::serde::seed_decode_from_slice(deserializer, &bytes, bincode::config::standard())
+///         .unwrap();
+///
+/// assert_eq!(Position::from_reflect(output.as_ref()), Some(input));
+/// ```
+///
+/// [`bincode`]: https://docs.rs/bincode
+/// [`postcard`]: https://docs.rs/postcard
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [`TypedCompactReflectSerializer`]: crate::serde::TypedCompactReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    manifest: &'a TypeIdManifest,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    /// Creates a compact deserializer resolving type ids with `manifest`.
+    pub fn new(registry: &'a TypeRegistry, manifest: &'a TypeIdManifest) -> Self {
+        Self { registry, manifest }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for CompactReflectDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for CompactReflectDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a type id followed by a reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let id = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let type_path = self
+            .manifest
+            .type_path(id)
+            .ok_or_else(|| make_custom_error(format_args!("unknown type id {id}")))?;
+        let registration = self.registry.get_with_type_path(type_path).ok_or_else(|| {
+            make_custom_error(format_args!("no registration found for `{type_path}`"))
+        })?;
+        seq.next_element_seed(TypedCompactReflectDeserializer::new(
+            registration,
+            self.registry,
+        ))?
+        .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
+/// A compact deserializer for reflected types whose type is known.
+///
+/// This is the deserializer counterpart to [`TypedCompactReflectSerializer`].
+///
+/// [`TypedCompactReflectSerializer`]: crate::serde::TypedCompactReflectSerializer
+pub struct TypedCompactReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedCompactReflectDeserializer<'a> {
+    /// Creates a compact deserializer for the type of `registration`.
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.set(crate::type_info_stack::TypeInfoStack::new());
+
+        Self::new_internal(registration, registry)
+    }
+
+    fn new_internal(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        Self {
+            registration,
+            registry,
+        }
+    }
+
+    /// Deserializes the tuple of the non-skipped `fields`, returning the values of all fields.
+    fn deserialize_fields<'de, D>(
+        &self,
+        deserializer: D,
+        fields: impl Iterator<Item = Type>,
+    ) -> Result<impl Iterator<Item = Box<dyn PartialReflect>>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let serialization_data = self.registration.data::<SerializationData>();
+        let fields = fields.collect::<Vec<_>>();
+        let types = fields
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .map(|(_, ty)| *ty)
+            .collect();
+        let mut values = FieldsVisitor {
+            types,
+            registry: self.registry,
+        }
+        .deserialize(deserializer)?
+        .into_iter();
+
+        Ok((0..fields.len()).map(move |index| {
+            match serialization_data.and_then(|data| data.generate_default(index)) {
+                Some(default) => default.into_partial_reflect(),
+                None => values.next().unwrap(),
+            }
+        }))
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for TypedCompactReflectDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let deserialize_internal = || -> Result<Self::Value, D::Error> {
+            let type_info = self.registration.type_info();
+
+            // Handle both Value case and types that have a custom `ReflectDeserialize`
+            if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+                let value = deserialize_reflect.deserialize(deserializer)?;
+                return Ok(value.into_partial_reflect());
+            }
+
+            if let Some(deserialize_reflect) =
+                self.registration.data::<ReflectDeserializeWithRegistry>()
+            {
+                let value = deserialize_reflect.deserialize(deserializer, self.registry)?;
+                return Ok(value);
+            }
+
+            let value: Box<dyn PartialReflect> = match type_info {
+                TypeInfo::Struct(struct_info) => {
+                    let values = self.deserialize_fields(
+                        deserializer,
+                        struct_info.iter().map(|field| *field.ty()),
+                    )?;
+                    let mut dynamic_struct = DynamicStruct::default();
+                    for (field, value) in struct_info.iter().zip(values) {
+                        dynamic_struct.insert_boxed(field.name(), value);
+                    }
+                    dynamic_struct.set_represented_type(Some(type_info));
+                    Box::new(dynamic_struct)
+                }
+                TypeInfo::TupleStruct(tuple_struct_info) => {
+                    let values = self.deserialize_fields(
+                        deserializer,
+                        tuple_struct_info.iter().map(|field| *field.ty()),
+                    )?;
+                    let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                    values.for_each(|value| dynamic_tuple_struct.insert_boxed(value));
+                    dynamic_tuple_struct.set_represented_type(Some(type_info));
+                    Box::new(dynamic_tuple_struct)
+                }
+                TypeInfo::Tuple(tuple_info) => {
+                    let values = self.deserialize_fields(
+                        deserializer,
+                        tuple_info.iter().map(|field| *field.ty()),
+                    )?;
+                    let mut dynamic_tuple = DynamicTuple::default();
+                    values.for_each(|value| dynamic_tuple.insert_boxed(value));
+                    dynamic_tuple.set_represented_type(Some(type_info));
+                    Box::new(dynamic_tuple)
+                }
+                TypeInfo::Array(array_info) => {
+                    let values = FieldsVisitor {
+                        types: vec![array_info.item_ty(); array_info.capacity()],
+                        registry: self.registry,
+                    }
+                    .deserialize(deserializer)?;
+                    let mut dynamic_array = DynamicArray::new(values.into_boxed_slice());
+                    dynamic_array.set_represented_type(Some(type_info));
+                    Box::new(dynamic_array)
+                }
+                TypeInfo::List(list_info) => {
+                    let values = deserializer.deserialize_seq(SeqVisitor {
+                        ty: list_info.item_ty(),
+                        registry: self.registry,
+                    })?;
+                    let mut dynamic_list = DynamicList::default();
+                    values
+                        .into_iter()
+                        .for_each(|value| dynamic_list.push_box(value));
+                    dynamic_list.set_represented_type(Some(type_info));
+                    Box::new(dynamic_list)
+                }
+                TypeInfo::Set(set_info) => {
+                    let values = deserializer.deserialize_seq(SeqVisitor {
+                        ty: set_info.value_ty(),
+                        registry: self.registry,
+                    })?;
+                    let mut dynamic_set = DynamicSet::default();
+                    values.into_iter().for_each(|value| {
+                        dynamic_set.insert_boxed(value);
+                    });
+                    dynamic_set.set_represented_type(Some(type_info));
+                    Box::new(dynamic_set)
+                }
+                TypeInfo::Map(map_info) => {
+                    let mut dynamic_map = deserializer.deserialize_map(CompactMapVisitor {
+                        key_ty: map_info.key_ty(),
+                        value_ty: map_info.value_ty(),
+                        registry: self.registry,
+                    })?;
+                    dynamic_map.set_represented_type(Some(type_info));
+                    Box::new(dynamic_map)
+                }
+                TypeInfo::Enum(enum_info) => {
+                    let mut dynamic_enum = deserializer.deserialize_tuple(
+                        2,
+                        CompactEnumVisitor {
+                            enum_info,
+                            registry: self.registry,
+                        },
+                    )?;
+                    dynamic_enum.set_represented_type(Some(type_info));
+                    Box::new(dynamic_enum)
+                }
+                TypeInfo::Opaque(_) => {
+                    return Err(make_custom_error(format_args!(
+                        "type `{}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
+                        type_info.type_path(),
+                    )));
+                }
+            };
+            Ok(value)
+        };
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
+
+        let output = deserialize_internal();
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        output
+    }
+}
+
+/// Deserializes a tuple of values of the given types.
+struct FieldsVisitor<'a> {
+    types: Vec<Type>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for FieldsVisitor<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(self.types.len(), self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsVisitor<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a tuple of {} reflected values",
+            self.types.len()
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.types.len());
+        for (index, ty) in self.types.iter().enumerate() {
+            let registration = try_get_registration(*ty, self.registry)?;
+            let value = seq
+                .next_element_seed(TypedCompactReflectDeserializer::new_internal(
+                    registration,
+                    self.registry,
+                ))?
+                .ok_or_else(|| Error::invalid_length(index, &self))?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a sequence of values of the given type.
+struct SeqVisitor<'a> {
+    ty: Type,
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for SeqVisitor<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let registration = try_get_registration(self.ty, self.registry)?;
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(
+            TypedCompactReflectDeserializer::new_internal(registration, self.registry),
+        )? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct CompactMapVisitor<'a> {
+    key_ty: Type,
+    value_ty: Type,
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for CompactMapVisitor<'_> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of reflected values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let key_registration = try_get_registration(self.key_ty, self.registry)?;
+        let value_registration = try_get_registration(self.value_ty, self.registry)?;
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(TypedCompactReflectDeserializer::new_internal(
+            key_registration,
+            self.registry,
+        ))? {
+            let value = map.next_value_seed(TypedCompactReflectDeserializer::new_internal(
+                value_registration,
+                self.registry,
+            ))?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
+
+struct CompactEnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for CompactEnumVisitor<'_> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a variant index followed by the variant fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let variant_index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))? as usize;
+        let variant_info = self.enum_info.variant_at(variant_index).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no variant found at index {} on enum `{}`",
+                variant_index,
+                self.enum_info.type_path()
+            ))
+        })?;
+        let types = match variant_info {
+            VariantInfo::Struct(info) => info.iter().map(|field| *field.ty()).collect(),
+            VariantInfo::Tuple(info) => info.iter().map(|field| *field.ty()).collect(),
+            VariantInfo::Unit(_) => Vec::new(),
+        };
+        let values = seq
+            .next_element_seed(FieldsVisitor {
+                types,
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        let variant = match variant_info {
+            VariantInfo::Struct(info) => {
+                let mut dynamic_struct = DynamicStruct::default();
+                for (field, value) in info.iter().zip(values) {
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                DynamicVariant::Struct(dynamic_struct)
+            }
+            VariantInfo::Tuple(_) => {
+                let mut dynamic_tuple = DynamicTuple::default();
+                values
+                    .into_iter()
+                    .for_each(|value| dynamic_tuple.insert_boxed(value));
+                DynamicVariant::Tuple(dynamic_tuple)
+            }
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        };
+        Ok(DynamicEnum::new_with_index(
+            variant_index,
+            variant_info.name(),
+            variant,
+        ))
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{
+    de::versioned::VersionedVisitor, versioned_field_names, ReflectDeserializeWithRegistry,
+    SerializationData, TypeVersion,
+};
 use crate::{
     serde::{
         de::{
@@ -273,6 +276,8 @@ pub struct TypedReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    /// Whether a versioned value is deserialized without its version.
+    skip_version: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a, ()> {
@@ -290,6 +295,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            skip_version: false,
         }
     }
 
@@ -308,6 +314,7 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            skip_version: false,
         }
     }
 }
@@ -331,9 +338,17 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor: Some(processor),
+            skip_version: false,
         }
     }
 
+    /// Deserializes the value of a [versioned](crate::serde::TypeVersion) tuple struct or enum
+    /// without the version wrapping it.
+    pub(super) fn unversioned(mut self) -> Self {
+        self.skip_version = true;
+        self
+    }
+
     /// An internal constructor for creating a deserializer without resetting the type info stack.
     pub(super) fn new_internal(
         registration: &'a TypeRegistration,
@@ -344,6 +359,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor,
+            skip_version: false,
         }
     }
 }
@@ -391,9 +407,14 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
 
             match self.registration.type_info() {
                 TypeInfo::Struct(struct_info) => {
+                    let field_names = if struct_info.has_attribute::<TypeVersion>() {
+                        versioned_field_names(struct_info)
+                    } else {
+                        struct_info.field_names()
+                    };
                     let mut dynamic_struct = deserializer.deserialize_struct(
                         struct_info.type_path_table().ident().unwrap(),
-                        struct_info.field_names(),
+                        field_names,
                         StructVisitor {
                             struct_info,
                             registration: self.registration,
@@ -405,6 +426,19 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                     Ok(Box::new(dynamic_struct))
                 }
                 TypeInfo::TupleStruct(tuple_struct_info) => {
+                    if !self.skip_version
+                        && let Some(version) = tuple_struct_info.get_attribute::<TypeVersion>()
+                    {
+                        return deserializer.deserialize_tuple(
+                            2,
+                            VersionedVisitor {
+                                current_version: version.0,
+                                registration: self.registration,
+                                registry: self.registry,
+                                processor: self.processor,
+                            },
+                        );
+                    }
                     let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
                         && self.registration.data::<SerializationData>().is_none()
                     {
@@ -485,6 +519,19 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                     Ok(Box::new(dynamic_tuple))
                 }
                 TypeInfo::Enum(enum_info) => {
+                    if !self.skip_version
+                        && let Some(version) = enum_info.get_attribute::<TypeVersion>()
+                    {
+                        return deserializer.deserialize_tuple(
+                            2,
+                            VersionedVisitor {
+                                current_version: version.0,
+                                registration: self.registration,
+                                registry: self.registry,
+                                processor: self.processor,
+                            },
+                        );
+                    }
                     let mut dynamic_enum = if enum_info.type_path_table().module_path()
                         == Some("core::option")
                         && enum_info.type_path_table().ident() == Some("Option")
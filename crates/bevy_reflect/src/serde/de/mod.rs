@@ -1,9 +1,11 @@
+pub use compact::*;
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
 pub use processor::*;
 pub use registrations::*;
 
 mod arrays;
+mod compact;
 mod deserialize_with_registry;
 mod deserializer;
 mod enums;
@@ -21,6 +23,7 @@ mod structs;
 mod tuple_structs;
 mod tuple_utils;
 mod tuples;
+mod versioned;
 
 #[cfg(test)]
 mod tests {
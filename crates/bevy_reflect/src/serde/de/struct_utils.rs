@@ -5,9 +5,10 @@ use crate::{
             helpers::{ExpectedValues, Ident},
             registration_utils::try_get_registration,
         },
-        SerializationData, TypedReflectDeserializer,
+        ReflectMigrations, SerializationData, TypeVersion, TypedReflectDeserializer, VERSION_FIELD,
     },
-    DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration, TypeRegistry,
+    DynamicStruct, NamedField, Struct, StructInfo, StructVariantInfo, TypeRegistration,
+    TypeRegistry,
 };
 use alloc::string::ToString;
 use core::slice::Iter;
@@ -117,9 +118,112 @@ where
         dynamic_struct.insert_boxed(&key, value);
     }
 
+    insert_skipped_fields::<_, V::Error>(&mut dynamic_struct, info, registration);
+
+    Ok(dynamic_struct)
+}
+
+/// Deserializes a [versioned](TypeVersion) struct from a mapping of fields, returning a [`DynamicStruct`].
+///
+/// Data from an older version of the struct is upgraded with the [`ReflectMigrations`] registered for it.
+pub(super) fn visit_versioned_struct<'de, V, P>(
+    map: &mut V,
+    info: &'static StructInfo,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    mut processor: Option<&mut P>,
+) -> Result<DynamicStruct, V::Error>
+where
+    V: MapAccess<'de>,
+    P: ReflectDeserializerProcessor,
+{
+    let current_version = info
+        .get_attribute::<TypeVersion>()
+        .map_or(0, |version| version.0);
+    let migrations = registration.data::<ReflectMigrations>();
+
+    // Data without a version field predates the versioning of the type.
+    let mut version = 0;
+    let mut dynamic_struct = DynamicStruct::default();
+    while let Some(Ident(key)) = map.next_key::<Ident>()? {
+        if key == VERSION_FIELD {
+            version = map.next_value::<u32>()?;
+            continue;
+        }
+        let ty = match info.field(&key) {
+            Some(field) => *field.ty(),
+            None => migrations
+                .and_then(|migrations| migrations.field_type(&key, info))
+                .ok_or_else(|| {
+                    let fields = info.iter_fields().map(NamedField::name);
+                    make_custom_error::<V::Error>(format_args!(
+                        "unknown field `{}`, expected one of {:?}",
+                        key,
+                        ExpectedValues::from_iter(fields)
+                    ))
+                })?,
+        };
+        let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
+            try_get_registration(ty, registry)?,
+            registry,
+            processor.as_deref_mut(),
+        ))?;
+        dynamic_struct.insert_boxed(key, value);
+    }
+
+    if version > current_version {
+        return Err(make_custom_error(format_args!(
+            "version {} of struct `{}` is newer than its current version {}",
+            version,
+            info.type_path(),
+            current_version,
+        )));
+    }
+    if version < current_version {
+        migrations
+            .ok_or(version)
+            .and_then(|migrations| {
+                migrations.migrate(&mut dynamic_struct, version, current_version)
+            })
+            .map_err(|missing_version| {
+                make_custom_error::<V::Error>(format_args!(
+                    "no migration registered from version {} of struct `{}`",
+                    missing_version,
+                    info.type_path(),
+                ))
+            })?;
+    }
+
+    // Migrations may add and remove fields in any order.
+    let mut ordered_struct = DynamicStruct::default();
+    for field in info.iter() {
+        if let Some(value) = dynamic_struct.remove(field.name()) {
+            ordered_struct.insert_boxed(field.name(), value);
+        }
+    }
+    if let Some(name) = dynamic_struct.name_at(0) {
+        return Err(make_custom_error(format_args!(
+            "field `{}` does not exist in version {} of struct `{}`",
+            name,
+            current_version,
+            info.type_path(),
+        )));
+    }
+
+    insert_skipped_fields::<_, V::Error>(&mut ordered_struct, info, registration);
+
+    Ok(ordered_struct)
+}
+
+/// Inserts the default values of the fields skipped during serialization.
+fn insert_skipped_fields<T: StructLikeInfo, E: Error>(
+    dynamic_struct: &mut DynamicStruct,
+    info: &T,
+    registration: &TypeRegistration,
+) {
     if let Some(serialization_data) = registration.data::<SerializationData>() {
         for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
-            let Ok(field) = info.field_at::<V::Error>(*skipped_index) else {
+            let Ok(field) = info.field_at::<E>(*skipped_index) else {
                 continue;
             };
             dynamic_struct.insert_boxed(
@@ -128,8 +232,6 @@ where
             );
         }
     }
-
-    Ok(dynamic_struct)
 }
 
 /// Deserializes a [struct-like] type from a sequence of fields, returning a [`DynamicStruct`].
@@ -1,9 +1,15 @@
 use crate::{
-    serde::de::struct_utils::{visit_struct, visit_struct_seq},
+    serde::{
+        de::{
+            error_utils::make_custom_error,
+            struct_utils::{visit_struct, visit_struct_seq, visit_versioned_struct},
+        },
+        ReflectMigrations, TypeVersion,
+    },
     DynamicStruct, StructInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
-use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
 
 use super::ReflectDeserializerProcessor;
 
@@ -28,6 +34,20 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
     where
         A: SeqAccess<'de>,
     {
+        if let Some(current_version) = self.struct_info.get_attribute::<TypeVersion>() {
+            let version = seq
+                .next_element::<u32>()?
+                .ok_or_else(|| Error::invalid_length(0, &self))?;
+            // Sequences only store the fields of the current version.
+            if version != current_version.0 {
+                return Err(make_custom_error(format_args!(
+                    "version {} of struct `{}` cannot be read from a sequence of fields, only its current version {} can",
+                    version,
+                    self.struct_info.type_path(),
+                    current_version.0,
+                )));
+            }
+        }
         visit_struct_seq(
             &mut seq,
             self.struct_info,
@@ -41,6 +61,17 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVisitor<'_, P>
     where
         V: MapAccess<'de>,
     {
+        if self.struct_info.has_attribute::<TypeVersion>()
+            || self.registration.contains::<ReflectMigrations>()
+        {
+            return visit_versioned_struct(
+                &mut map,
+                self.struct_info,
+                self.registration,
+                self.registry,
+                self.processor,
+            );
+        }
         visit_struct(
             &mut map,
             self.struct_info,
@@ -0,0 +1,117 @@
+use crate::{
+    serde::{
+        de::{error_utils::make_custom_error, registration_utils::try_get_registration},
+        ReflectMigrations, TypedReflectDeserializer,
+    },
+    DynamicTupleStruct, PartialReflect, ReflectOwned, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use alloc::boxed::Box;
+use core::{fmt, fmt::Formatter};
+use serde::de::{Error, SeqAccess, Visitor};
+
+use super::ReflectDeserializerProcessor;
+
+/// A [`Visitor`] for deserializing the `(version, value)` tuple of a [versioned] tuple struct or
+/// enum.
+///
+/// Values of older versions are upgraded with the [`ReflectMigrations`] registered for the type.
+///
+/// [versioned]: crate::serde::TypeVersion
+pub(super) struct VersionedVisitor<'a, P> {
+    pub current_version: u32,
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for VersionedVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected versioned value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_info = self.registration.type_info();
+        let version = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"a (version, value) tuple"))?;
+        if version > self.current_version {
+            return Err(make_custom_error(format_args!(
+                "version {} of `{}` is newer than its current version {}",
+                version,
+                type_info.type_path(),
+                self.current_version,
+            )));
+        }
+
+        let migrations = self.registration.data::<ReflectMigrations>();
+        let source_registration = match migrations
+            .and_then(|migrations| migrations.source_type(version))
+            .filter(|_| version < self.current_version)
+        {
+            Some(ty) => try_get_registration(ty, self.registry)?,
+            None => self.registration,
+        };
+        let value = seq
+            .next_element_seed(
+                TypedReflectDeserializer::new_internal(
+                    source_registration,
+                    self.registry,
+                    self.processor,
+                )
+                .unversioned(),
+            )?
+            .ok_or_else(|| Error::invalid_length(1, &"a (version, value) tuple"))?;
+        if version == self.current_version {
+            return Ok(value);
+        }
+
+        let missing_migration = |missing_version| {
+            make_custom_error::<A::Error>(format_args!(
+                "no migration registered from version {} of `{}`",
+                missing_version,
+                type_info.type_path(),
+            ))
+        };
+        let unexpected_data = || {
+            make_custom_error::<A::Error>(format_args!(
+                "data of version {} of `{}` has a different kind than the type",
+                version,
+                type_info.type_path(),
+            ))
+        };
+        let migrations = migrations.ok_or_else(|| missing_migration(version))?;
+        match type_info {
+            TypeInfo::TupleStruct(_) => {
+                let mut dynamic_tuple_struct = match value.reflect_owned() {
+                    ReflectOwned::TupleStruct(value) => value.to_dynamic_tuple_struct(),
+                    ReflectOwned::Tuple(value) => {
+                        DynamicTupleStruct::from(value.to_dynamic_tuple())
+                    }
+                    _ => return Err(unexpected_data()),
+                };
+                migrations
+                    .migrate_tuple_struct(&mut dynamic_tuple_struct, version, self.current_version)
+                    .map_err(missing_migration)?;
+                dynamic_tuple_struct.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Enum(_) => {
+                let ReflectOwned::Enum(value) = value.reflect_owned() else {
+                    return Err(unexpected_data());
+                };
+                let mut dynamic_enum = value.to_dynamic_enum();
+                migrations
+                    .migrate_enum(&mut dynamic_enum, version, self.current_version)
+                    .map_err(missing_migration)?;
+                dynamic_enum.set_represented_type(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            _ => Err(unexpected_data()),
+        }
+    }
+}
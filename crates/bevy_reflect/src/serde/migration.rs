@@ -0,0 +1,321 @@
+use crate::{
+    DynamicEnum, DynamicStruct, DynamicTupleStruct, PartialReflect, Reflect, StructInfo, Type,
+    TypeInfo, TypePath,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform::{
+    hash::NoOpHash,
+    sync::{Arc, PoisonError, RwLock},
+};
+use bevy_utils::TypeIdMap;
+
+/// Name of the field holding the version of a [versioned](TypeVersion) struct in its serialized data.
+pub const VERSION_FIELD: &str = "__version";
+
+/// A [custom attribute] declaring the version of a struct, tuple struct or enum type.
+///
+/// When data is deserialized with an older version, the [migrations] registered for the type upgrade it to the
+/// current version before it is converted with [`FromReflect`](crate::FromReflect).
+///
+/// The version is serialized differently depending on the kind of type:
+/// - Structs write their version first, in the [`VERSION_FIELD`] field.
+///   Data without this field predates the versioning of the type, and counts as version `0`.
+///   Formats serializing structs as maps (such as RON, JSON or `MessagePack` with named fields) can be migrated
+///   from any version.
+///   Formats serializing structs as sequences (such as bincode) only store the fields of the current version,
+///   so only data of the current version can be read from them.
+/// - Tuple structs and enums are serialized as a `(version, value)` tuple, in every format.
+///   The layout of older versions is declared with [`Migration::from_type`].
+///   Since their value does not include the version, data written before the type was versioned can't be read.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Struct, TypeRegistry, serde::{Migration, TypedReflectDeserializer, TypeVersion}};
+/// # use serde::de::DeserializeSeed;
+/// # use core::any::TypeId;
+/// // Version 0 of `Player` had a `hp: f32` field and a `mana: u32` field.
+/// #[derive(Reflect)]
+/// #[reflect(@TypeVersion(1))]
+/// struct Player {
+///     health: f32,
+///     armor: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register_migration::<Player>(
+///     0,
+///     Migration::new()
+///         .rename_field("hp", "health")
+///         .remove_field::<u32>("mana")
+///         .add_field("armor", 0_u32),
+/// );
+///
+/// let registration = registry.get(TypeId::of::<Player>()).unwrap();
+/// let mut deserializer = ron::de::Deserializer::from_str("(hp: 8.0, mana: 3)").unwrap();
+/// let player = TypedReflectDeserializer::new(registration, &registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let player = player.reflect_ref().as_struct().unwrap();
+///
+/// assert_eq!(player.field("health").unwrap().try_downcast_ref::<f32>(), Some(&8.0));
+/// assert_eq!(player.field("armor").unwrap().try_downcast_ref::<u32>(), Some(&0));
+/// ```
+///
+/// [custom attribute]: crate::attributes::CustomAttributes
+/// [migrations]: Migration
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TypeVersion(pub u32);
+
+type AddedField = (
+    String,
+    Box<dyn Fn() -> Box<dyn PartialReflect> + Send + Sync>,
+);
+type MapFn = Box<dyn Fn(&mut DynamicStruct) + Send + Sync>;
+type MapTupleStructFn = Box<dyn Fn(&mut DynamicTupleStruct) + Send + Sync>;
+type MapEnumFn = Box<dyn Fn(&mut DynamicEnum) + Send + Sync>;
+
+/// Upgrades the deserialized data of a [versioned](TypeVersion) type from one version to the next.
+///
+/// For structs, migrations operate on the [`DynamicStruct`] produced by deserialization, applying in order:
+/// 1. the [renamed fields](Migration::rename_field),
+/// 2. the [added fields](Migration::add_field),
+/// 3. the [custom function](Migration::map), which can still read the fields about to be removed,
+/// 4. the [removed fields](Migration::remove_field).
+///
+/// For tuple structs and enums, the data is deserialized as the type given to [`Migration::from_type`] (or as the
+/// current type if there is none), then converted with [`Migration::map_tuple_struct`] or [`Migration::map_enum`].
+///
+/// Migrations are registered with [`TypeRegistry::register_migration`](crate::TypeRegistry::register_migration).
+#[derive(Default)]
+pub struct Migration {
+    renamed_fields: Vec<(String, String)>,
+    added_fields: Vec<AddedField>,
+    removed_fields: Vec<(String, Type)>,
+    map: Option<MapFn>,
+    source_type: Option<Type>,
+    map_tuple_struct: Option<MapTupleStructFn>,
+    map_enum: Option<MapEnumFn>,
+}
+
+impl Migration {
+    /// Creates a migration which doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the field `old` into `new`.
+    pub fn rename_field(mut self, old: impl Into<String>, new: impl Into<String>) -> Self {
+        self.renamed_fields.push((old.into(), new.into()));
+        self
+    }
+
+    /// Adds the field `name` with the given value, if it is missing from the data.
+    pub fn add_field<T: Reflect + Clone>(mut self, name: impl Into<String>, value: T) -> Self {
+        self.added_fields.push((
+            name.into(),
+            Box::new(move || Box::new(value.clone()).into_partial_reflect()),
+        ));
+        self
+    }
+
+    /// Removes the field `name`, whose values were of type `T`.
+    ///
+    /// The type of the removed field is needed to deserialize it, and must be registered in the type registry.
+    pub fn remove_field<T: Reflect + TypePath>(mut self, name: impl Into<String>) -> Self {
+        self.removed_fields.push((name.into(), Type::of::<T>()));
+        self
+    }
+
+    /// Applies `map` to the data.
+    ///
+    /// Fields that don't exist in the current version of the type must be declared with [`Migration::remove_field`]
+    /// for their value to be deserialized.
+    pub fn map(mut self, map: impl Fn(&mut DynamicStruct) + Send + Sync + 'static) -> Self {
+        self.map = Some(Box::new(map));
+        self
+    }
+
+    /// Deserializes the data of the version this migration upgrades from as the type `T`.
+    ///
+    /// This describes the layout of older versions of tuple structs and enums, for example with a tuple type for
+    /// the fields of a tuple struct, or with a copy of an older enum.
+    /// `T` must be registered in the type registry.
+    pub fn from_type<T: Reflect + TypePath>(mut self) -> Self {
+        self.source_type = Some(Type::of::<T>());
+        self
+    }
+
+    /// Applies `map` to the data of a tuple struct.
+    pub fn map_tuple_struct(
+        mut self,
+        map: impl Fn(&mut DynamicTupleStruct) + Send + Sync + 'static,
+    ) -> Self {
+        self.map_tuple_struct = Some(Box::new(map));
+        self
+    }
+
+    /// Applies `map` to the data of an enum.
+    pub fn map_enum(mut self, map: impl Fn(&mut DynamicEnum) + Send + Sync + 'static) -> Self {
+        self.map_enum = Some(Box::new(map));
+        self
+    }
+
+    fn apply(&self, value: &mut DynamicStruct) {
+        for (old, new) in &self.renamed_fields {
+            if let Some(field) = value.remove(old) {
+                value.insert_boxed(new.to_owned(), field);
+            }
+        }
+        for (name, default) in &self.added_fields {
+            if value.index_of(name).is_none() {
+                value.insert_boxed(name.to_owned(), default());
+            }
+        }
+        if let Some(map) = &self.map {
+            map(value);
+        }
+        for (name, _) in &self.removed_fields {
+            value.remove(name);
+        }
+    }
+}
+
+/// Type data holding the [migrations](Migration) of a [versioned](TypeVersion) struct, indexed by the version they
+/// upgrade from.
+#[derive(Clone, Default)]
+pub struct ReflectMigrations {
+    migrations: BTreeMap<u32, Arc<Migration>>,
+}
+
+impl ReflectMigrations {
+    /// Sets the migration from `from_version` to the next version, replacing any previous one.
+    pub fn insert(&mut self, from_version: u32, migration: Migration) {
+        self.migrations.insert(from_version, Arc::new(migration));
+    }
+
+    /// Returns the migration from `from_version` to the next version, if any.
+    pub fn get(&self, from_version: u32) -> Option<&Migration> {
+        self.migrations.get(&from_version).map(AsRef::as_ref)
+    }
+
+    /// Upgrades `value` from `from_version` to `to_version`.
+    ///
+    /// Returns the first version without a migration on error.
+    pub fn migrate(
+        &self,
+        value: &mut DynamicStruct,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<(), u32> {
+        for version in from_version..to_version {
+            self.get(version).ok_or(version)?.apply(value);
+        }
+        Ok(())
+    }
+
+    /// Upgrades the tuple struct `value` from `from_version` to `to_version`.
+    ///
+    /// Returns the first version without a migration on error.
+    pub fn migrate_tuple_struct(
+        &self,
+        value: &mut DynamicTupleStruct,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<(), u32> {
+        for version in from_version..to_version {
+            if let Some(map) = &self.get(version).ok_or(version)?.map_tuple_struct {
+                map(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Upgrades the enum `value` from `from_version` to `to_version`.
+    ///
+    /// Returns the first version without a migration on error.
+    pub fn migrate_enum(
+        &self,
+        value: &mut DynamicEnum,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<(), u32> {
+        for version in from_version..to_version {
+            if let Some(map) = &self.get(version).ok_or(version)?.map_enum {
+                map(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the type the data of `version` is deserialized as, if it differs from the current type.
+    pub(crate) fn source_type(&self, version: u32) -> Option<Type> {
+        self.get(version)
+            .and_then(|migration| migration.source_type)
+    }
+
+    /// Returns the type of the field `name`, which may only exist in older versions of the type described by `info`.
+    pub(crate) fn field_type(&self, name: &str, info: &StructInfo) -> Option<Type> {
+        let mut name = name.to_string();
+        for migration in self.migrations.values() {
+            if let Some((_, ty)) = migration
+                .removed_fields
+                .iter()
+                .find(|(removed, _)| *removed == name)
+            {
+                return Some(*ty);
+            }
+            if let Some((_, new)) = migration
+                .renamed_fields
+                .iter()
+                .find(|(old, _)| *old == name)
+            {
+                name.clone_from(new);
+            }
+        }
+        info.field(&name).map(|field| *field.ty())
+    }
+}
+
+/// Returns the [version](TypeVersion) of the struct, tuple struct or enum type described by `info`.
+pub(crate) fn type_version(info: Option<&TypeInfo>) -> Option<u32> {
+    let version = match info? {
+        TypeInfo::Struct(info) => info.get_attribute::<TypeVersion>(),
+        TypeInfo::TupleStruct(info) => info.get_attribute::<TypeVersion>(),
+        TypeInfo::Enum(info) => info.get_attribute::<TypeVersion>(),
+        _ => None,
+    };
+    version.map(|version| version.0)
+}
+
+/// Returns the field names of the versioned struct described by `info`, preceded by [`VERSION_FIELD`].
+///
+/// Formats serializing structs as sequences use the number of field names to know how many values to read.
+pub(crate) fn versioned_field_names(info: &StructInfo) -> &'static [&'static str] {
+    static FIELD_NAMES: RwLock<TypeIdMap<&'static [&'static str]>> =
+        RwLock::new(TypeIdMap::with_hasher(NoOpHash));
+
+    if let Some(names) = FIELD_NAMES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&info.type_id())
+    {
+        return names;
+    }
+    let names: Vec<&'static str> = core::iter::once(VERSION_FIELD)
+        .chain(info.field_names().iter().copied())
+        .collect();
+    FIELD_NAMES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(info.type_id())
+        // Leaked once per type, like the type info cells, to obtain a `&'static` slice.
+        .or_insert_with(|| Box::leak(names.into_boxed_slice()))
+}
@@ -1,19 +1,29 @@
 //! Serde integration for reflected types.
 
 mod de;
+mod migration;
 mod ser;
 mod type_data;
+mod type_ids;
 
 pub use de::*;
+pub use migration::*;
 pub use ser::*;
 pub use type_data::*;
+pub use type_ids::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct, FromReflect,
-        PartialReflect, Reflect, Struct,
+        type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct, DynamicVariant, Enum,
+        FromReflect, PartialReflect, Reflect, Struct, TupleStruct, Typed,
+    };
+    use alloc::{
+        borrow::ToOwned,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
     };
     use serde::de::DeserializeSeed;
 
@@ -141,6 +151,504 @@ mod tests {
         );
     }
 
+    #[derive(Debug, Reflect, PartialEq)]
+    #[reflect(@TypeVersion(2))]
+    struct Versioned {
+        health: f32,
+        armor: u32,
+        level: u8,
+    }
+
+    fn create_versioned_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<(u8, u8)>();
+        registry.register_migration::<Versioned>(
+            0,
+            Migration::new()
+                .rename_field("hp", "health")
+                .remove_field::<u32>("mana")
+                .add_field("armor", 1_u32),
+        );
+        registry.register_migration::<Versioned>(
+            1,
+            Migration::new()
+                .remove_field::<(u8, u8)>("progress")
+                .map(|value| {
+                    let (level, _) = value
+                        .field("progress")
+                        .and_then(<(u8, u8)>::from_reflect)
+                        .unwrap_or_default();
+                    value.insert("level", level);
+                }),
+        );
+        registry
+    }
+
+    fn deserialize_versioned(
+        input: &str,
+        registry: &TypeRegistry,
+    ) -> Result<Versioned, ron::Error> {
+        let registration = registry.get(core::any::TypeId::of::<Versioned>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value =
+            TypedReflectDeserializer::new(registration, registry).deserialize(&mut deserializer)?;
+        Ok(Versioned::from_reflect(value.as_partial_reflect()).unwrap())
+    }
+
+    #[test]
+    fn should_migrate_versioned_struct() {
+        let registry = create_versioned_registry();
+        let expected = Versioned {
+            health: 8.0,
+            armor: 1,
+            level: 3,
+        };
+
+        let from_v0 = deserialize_versioned("(hp: 8.0, mana: 5)", &registry).unwrap();
+        assert_eq!(expected.health, from_v0.health);
+        assert_eq!(expected.armor, from_v0.armor);
+        assert_eq!(0, from_v0.level);
+
+        let from_v1 = deserialize_versioned(
+            "(__version: 1, progress: (3, 40), armor: 1, health: 8.0)",
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(expected, from_v1);
+    }
+
+    #[test]
+    fn should_roundtrip_versioned_struct() {
+        let registry = create_versioned_registry();
+        let value = Versioned {
+            health: 8.0,
+            armor: 2,
+            level: 3,
+        };
+
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("(__version:2,health:8.0,armor:2,level:3)", serialized);
+
+        assert_eq!(
+            value,
+            deserialize_versioned(&serialized, &registry).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_newer_version() {
+        let registry = create_versioned_registry();
+        let error =
+            deserialize_versioned("(__version: 3, health: 8.0, armor: 2, level: 3)", &registry)
+                .unwrap_err();
+        assert_eq!(
+            ron::Error::Message(
+                "version 3 of struct `bevy_reflect::serde::tests::Versioned` is newer than its current version 2 (stack: `bevy_reflect::serde::tests::Versioned`)"
+                    .into()
+            ),
+            error
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_without_migration() {
+        #[derive(Debug, Reflect, PartialEq)]
+        #[reflect(@TypeVersion(1))]
+        struct Unmigrated {
+            value: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Unmigrated>();
+        let registration = registry.get(core::any::TypeId::of::<Unmigrated>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str("(value: 1)").unwrap();
+        let error = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert_eq!(
+            ron::Error::Message(
+                "no migration registered from version 0 of struct `bevy_reflect::serde::tests::Unmigrated` (stack: `bevy_reflect::serde::tests::Unmigrated`)"
+                    .into()
+            ),
+            error
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_versioned_struct_in_binary_formats() {
+        let registry = create_versioned_registry();
+        let value = Versioned {
+            health: 8.0,
+            armor: 2,
+            level: 3,
+        };
+        let registration = registry.get(core::any::TypeId::of::<Versioned>()).unwrap();
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+
+        // MessagePack with named fields is self-describing but not human-readable: the version must still be
+        // written, or the data would be migrated again.
+        let bytes = rmp_serde::to_vec_named(&serializer).unwrap();
+        let output = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+            .unwrap();
+        assert_eq!(value, Versioned::from_reflect(&*output).unwrap());
+
+        let config = bincode::config::standard();
+        let bytes = bincode::serde::encode_to_vec(&serializer, config).unwrap();
+        let (output, _) = bincode::serde::seed_decode_from_slice(
+            TypedReflectDeserializer::new(registration, &registry),
+            &bytes,
+            config,
+        )
+        .unwrap();
+        assert_eq!(value, Versioned::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_not_read_older_struct_version_from_sequence() {
+        #[derive(Reflect)]
+        #[reflect(@TypeVersion(1))]
+        struct Old {
+            value: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Old>();
+        let registration = registry.get(core::any::TypeId::of::<Old>()).unwrap();
+
+        let config = bincode::config::standard();
+        let bytes = bincode::serde::encode_to_vec((0_u32, 5_u32), config).unwrap();
+        let result = bincode::serde::seed_decode_from_slice(
+            TypedReflectDeserializer::new(registration, &registry),
+            &bytes,
+            config,
+        );
+        assert!(result.is_err());
+    }
+
+    #[derive(Debug, Reflect, PartialEq)]
+    #[reflect(@TypeVersion(1))]
+    struct VersionedColor(f32, f32, f32, f32);
+
+    #[derive(Debug, Reflect, PartialEq)]
+    #[reflect(@TypeVersion(1))]
+    enum VersionedShape {
+        Circle { radius: f32 },
+        Square(f32),
+    }
+
+    /// Version 0 of `VersionedShape`.
+    #[derive(Reflect)]
+    enum ShapeV0 {
+        Circle(f32),
+        Square(f32),
+    }
+
+    fn create_versioned_tuple_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<(u8, u8, u8)>();
+        registry.register::<ShapeV0>();
+        registry.register_migration::<VersionedColor>(
+            0,
+            Migration::new()
+                .from_type::<(u8, u8, u8)>()
+                .map_tuple_struct(|value| {
+                    let mut color = DynamicTupleStruct::default();
+                    for field in value.iter_fields() {
+                        let channel = u8::from_reflect(field).unwrap_or_default();
+                        color.insert(f32::from(channel) / 255.0);
+                    }
+                    color.insert(1.0_f32);
+                    *value = color;
+                }),
+        );
+        registry.register_migration::<VersionedShape>(
+            0,
+            Migration::new().from_type::<ShapeV0>().map_enum(|value| {
+                if value.variant_name() == "Circle" {
+                    let radius = value
+                        .field_at(0)
+                        .and_then(f32::from_reflect)
+                        .unwrap_or_default();
+                    let mut circle = DynamicStruct::default();
+                    circle.insert("radius", radius);
+                    value.set_variant("Circle", DynamicVariant::Struct(circle));
+                }
+            }),
+        );
+        registry
+    }
+
+    fn deserialize_ron<T: FromReflect + crate::TypePath>(
+        input: &str,
+        registry: &TypeRegistry,
+    ) -> Result<T, ron::Error> {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let value = TypedReflectDeserializer::of::<T>(registry).deserialize(&mut deserializer)?;
+        Ok(T::from_reflect(value.as_partial_reflect()).unwrap())
+    }
+
+    #[test]
+    fn should_migrate_versioned_tuple_struct() {
+        let registry = create_versioned_tuple_registry();
+        let color = VersionedColor(1.0, 0.0, 0.2, 1.0);
+
+        let serialized =
+            ron::ser::to_string(&TypedReflectSerializer::new(&color, &registry)).unwrap();
+        assert_eq!("(1,(1.0,0.0,0.2,1.0))", serialized);
+        assert_eq!(
+            color,
+            deserialize_ron::<VersionedColor>(&serialized, &registry).unwrap()
+        );
+
+        assert_eq!(
+            color,
+            deserialize_ron::<VersionedColor>("(0, (255, 0, 51))", &registry).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_migrate_versioned_enum() {
+        let registry = create_versioned_tuple_registry();
+        let circle = VersionedShape::Circle { radius: 2.0 };
+
+        let serialized =
+            ron::ser::to_string(&TypedReflectSerializer::new(&circle, &registry)).unwrap();
+        assert_eq!("(1,Circle(radius:2.0))", serialized);
+        assert_eq!(
+            circle,
+            deserialize_ron::<VersionedShape>(&serialized, &registry).unwrap()
+        );
+
+        assert_eq!(
+            circle,
+            deserialize_ron::<VersionedShape>("(0, Circle(2.0))", &registry).unwrap()
+        );
+        assert_eq!(
+            VersionedShape::Square(3.0),
+            deserialize_ron::<VersionedShape>("(0, Square(3.0))", &registry).unwrap()
+        );
+
+        let error = deserialize_ron::<VersionedShape>("(2, Square(3.0))", &registry).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("is newer than its current version 1"));
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum CompactEnum {
+        Unit,
+        Tuple(u8, Option<u16>),
+        Struct { values: Vec<i32> },
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct CompactStruct {
+        id: u32,
+        #[reflect(skip_serializing)]
+        #[reflect(default = "default_cache")]
+        cache: u8,
+        name: String,
+        pair: (f32, bool),
+        array: [u8; 3],
+        enums: Vec<CompactEnum>,
+        map: bevy_platform::collections::HashMap<u8, CompactEnum>,
+    }
+
+    fn default_cache() -> u8 {
+        42
+    }
+
+    fn create_compact_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<CompactStruct>();
+        registry
+    }
+
+    #[test]
+    fn should_roundtrip_compact() {
+        let registry = create_compact_registry();
+        let manifest = TypeIdManifest::from_registry(&registry);
+        let input = CompactStruct {
+            id: 7,
+            cache: 1,
+            name: "compact".into(),
+            pair: (1.5, true),
+            array: [1, 2, 3],
+            enums: vec![
+                CompactEnum::Unit,
+                CompactEnum::Tuple(4, Some(5)),
+                CompactEnum::Struct {
+                    values: vec![-1, 2],
+                },
+            ],
+            map: [(0, CompactEnum::Tuple(0, None))].into_iter().collect(),
+        };
+
+        let config = bincode::config::standard();
+        let serializer = CompactReflectSerializer::new(&input, &registry, &manifest);
+        let bytes = bincode::serde::encode_to_vec(&serializer, config).unwrap();
+        let verbose_bytes =
+            bincode::serde::encode_to_vec(ReflectSerializer::new(&input, &registry), config)
+                .unwrap();
+        assert!(bytes.len() * 2 < verbose_bytes.len());
+
+        let deserializer = CompactReflectDeserializer::new(&registry, &manifest);
+        let (output, read) =
+            bincode::serde::seed_decode_from_slice(deserializer, &bytes, config).unwrap();
+        assert_eq!(bytes.len(), read);
+
+        let output = CompactStruct::from_reflect(output.as_partial_reflect()).unwrap();
+        assert_eq!(
+            CompactStruct {
+                cache: default_cache(),
+                ..input
+            },
+            output
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_typed_compact_with_self_describing_format() {
+        let registry = create_compact_registry();
+        let input = CompactEnum::Struct { values: vec![3] };
+
+        let serializer = TypedCompactReflectSerializer::new(&input, &registry);
+        let bytes = rmp_serde::to_vec(&serializer).unwrap();
+
+        let registration = registry
+            .get(core::any::TypeId::of::<CompactEnum>())
+            .unwrap();
+        let deserializer = TypedCompactReflectDeserializer::new(registration, &registry);
+        let output = deserializer
+            .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+            .unwrap();
+        assert_eq!(
+            Some(input),
+            CompactEnum::from_reflect(output.as_partial_reflect())
+        );
+    }
+
+    fn compact_struct() -> CompactStruct {
+        CompactStruct {
+            id: 7,
+            cache: 1,
+            name: "compact".into(),
+            pair: (1.5, true),
+            array: [1, 2, 3],
+            enums: vec![CompactEnum::Unit],
+            map: Default::default(),
+        }
+    }
+
+    #[test]
+    fn should_serialize_compact_reordered_dynamic_struct_in_type_order() {
+        let registry = create_compact_registry();
+        let input = compact_struct();
+
+        let mut reordered = DynamicStruct::default();
+        reordered.set_represented_type(Some(CompactStruct::type_info()));
+        for index in (0..input.field_len()).rev() {
+            let field = input.field_at(index).unwrap();
+            reordered.insert_boxed(input.name_at(index).unwrap(), field.to_dynamic());
+        }
+
+        let config = bincode::config::standard();
+        let expected = bincode::serde::encode_to_vec(
+            TypedCompactReflectSerializer::new(&input, &registry),
+            config,
+        )
+        .unwrap();
+        let bytes = bincode::serde::encode_to_vec(
+            TypedCompactReflectSerializer::new(&reordered, &registry),
+            config,
+        )
+        .unwrap();
+        assert_eq!(expected, bytes);
+
+        let registration = registry
+            .get(core::any::TypeId::of::<CompactStruct>())
+            .unwrap();
+        let deserializer = TypedCompactReflectDeserializer::new(registration, &registry);
+        let (output, _) =
+            bincode::serde::seed_decode_from_slice(deserializer, &bytes, config).unwrap();
+        assert_eq!(
+            Some(CompactStruct {
+                cache: default_cache(),
+                ..input
+            }),
+            CompactStruct::from_reflect(output.as_partial_reflect())
+        );
+    }
+
+    #[test]
+    fn should_not_serialize_compact_partial_dynamic_struct() {
+        let registry = create_compact_registry();
+        let mut partial = compact_struct().to_dynamic_struct();
+        partial.remove("name");
+
+        let error = bincode::serde::encode_to_vec(
+            TypedCompactReflectSerializer::new(&partial, &registry),
+            bincode::config::standard(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains(
+            "missing field `name` of struct `bevy_reflect::serde::tests::CompactStruct`"
+        ));
+
+        // A partial struct with only skipped fields must not underflow the field count.
+        let mut empty = DynamicStruct::default();
+        empty.set_represented_type(Some(CompactStruct::type_info()));
+        let error = bincode::serde::encode_to_vec(
+            TypedCompactReflectSerializer::new(&empty, &registry),
+            bincode::config::standard(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("missing field `id`"));
+    }
+
+    #[test]
+    fn should_keep_type_ids_stable() {
+        #[derive(Reflect)]
+        struct Late;
+
+        let mut registry = create_compact_registry();
+        let mut manifest = TypeIdManifest::from_registry(&registry);
+        let ids = manifest
+            .iter()
+            .map(|(id, type_path)| (id, type_path.to_owned()))
+            .collect::<Vec<_>>();
+
+        registry.register::<Late>();
+        manifest.extend_from_registry(&registry);
+        assert_eq!(ids.len() + 1, manifest.len());
+        for (id, type_path) in &ids {
+            assert_eq!(Some(*id), manifest.id(type_path));
+        }
+        assert_eq!(
+            Some(ids.len() as u32),
+            manifest.id(<Late as crate::TypePath>::type_path())
+        );
+
+        let serialized = ron::to_string(&manifest).unwrap();
+        let deserialized = ron::from_str::<TypeIdManifest>(&serialized).unwrap();
+        assert_eq!(manifest, deserialized);
+    }
+
+    #[test]
+    fn should_not_serialize_compact_without_type_id() {
+        let registry = create_compact_registry();
+        let manifest = TypeIdManifest::default();
+        let serializer = CompactReflectSerializer::new(&CompactEnum::Unit, &registry, &manifest);
+        let error =
+            bincode::serde::encode_to_vec(&serializer, bincode::config::standard()).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("has no id in the type id manifest"));
+    }
+
     #[test]
     #[should_panic(
         expected = "cannot serialize dynamic value without represented type: `bevy_reflect::DynamicStruct`"
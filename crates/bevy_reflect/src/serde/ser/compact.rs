@@ -0,0 +1,289 @@
+#[cfg(feature = "debug_stack")]
+use crate::serde::ser::error_utils::TYPE_INFO_STACK;
+use crate::{
+    serde::{
+        ser::{custom_serialization::try_custom_serialize, error_utils::make_custom_error},
+        SerializationData, TypeIdManifest,
+    },
+    Enum, PartialReflect, ReflectRef, Struct, TypeInfo, TypeRegistry,
+};
+use serde::{
+    ser::{SerializeMap, SerializeSeq, SerializeTuple},
+    Serialize, Serializer,
+};
+
+/// A compact serializer for reflected types, meant for binary formats such as [`bincode`] or [`postcard`].
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// See [`TypedCompactReflectSerializer`] for a serializer that serializes a known type.
+///
+/// # Output
+///
+/// This serializer will output a tuple of the numeric id of the reflected type in the given [`TypeIdManifest`],
+/// followed by the value serialized with [`TypedCompactReflectSerializer`].
+///
+/// [`bincode`]: https://docs.rs/bincode
+/// [`postcard`]: https://docs.rs/postcard
+/// [`CompactReflectDeserializer`]: crate::serde::CompactReflectDeserializer
+pub struct CompactReflectSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+    manifest: &'a TypeIdManifest,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    /// Creates a compact serializer identifying the type of `value` with its id in `manifest`.
+    pub fn new(
+        value: &'a dyn PartialReflect,
+        registry: &'a TypeRegistry,
+        manifest: &'a TypeIdManifest,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            manifest,
+        }
+    }
+}
+
+impl Serialize for CompactReflectSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_path = self
+            .value
+            .get_represented_type_info()
+            .ok_or_else(|| {
+                make_custom_error(format_args!(
+                    "cannot get type info for `{}`",
+                    self.value.reflect_type_path()
+                ))
+            })?
+            .type_path();
+        let id = self.manifest.id(type_path).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "type `{type_path}` has no id in the type id manifest"
+            ))
+        })?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&id)?;
+        state.serialize_element(&TypedCompactReflectSerializer::new(
+            self.value,
+            self.registry,
+        ))?;
+        state.end()
+    }
+}
+
+/// A compact serializer for reflected types whose type will be known during deserialization.
+///
+/// This is the serializer counterpart to [`TypedCompactReflectDeserializer`].
+///
+/// # Output
+///
+/// Values registering [`ReflectSerialize`] or [`ReflectSerializeWithRegistry`] type data are serialized with it.
+/// Otherwise, no field or variant names are written:
+/// - structs, tuple structs, tuples and arrays are written as tuples of their serialized fields, in the index order of
+///   their type info (serializing a dynamic value missing some of the fields of its type is an error),
+/// - enums are written as a tuple of their variant index and of the tuple of their variant fields,
+/// - lists and sets are written as sequences and maps as maps.
+///
+/// [`TypedCompactReflectDeserializer`]: crate::serde::TypedCompactReflectDeserializer
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+/// [`ReflectSerializeWithRegistry`]: crate::serde::ReflectSerializeWithRegistry
+pub struct TypedCompactReflectSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedCompactReflectSerializer<'a> {
+    /// Creates a compact serializer for `value`.
+    pub fn new(value: &'a dyn PartialReflect, registry: &'a TypeRegistry) -> Self {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.set(crate::type_info_stack::TypeInfoStack::new());
+
+        Self::new_internal(value, registry)
+    }
+
+    fn new_internal(value: &'a dyn PartialReflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+
+    fn serialization_data(&self) -> Option<&'a SerializationData> {
+        self.value
+            .get_represented_type_info()
+            .and_then(|info| self.registry.get(info.type_id()))
+            .and_then(|registration| registration.data::<SerializationData>())
+    }
+
+    /// Serializes the tuple of the non-skipped fields among the `len` fields returned by `field`, in index order.
+    fn serialize_fields<'v, S: Serializer>(
+        &self,
+        serializer: S,
+        len: usize,
+        field: impl Fn(usize) -> Result<&'v dyn PartialReflect, S::Error>,
+    ) -> Result<S::Ok, S::Error> {
+        let serialization_data = self.serialization_data();
+        let is_skipped =
+            |index: usize| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+        let mut state =
+            serializer.serialize_tuple((0..len).filter(|index| !is_skipped(*index)).count())?;
+        for index in (0..len).filter(|index| !is_skipped(*index)) {
+            state.serialize_element(&TypedCompactReflectSerializer::new_internal(
+                field(index)?,
+                self.registry,
+            ))?;
+        }
+        state.end()
+    }
+
+    /// Serializes the fields of a struct in the order of its [`StructInfo`], looking them up by name,
+    /// since the fields of a dynamic struct may be in any order.
+    fn serialize_struct<S: Serializer>(
+        &self,
+        serializer: S,
+        struct_value: &dyn Struct,
+    ) -> Result<S::Ok, S::Error> {
+        let Some(TypeInfo::Struct(struct_info)) = struct_value.get_represented_type_info() else {
+            return self.serialize_fields(serializer, struct_value.field_len(), |index| {
+                Ok(struct_value.field_at(index).unwrap())
+            });
+        };
+        self.serialize_fields(serializer, struct_info.field_len(), |index| {
+            let name = struct_info.field_at(index).unwrap().name();
+            struct_value.field(name).ok_or_else(|| {
+                make_custom_error(format_args!(
+                    "missing field `{name}` of struct `{}`",
+                    struct_info.type_path()
+                ))
+            })
+        })
+    }
+
+    /// Serializes the fields of a tuple struct or tuple, which must have all the fields of their type.
+    fn serialize_tuple_fields<'v, S: Serializer>(
+        &self,
+        serializer: S,
+        field_len: usize,
+        field: impl Fn(usize) -> Option<&'v dyn PartialReflect>,
+    ) -> Result<S::Ok, S::Error> {
+        let (len, type_path) = match self.value.get_represented_type_info() {
+            Some(TypeInfo::TupleStruct(info)) => (info.field_len(), info.type_path()),
+            Some(TypeInfo::Tuple(info)) => (info.field_len(), info.type_path()),
+            _ => (field_len, self.value.reflect_type_path()),
+        };
+        self.serialize_fields(serializer, len, |index| {
+            field(index).ok_or_else(|| {
+                make_custom_error(format_args!("missing field {index} of `{type_path}`"))
+            })
+        })
+    }
+}
+
+impl Serialize for TypedCompactReflectSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[cfg(feature = "debug_stack")]
+        {
+            if let Some(info) = self.value.get_represented_type_info() {
+                TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(info));
+            }
+        }
+
+        // Handle both Value case and types that have a custom `Serialize`
+        let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
+        {
+            Ok(result) => return result,
+            Err(value) => value,
+        };
+
+        let output = match self.value.reflect_ref() {
+            ReflectRef::Struct(struct_value) => self.serialize_struct(serializer, struct_value),
+            ReflectRef::TupleStruct(tuple_struct) => {
+                self.serialize_tuple_fields(serializer, tuple_struct.field_len(), |index| {
+                    tuple_struct.field(index)
+                })
+            }
+            ReflectRef::Tuple(tuple) => {
+                self.serialize_tuple_fields(serializer, tuple.field_len(), |index| {
+                    tuple.field(index)
+                })
+            }
+            ReflectRef::Array(array) => {
+                let mut state = serializer.serialize_tuple(array.len())?;
+                for value in array.iter() {
+                    state.serialize_element(&Self::new_internal(value, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::List(list) => {
+                let mut state = serializer.serialize_seq(Some(list.len()))?;
+                for value in list.iter() {
+                    state.serialize_element(&Self::new_internal(value, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::Set(set) => {
+                let mut state = serializer.serialize_seq(Some(set.len()))?;
+                for value in set.iter() {
+                    state.serialize_element(&Self::new_internal(value, self.registry))?;
+                }
+                state.end()
+            }
+            ReflectRef::Map(map) => {
+                let mut state = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map.iter() {
+                    state.serialize_entry(
+                        &Self::new_internal(key, self.registry),
+                        &Self::new_internal(value, self.registry),
+                    )?;
+                }
+                state.end()
+            }
+            ReflectRef::Enum(enum_value) => {
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&(enum_value.variant_index() as u32))?;
+                state.serialize_element(&CompactVariantSerializer {
+                    enum_value,
+                    registry: self.registry,
+                })?;
+                state.end()
+            }
+            #[cfg(feature = "functions")]
+            ReflectRef::Function(_) => Err(make_custom_error("functions cannot be serialized")),
+            ReflectRef::Opaque(_) => Err(error),
+        };
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        output
+    }
+}
+
+/// Serializes the fields of the current variant of an enum as a tuple.
+struct CompactVariantSerializer<'a> {
+    enum_value: &'a dyn Enum,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for CompactVariantSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(self.enum_value.field_len())?;
+        for field in self.enum_value.iter_fields() {
+            state.serialize_element(&TypedCompactReflectSerializer::new_internal(
+                field.value(),
+                self.registry,
+            ))?;
+        }
+        state.end()
+    }
+}
@@ -1,9 +1,11 @@
+pub use compact::*;
 pub use processor::*;
 pub use serializable::*;
 pub use serialize_with_registry::*;
 pub use serializer::*;
 
 mod arrays;
+mod compact;
 mod custom_serialization;
 mod enums;
 mod error_utils;
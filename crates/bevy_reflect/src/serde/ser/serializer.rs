@@ -7,9 +7,13 @@ use crate::{
         sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
         tuples::TupleSerializer,
     },
+    serde::type_version,
     PartialReflect, ReflectRef, TypeRegistry,
 };
-use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde::{
+    ser::{SerializeMap, SerializeTuple},
+    Serialize, Serializer,
+};
 
 use super::ReflectSerializerProcessor;
 
@@ -271,10 +275,13 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
                 processor: self.processor,
             }
             .serialize(serializer),
-            ReflectRef::TupleStruct(tuple_struct) => TupleStructSerializer {
-                tuple_struct,
-                registry: self.registry,
-                processor: self.processor,
+            ReflectRef::TupleStruct(tuple_struct) => VersionedSerializer {
+                version: type_version(tuple_struct.get_represented_type_info()),
+                value: TupleStructSerializer {
+                    tuple_struct,
+                    registry: self.registry,
+                    processor: self.processor,
+                },
             }
             .serialize(serializer),
             ReflectRef::Tuple(tuple) => TupleSerializer {
@@ -307,10 +314,13 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
                 processor: self.processor,
             }
             .serialize(serializer),
-            ReflectRef::Enum(enum_value) => EnumSerializer {
-                enum_value,
-                registry: self.registry,
-                processor: self.processor,
+            ReflectRef::Enum(enum_value) => VersionedSerializer {
+                version: type_version(enum_value.get_represented_type_info()),
+                value: EnumSerializer {
+                    enum_value,
+                    registry: self.registry,
+                    processor: self.processor,
+                },
             }
             .serialize(serializer),
             #[cfg(feature = "functions")]
@@ -324,3 +334,25 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
         output
     }
 }
+
+/// Serializes the value of a [versioned](crate::serde::TypeVersion) tuple struct or enum as a
+/// `(version, value)` tuple, or serializes the value alone if it is not versioned.
+struct VersionedSerializer<T> {
+    version: Option<u32>,
+    value: T,
+}
+
+impl<T: Serialize> Serialize for VersionedSerializer<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(version) = self.version else {
+            return self.value.serialize(serializer);
+        };
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&version)?;
+        state.serialize_element(&self.value)?;
+        state.end()
+    }
+}
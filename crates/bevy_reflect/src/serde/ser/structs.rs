@@ -1,5 +1,8 @@
 use crate::{
-    serde::{ser::error_utils::make_custom_error, SerializationData, TypedReflectSerializer},
+    serde::{
+        ser::error_utils::make_custom_error, SerializationData, TypeVersion,
+        TypedReflectSerializer, VERSION_FIELD,
+    },
     Struct, TypeInfo, TypeRegistry,
 };
 use serde::{ser::SerializeStruct, Serialize};
@@ -42,11 +45,17 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
         let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+        // The version is written in every format, so that data is never mistaken for an older version.
+        let version = struct_info.get_attribute::<TypeVersion>();
         let mut state = serializer.serialize_struct(
             struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
+            self.struct_value.field_len() - ignored_len + usize::from(version.is_some()),
         )?;
 
+        if let Some(version) = version {
+            state.serialize_field(VERSION_FIELD, &version.0)?;
+        }
+
         for (index, value) in self.struct_value.iter_fields().enumerate() {
             if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
                 continue;
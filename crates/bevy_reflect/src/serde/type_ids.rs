@@ -0,0 +1,130 @@
+use crate::TypeRegistry;
+use alloc::{string::String, vec::Vec};
+use bevy_platform::collections::HashMap;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A stable assignment of numeric ids to [type paths], used by the [compact serializers] to identify types.
+///
+/// Ids are assigned in insertion order and never change once assigned,
+/// so a manifest can be saved alongside snapshots, or shared between peers ahead of network packets,
+/// and extended as new types get registered without invalidating previously written data.
+///
+/// The manifest itself is serialized as the list of its type paths, ordered by id.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, serde::TypeIdManifest};
+/// #[derive(Reflect)]
+/// #[type_path = "my_crate"]
+/// struct Player;
+///
+/// let mut registry = TypeRegistry::empty();
+/// registry.register::<Player>();
+///
+/// let mut manifest = TypeIdManifest::default();
+/// manifest.extend_from_registry(&registry);
+/// assert_eq!(manifest.id("my_crate::Player"), Some(0));
+/// assert_eq!(manifest.type_path(0), Some("my_crate::Player"));
+/// ```
+///
+/// [type paths]: crate::TypePath::type_path
+/// [compact serializers]: crate::serde::CompactReflectSerializer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeIdManifest {
+    type_paths: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl TypeIdManifest {
+    /// Creates a manifest assigning ids to all the types of `registry`, in [type path] order.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut manifest = Self::default();
+        manifest.extend_from_registry(registry);
+        manifest
+    }
+
+    /// Assigns ids to the types of `registry` which don't have one yet, in [type path] order.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn extend_from_registry(&mut self, registry: &TypeRegistry) {
+        let mut type_paths = registry
+            .iter()
+            .map(|registration| registration.type_info().type_path())
+            .filter(|type_path| !self.ids.contains_key(*type_path))
+            .collect::<Vec<_>>();
+        type_paths.sort_unstable();
+        for type_path in type_paths {
+            self.insert(type_path);
+        }
+    }
+
+    /// Returns the id of `type_path`, assigning it the next available id if it doesn't have one yet.
+    pub fn insert(&mut self, type_path: impl Into<String>) -> u32 {
+        let type_path = type_path.into();
+        if let Some(id) = self.ids.get(&type_path) {
+            return *id;
+        }
+        let id = self.type_paths.len() as u32;
+        self.type_paths.push(type_path.clone());
+        self.ids.insert(type_path, id);
+        id
+    }
+
+    /// Returns the id assigned to `type_path`, if any.
+    pub fn id(&self, type_path: &str) -> Option<u32> {
+        self.ids.get(type_path).copied()
+    }
+
+    /// Returns the type path with the given `id`, if any.
+    pub fn type_path(&self, id: u32) -> Option<&str> {
+        self.type_paths.get(id as usize).map(String::as_str)
+    }
+
+    /// Returns an iterator over the ids and type paths of this manifest, in id order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u32, &str)> {
+        self.type_paths
+            .iter()
+            .enumerate()
+            .map(|(id, type_path)| (id as u32, type_path.as_str()))
+    }
+
+    /// Returns the number of types in this manifest.
+    pub fn len(&self) -> usize {
+        self.type_paths.len()
+    }
+
+    /// Returns `true` if no type has an id in this manifest.
+    pub fn is_empty(&self) -> bool {
+        self.type_paths.is_empty()
+    }
+}
+
+impl Serialize for TypeIdManifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.type_paths.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeIdManifest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut manifest = Self::default();
+        for type_path in Vec::<String>::deserialize(deserializer)? {
+            if manifest.ids.contains_key(&type_path) {
+                return Err(Error::custom(format_args!(
+                    "duplicate type path `{type_path}` in type id manifest"
+                )));
+            }
+            manifest.insert(type_path);
+        }
+        Ok(manifest)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::{Migration, ReflectMigrations, Serializable},
+    FromReflect, Reflect, TypeInfo, TypePath, Typed,
+};
 use alloc::{boxed::Box, string::String};
 use bevy_platform::{
     collections::{HashMap, HashSet},
@@ -352,6 +355,26 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers the type `T` if it has not been registered already, along with a [`Migration`] upgrading its
+    /// deserialized data from `from_version` to the next version.
+    ///
+    /// See [`TypeVersion`](crate::serde::TypeVersion) for more information about versioned types.
+    pub fn register_migration<T: GetTypeRegistration>(
+        &mut self,
+        from_version: u32,
+        migration: Migration,
+    ) {
+        self.register::<T>();
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap();
+        if let Some(migrations) = registration.data_mut::<ReflectMigrations>() {
+            migrations.insert(from_version, migration);
+        } else {
+            let mut migrations = ReflectMigrations::default();
+            migrations.insert(from_version, migration);
+            registration.insert(migrations);
+        }
+    }
+
     /// Whether the type with given [`TypeId`] has been registered in this registry.
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
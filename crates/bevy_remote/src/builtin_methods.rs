@@ -16,9 +16,11 @@ use bevy_ecs::{
 };
 use bevy_log::warn_once;
 use bevy_platform::collections::HashMap;
+#[cfg(feature = "reflect_functions")]
+use bevy_reflect::func::args::Ownership;
 use bevy_reflect::{
     serde::{ReflectSerializer, TypedReflectDeserializer},
-    GetPath, PartialReflect, TypeRegistration, TypeRegistry,
+    GetPath, PartialReflect, PathQuery, TypeRegistration, TypeRegistry,
 };
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -86,6 +88,10 @@ pub const BRP_LIST_RESOURCES_METHOD: &str = "world.list_resources";
 /// The method path for a `registry.schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "registry.schema";
 
+/// The method path for a `registry.call_function` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_FUNCTION_METHOD: &str = "registry.call_function";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
@@ -273,7 +279,11 @@ pub struct BrpMutateComponentsParams {
 
     /// The [path] of the field within the component.
     ///
+    /// This may also be a [query] with wildcards or filters, in which case `value`
+    /// is inserted into every matching field.
+    ///
     /// [path]: bevy_reflect::GetPath
+    /// [query]: bevy_reflect::PathQuery
     pub path: String,
 
     /// The value to insert at `path`.
@@ -292,13 +302,39 @@ pub struct BrpMutateResourcesParams {
 
     /// The [path] of the field within the resource.
     ///
+    /// This may also be a [query] with wildcards or filters, in which case `value`
+    /// is inserted into every matching field.
+    ///
     /// [path]: bevy_reflect::GetPath
+    /// [query]: bevy_reflect::PathQuery
     pub path: String,
 
     /// The value to insert at `path`.
     pub value: Value,
 }
 
+/// `registry.call_function`: Calls a function registered in the [`AppFunctionRegistry`].
+///
+/// The server responds with the serialized return value of the function, or null if it returns `()`.
+///
+/// [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpCallFunctionParams {
+    /// The name the function was registered with.
+    pub function: String,
+
+    /// The serialized arguments of the function, in order.
+    ///
+    /// Arguments taking a reference to a reflected [`Resource`] are filled from the world,
+    /// and must be left out of this list. Changes made through mutable references
+    /// are applied back to the resource.
+    ///
+    /// [`Resource`]: bevy_ecs::resource::Resource
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -1078,6 +1114,19 @@ pub fn process_remote_mutate_components_request(
             BrpError::component_error(anyhow!("Cannot reflect component `{}`", component))
         })?;
 
+    // Queries with wildcards or filters mutate every matching field.
+    if let Some(query) = parse_path_query(&path) {
+        mutate_path_query(
+            reflected.as_partial_reflect_mut(),
+            &path,
+            &query,
+            &value,
+            &type_registry,
+        )
+        .map_err(BrpError::component_error)?;
+        return Ok(Value::Null);
+    }
+
     // Get the type of the field in the component that is to be
     // mutated.
     let value_type: &TypeRegistration = type_registry
@@ -1130,6 +1179,19 @@ pub fn process_remote_mutate_resources_request(
         .reflect_mut(world)
         .map_err(|_| BrpError::resource_not_present(&resource_path))?;
 
+    // Queries with wildcards or filters mutate every matching field.
+    if let Some(query) = parse_path_query(&field_path) {
+        mutate_path_query(
+            reflected_resource.as_partial_reflect_mut(),
+            &field_path,
+            &query,
+            &value,
+            &type_registry,
+        )
+        .map_err(BrpError::resource_error)?;
+        return Ok(Value::Null);
+    }
+
     // Get the type registration for the field with the given path.
     let value_registration = type_registry
         .get_with_type_path(
@@ -1158,6 +1220,214 @@ pub fn process_remote_mutate_resources_request(
     Ok(Value::Null)
 }
 
+/// Parses `path` as a [`PathQuery`] if it can match more than one field.
+///
+/// Plain paths return `None`, so that they keep being resolved with [`GetPath`] and its error messages.
+fn parse_path_query(path: &str) -> Option<PathQuery> {
+    PathQuery::parse(path)
+        .ok()
+        .filter(|query| !query.is_concrete())
+}
+
+/// Inserts `value` into every field of `reflected` matched by `path`, parsed as `query`.
+///
+/// The value is deserialized once for each distinct type of matched field. Mutations are atomic: the value is first
+/// applied to a clone of every matched field, and the fields are only written once all of them succeeded.
+/// It is an error for the query to match no field.
+fn mutate_path_query(
+    reflected: &mut dyn PartialReflect,
+    path: &str,
+    query: &PathQuery,
+    value: &Value,
+    type_registry: &TypeRegistry,
+) -> AnyhowResult<()> {
+    let mut values: HashMap<String, Box<dyn PartialReflect>> = HashMap::default();
+    let mut mutated_fields = Vec::new();
+    query.try_for_each_element_mut(reflected, |field| {
+        let type_path = field.reflect_type_path().to_owned();
+        if !values.contains_key(&type_path) {
+            let registration = type_registry
+                .get_with_type_path(&type_path)
+                .ok_or_else(|| anyhow!("Unknown field type: `{}`", type_path))?;
+            let value =
+                TypedReflectDeserializer::new(registration, type_registry).deserialize(value)?;
+            values.insert(type_path.clone(), value);
+        }
+        let mut mutated_field = field
+            .reflect_clone()
+            .map(PartialReflect::into_partial_reflect)
+            .unwrap_or_else(|_| field.to_dynamic());
+        mutated_field.try_apply(values[&type_path].as_ref())?;
+        mutated_fields.push(mutated_field);
+        Ok::<_, anyhow::Error>(())
+    })?;
+    if mutated_fields.is_empty() {
+        return Err(anyhow!("Path `{}` doesn't match any field", path));
+    }
+
+    let mut mutated_fields = mutated_fields.into_iter();
+    query.try_for_each_element_mut(reflected, |field| {
+        let mutated_field = mutated_fields
+            .next()
+            .ok_or_else(|| anyhow!("Path `{}` matched more fields than expected", path))?;
+        field.try_apply(mutated_field.as_ref())?;
+        Ok::<_, anyhow::Error>(())
+    })?;
+    Ok(())
+}
+
+/// Handles a `registry.call_function` request coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_function_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    use bevy_ecs::reflect::AppFunctionRegistry;
+    use bevy_reflect::{
+        func::{args::ArgList, Return},
+        serde::TypedReflectSerializer,
+    };
+
+    let BrpCallFunctionParams { function, args } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+
+    // Clone the function out of the registry so that it may itself access the registry.
+    let dynamic_function = world
+        .get_resource::<AppFunctionRegistry>()
+        .and_then(|registry| registry.read().get(&function).cloned())
+        .ok_or_else(|| BrpError::function_not_found(&function))?;
+
+    // The type registry is not locked during the call either, for the same reason.
+    let mut call_args = resolve_function_args(
+        dynamic_function.info().signatures(),
+        &args,
+        world,
+        &app_type_registry.read(),
+    )
+    .map_err(BrpError::function_error)?;
+
+    let mut arg_list = ArgList::new();
+    for call_arg in &mut call_args {
+        match call_arg.ownership {
+            Ownership::Owned => arg_list.push_boxed(call_arg.value.take().unwrap()),
+            Ownership::Ref => arg_list.push_ref(call_arg.value.as_deref().unwrap()),
+            Ownership::Mut => arg_list.push_mut(call_arg.value.as_deref_mut().unwrap()),
+        }
+    }
+
+    let result = dynamic_function.call(arg_list);
+    let type_registry = app_type_registry.read();
+    let value = match result {
+        Ok(result) if result.is_unit() => Ok(Value::Null),
+        Ok(Return::Owned(value)) => {
+            serde_json::to_value(TypedReflectSerializer::new(value.as_ref(), &type_registry))
+                .map_err(BrpError::function_error)
+        }
+        Ok(Return::Ref(value)) => {
+            serde_json::to_value(TypedReflectSerializer::new(value, &type_registry))
+                .map_err(BrpError::function_error)
+        }
+        Ok(Return::Mut(value)) => {
+            serde_json::to_value(TypedReflectSerializer::new(&*value, &type_registry))
+                .map_err(BrpError::function_error)
+        }
+        Err(err) => Err(BrpError::function_error(err)),
+    };
+
+    // Write the resources borrowed mutably back to the world.
+    for call_arg in call_args {
+        if let (Ownership::Mut, Some(resource), Some(value)) =
+            (call_arg.ownership, call_arg.resource, call_arg.value)
+        {
+            resource.apply(world, value.as_ref());
+        }
+    }
+
+    value
+}
+
+/// An argument of a function called by [`process_remote_call_function_request`].
+#[cfg(feature = "reflect_functions")]
+struct FunctionArg {
+    ownership: Ownership,
+    value: Option<Box<dyn PartialReflect>>,
+    /// The resource this argument was cloned from, if it was borrowed from the world.
+    resource: Option<ReflectResource>,
+}
+
+/// Deserializes `args` for the first of `signatures` accepting them.
+///
+/// References to reflected resources are cloned from `world` instead.
+#[cfg(feature = "reflect_functions")]
+fn resolve_function_args(
+    signatures: &[bevy_reflect::func::SignatureInfo],
+    args: &[Value],
+    world: &World,
+    type_registry: &TypeRegistry,
+) -> AnyhowResult<Vec<FunctionArg>> {
+    let mut error = None;
+    'signatures: for signature in signatures {
+        let mut call_args = Vec::with_capacity(signature.arg_count());
+        let mut values = args.iter();
+        for arg in signature.args() {
+            let ownership = arg.ownership();
+            // References are reflected with the type path of their referent, behind `&` or `&mut `.
+            let type_path = match ownership {
+                Ownership::Ref => arg.type_path().strip_prefix('&'),
+                Ownership::Mut => arg.type_path().strip_prefix("&mut "),
+                Ownership::Owned => Some(arg.type_path()),
+            }
+            .unwrap_or(arg.type_path());
+            let Some(registration) = type_registry.get_with_type_path(type_path) else {
+                error = Some(anyhow!("Unknown argument type: `{}`", type_path));
+                continue 'signatures;
+            };
+
+            let resource = match ownership {
+                Ownership::Owned => None,
+                _ => registration.data::<ReflectResource>(),
+            };
+            if let Some(resource) = resource {
+                let value = resource
+                    .reflect(world)
+                    .map_err(|_| anyhow!("Resource `{}` not present in the world", type_path))?
+                    .reflect_clone()?
+                    .into_partial_reflect();
+                call_args.push(FunctionArg {
+                    ownership,
+                    value: Some(value),
+                    resource: Some(resource.clone()),
+                });
+                continue;
+            }
+
+            let Some(value) = values.next() else {
+                error = Some(anyhow!("Received too few arguments"));
+                continue 'signatures;
+            };
+            match TypedReflectDeserializer::new(registration, type_registry).deserialize(value) {
+                Ok(value) => call_args.push(FunctionArg {
+                    ownership,
+                    value: Some(value),
+                    resource: None,
+                }),
+                Err(err) => {
+                    error = Some(err.into());
+                    continue 'signatures;
+                }
+            }
+        }
+
+        if values.next().is_some() {
+            error = Some(anyhow!("Received too many arguments"));
+            continue;
+        }
+        return Ok(call_args);
+    }
+    Err(error.unwrap_or_else(|| anyhow!("Function has no signature")))
+}
+
 /// Handles a `world.remove_components` request (remove components) coming from a client.
 pub fn process_remote_remove_components_request(
     In(params): In<Option<Value>>,
@@ -1657,4 +1927,131 @@ mod tests {
             entity: Entity::from_raw_u32(0).unwrap(),
         });
     }
+
+    #[test]
+    fn mutate_resources_with_path_query() {
+        use bevy_ecs::{reflect::ReflectResource, resource::Resource};
+        use bevy_reflect::{Reflect, TypePath};
+        use serde_json::json;
+
+        #[derive(Resource, Reflect)]
+        #[reflect(Resource)]
+        struct Inventory {
+            items: Vec<Item>,
+        }
+
+        #[derive(Reflect)]
+        struct Item {
+            name: String,
+            count: u32,
+        }
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Inventory>();
+        world.insert_resource(registry);
+        world.insert_resource(Inventory {
+            items: vec![
+                Item {
+                    name: "sword".to_owned(),
+                    count: 1,
+                },
+                Item {
+                    name: "arrow".to_owned(),
+                    count: 20,
+                },
+            ],
+        });
+
+        let params = json!({
+            "resource": Inventory::type_path(),
+            "path": ".items[?.name == \"arrow\"].count",
+            "value": 99,
+        });
+        process_remote_mutate_resources_request(In(Some(params)), &mut world).unwrap();
+
+        let inventory = world.resource::<Inventory>();
+        assert_eq!(inventory.items[0].count, 1);
+        assert_eq!(inventory.items[1].count, 99);
+
+        // A query matching nothing is an error.
+        let params = json!({
+            "resource": Inventory::type_path(),
+            "path": ".items[?.name == \"shield\"].count",
+            "value": 1,
+        });
+        let error =
+            process_remote_mutate_resources_request(In(Some(params)), &mut world).unwrap_err();
+        assert!(error.message.contains("doesn't match any field"));
+
+        // The value can be applied to the names but not to the counts, so nothing is mutated.
+        let params = json!({
+            "resource": Inventory::type_path(),
+            "path": ".items[*].*",
+            "value": "shield",
+        });
+        process_remote_mutate_resources_request(In(Some(params)), &mut world).unwrap_err();
+        let inventory = world.resource::<Inventory>();
+        assert_eq!(inventory.items[0].name, "sword");
+        assert_eq!(inventory.items[1].name, "arrow");
+        assert_eq!(inventory.items[1].count, 99);
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn call_function() {
+        use bevy_ecs::{
+            reflect::{AppFunctionRegistry, ReflectResource},
+            resource::Resource,
+        };
+        use bevy_reflect::Reflect;
+        use serde_json::json;
+
+        #[derive(Resource, Reflect)]
+        #[reflect(Resource)]
+        struct Wallet(u32);
+
+        fn deposit(wallet: &mut Wallet, amount: u32) -> u32 {
+            wallet.0 += amount;
+            wallet.0
+        }
+
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Wallet>();
+        world.insert_resource(type_registry);
+        let function_registry = AppFunctionRegistry::default();
+        function_registry
+            .write()
+            .register_with_name("deposit", deposit)
+            .unwrap();
+        world.insert_resource(function_registry);
+        world.insert_resource(Wallet(5));
+
+        let params = json!({ "function": "deposit", "args": [10] });
+        let result = process_remote_call_function_request(In(Some(params)), &mut world).unwrap();
+        assert_eq!(result, json!(15));
+        assert_eq!(world.resource::<Wallet>().0, 15);
+
+        let params = json!({ "function": "deposit", "args": ["ten"] });
+        let error = process_remote_call_function_request(In(Some(params)), &mut world).unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+
+        let params = json!({ "function": "withdraw" });
+        let error = process_remote_call_function_request(In(Some(params)), &mut world).unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_NOT_FOUND);
+
+        // The type registry is not locked while the function runs.
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        world
+            .resource::<AppFunctionRegistry>()
+            .write()
+            .register_with_name("can_register", move || {
+                type_registry.internal.try_write().is_ok()
+            })
+            .unwrap();
+        let params = json!({ "function": "can_register" });
+        let result = process_remote_call_function_request(In(Some(params)), &mut world).unwrap();
+        assert_eq!(result, json!(true));
+    }
 }
@@ -337,6 +337,7 @@
 //! - `component`: The component's [fully-qualified type name].
 //! - `path`: The path of the field within the component. See
 //!   [`GetPath`](bevy_reflect::GetPath#syntax) for more information on formatting this string.
+//!   Paths with wildcards or filters mutate every matching field, see [`PathQuery`](bevy_reflect::PathQuery).
 //! - `value`: The value to insert at `path`.
 //!
 //! `result`: null.
@@ -445,6 +446,7 @@
 //! - `resource`: The [fully-qualified type name] of the resource to mutate.
 //! - `path`: The path of the field within the resource. See
 //!   [`GetPath`](bevy_reflect::GetPath#syntax) for more information on formatting this string.
+//!   Paths with wildcards or filters mutate every matching field, see [`PathQuery`](bevy_reflect::PathQuery).
 //! - `value`: The value to be inserted at `path`.
 //!
 //! `result`: null.
@@ -455,6 +457,21 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `registry.call_function`
+//!
+//! Call a function registered in the [`AppFunctionRegistry`].
+//! This method is only available with the `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name the function was registered with.
+//! - `args` (optional): The serialized arguments of the function, in order.
+//!   Arguments taking a reference to a reflected resource are filled from the world instead,
+//!   and must be left out.
+//!
+//! `result`: The serialized return value of the function, or null if it returns `()`.
+//!
+//! [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -574,7 +591,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_COMPONENTS_METHOD,
                 builtin_methods::process_remote_get_components_request,
@@ -646,7 +663,15 @@ impl Default for RemotePlugin {
             .with_method(
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
-            )
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_CALL_FUNCTION_METHOD,
+            builtin_methods::process_remote_call_function_request,
+        );
+
+        plugin
     }
 }
 
@@ -955,6 +980,26 @@ impl BrpError {
         }
     }
 
+    /// Function wasn't found in the function registry.
+    #[must_use]
+    pub fn function_not_found(function: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_FOUND,
+            message: format!("Function `{function}` not found"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary function error, such as arguments failing to deserialize.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
     /// An arbitrary internal error.
     #[must_use]
     pub fn internal<E: ToString>(error: E) -> Self {
@@ -1015,6 +1060,12 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find function in the function registry.
+    pub const FUNCTION_NOT_FOUND: i16 = -23601;
+
+    /// Could not call function.
+    pub const FUNCTION_ERROR: i16 = -23602;
 }
 
 /// The result of a request.
@@ -3,6 +3,7 @@ use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{component::Component, prelude::ReflectComponent};
 use bevy_reflect::{prelude::ReflectDefault, Reflect};
 use bevy_transform::components::Transform;
+use core::num::NonZeroUsize;
 use derive_more::derive::From;
 
 use bevy_camera::visibility::Visibility;
@@ -24,3 +25,15 @@ pub struct SceneRoot(pub Handle<Scene>);
 #[require(Transform)]
 #[require(Visibility)]
 pub struct DynamicSceneRoot(pub Handle<DynamicScene>);
+
+/// Adding this component alongside a [`DynamicSceneRoot`] spawns the scene over several frames, spawning at most
+/// [`entities_per_frame`](Self::entities_per_frame) entities each frame.
+///
+/// [`SceneInstanceReady`](crate::SceneInstanceReady) is triggered once the last batch has been spawned.
+/// See [`SceneSpawner::spawn_dynamic_incremental`](crate::SceneSpawner::spawn_dynamic_incremental).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+pub struct SceneSpawnBudget {
+    /// The maximum number of scene entities spawned each frame.
+    pub entities_per_frame: NonZeroUsize,
+}
@@ -6,7 +6,8 @@ use bevy_ecs::{
     reflect::{AppTypeRegistry, ReflectComponent},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath};
+use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use core::ops::Range;
 
 use crate::reflect_utils::clone_reflect_value;
 use bevy_ecs::component::ComponentCloneBehavior;
@@ -15,7 +16,6 @@ use bevy_ecs::relationship::RelationshipHookMode;
 #[cfg(feature = "serialize")]
 use {
     crate::{ron, serde::SceneSerializer},
-    bevy_reflect::TypeRegistry,
     serde::Serialize,
 };
 
@@ -79,9 +79,17 @@ impl DynamicScene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        self.reserve_entities(world, entity_map);
+        self.write_entities_to_world(world, entity_map, &type_registry, 0..self.entities.len())?;
+        self.write_resources_to_world(world, entity_map, &type_registry)
+    }
 
-        // First ensure that every entity in the scene has a corresponding world
-        // entity in the entity map.
+    /// Ensures that every entity in the scene has a corresponding world entity in the entity map.
+    pub(crate) fn reserve_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) {
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -90,100 +98,49 @@ impl DynamicScene {
                 .entry(scene_entity.entity)
                 .or_insert_with(|| world.spawn_empty().id());
         }
+    }
 
-        for scene_entity in &self.entities {
+    /// Writes the components of the scene entities in `range` to the given world.
+    ///
+    /// Every entity of the scene must have been reserved with [`Self::reserve_entities`].
+    pub(crate) fn write_entities_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        range: Range<usize>,
+    ) -> Result<(), SceneSpawnError> {
+        for scene_entity in &self.entities[range] {
             // Fetch the entity with the given entity id from the `entity_map`.
             let entity = *entity_map
                 .get(&scene_entity.entity)
                 .expect("should have previously spawned an empty entity");
 
-            // Apply/ add each component to the given entity.
-            for component in &scene_entity.components {
-                let type_info = component.get_represented_type_info().ok_or_else(|| {
-                    SceneSpawnError::NoRepresentedType {
-                        type_path: component.reflect_type_path().to_string(),
-                    }
-                })?;
-                let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                    SceneSpawnError::UnregisteredButReflectedType {
-                        type_path: type_info.type_path().to_string(),
-                    }
-                })?;
-                let reflect_component =
-                    registration.data::<ReflectComponent>().ok_or_else(|| {
-                        SceneSpawnError::UnregisteredComponent {
-                            type_path: type_info.type_path().to_string(),
-                        }
-                    })?;
-
-                {
-                    let component_id = reflect_component.register_component(world);
-                    // SAFETY: we registered the component above. the info exists
-                    #[expect(unsafe_code, reason = "this is faster")]
-                    let component_info =
-                        unsafe { world.components().get_info_unchecked(component_id) };
-                    if matches!(
-                        *component_info.clone_behavior(),
-                        ComponentCloneBehavior::Ignore
-                    ) {
-                        continue;
-                    }
-                }
-
-                SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
-                    reflect_component.apply_or_insert_mapped(
-                        &mut world.entity_mut(entity),
-                        component.as_partial_reflect(),
-                        &type_registry,
-                        mapper,
-                        RelationshipHookMode::Skip,
-                    );
-                });
-            }
-        }
-
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
-        for resource in &self.resources {
-            let type_info = resource.get_represented_type_info().ok_or_else(|| {
-                SceneSpawnError::NoRepresentedType {
-                    type_path: resource.reflect_type_path().to_string(),
-                }
-            })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                SceneSpawnError::UnregisteredButReflectedType {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-
-            // If this component references entities in the scene, update
-            // them to the entities in the world.
-            let mut cloned_resource;
-            let partial_reflect_resource = if let Some(map_entities) =
-                registration.data::<ReflectMapEntities>()
-            {
-                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
-                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
-                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
-                });
-                cloned_resource.as_partial_reflect()
-            } else {
-                resource.as_partial_reflect()
-            };
-
-            // If the world already contains an instance of the given resource
-            // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, partial_reflect_resource, &type_registry);
+            write_components(
+                world,
+                entity_map,
+                type_registry,
+                entity,
+                &scene_entity.components,
+            )?;
         }
 
         Ok(())
     }
 
+    /// Writes the resources of the scene to the given world.
+    ///
+    /// Resources are written after all entities have been added to the world.
+    /// This ensures the entities are available for the resources to reference during mapping.
+    pub(crate) fn write_resources_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        write_resources(world, entity_map, type_registry, &self.resources)
+    }
+
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
@@ -212,6 +169,105 @@ impl DynamicScene {
     }
 }
 
+/// Applies or adds the given components to `entity`, mapping the scene entities they reference with `entity_map`.
+pub(crate) fn write_components(
+    world: &mut World,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+    components: &[Box<dyn PartialReflect>],
+) -> Result<(), SceneSpawnError> {
+    // Apply/ add each component to the given entity.
+    for component in components {
+        let type_info = component.get_represented_type_info().ok_or_else(|| {
+            SceneSpawnError::NoRepresentedType {
+                type_path: component.reflect_type_path().to_string(),
+            }
+        })?;
+        let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            SceneSpawnError::UnregisteredButReflectedType {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+        let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredComponent {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+
+        {
+            let component_id = reflect_component.register_component(world);
+            // SAFETY: we registered the component above. the info exists
+            #[expect(unsafe_code, reason = "this is faster")]
+            let component_info = unsafe { world.components().get_info_unchecked(component_id) };
+            if matches!(
+                *component_info.clone_behavior(),
+                ComponentCloneBehavior::Ignore
+            ) {
+                continue;
+            }
+        }
+
+        SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+            reflect_component.apply_or_insert_mapped(
+                &mut world.entity_mut(entity),
+                component.as_partial_reflect(),
+                type_registry,
+                mapper,
+                RelationshipHookMode::Skip,
+            );
+        });
+    }
+
+    Ok(())
+}
+
+/// Applies or inserts the given resources, mapping the scene entities they reference with `entity_map`.
+pub(crate) fn write_resources(
+    world: &mut World,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+    resources: &[Box<dyn PartialReflect>],
+) -> Result<(), SceneSpawnError> {
+    for resource in resources {
+        let type_info = resource.get_represented_type_info().ok_or_else(|| {
+            SceneSpawnError::NoRepresentedType {
+                type_path: resource.reflect_type_path().to_string(),
+            }
+        })?;
+        let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            SceneSpawnError::UnregisteredButReflectedType {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+        let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredResource {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+
+        // If this component references entities in the scene, update
+        // them to the entities in the world.
+        let mut cloned_resource;
+        let partial_reflect_resource =
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
+                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
+                });
+                cloned_resource.as_partial_reflect()
+            } else {
+                resource.as_partial_reflect()
+            };
+
+        // If the world already contains an instance of the given resource
+        // just apply the (possibly) new value, otherwise insert the resource
+        reflect_resource.apply_or_insert(world, partial_reflect_resource, type_registry);
+    }
+
+    Ok(())
+}
+
 /// Serialize a given Rust data structure into rust object notation (ron).
 #[cfg(feature = "serialize")]
 pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
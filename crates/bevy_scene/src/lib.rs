@@ -15,10 +15,15 @@ extern crate alloc;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod nested_scene;
+mod reconcile;
 mod reflect_utils;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_patch;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -30,9 +35,15 @@ pub use bevy_asset::ron;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use nested_scene::*;
+pub use reconcile::SceneReloadMode;
+pub(crate) use reconcile::{reconcile_instance, snapshot_dynamic_scene, snapshot_scene};
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_patch::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -41,15 +52,18 @@ pub use scene_spawner::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, Scene, SceneFilter, SceneRoot,
-        SceneSpawner,
+        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, NestedScene, Scene, SceneFilter,
+        SceneRoot, SceneSpawner,
     };
 }
 
 use bevy_app::prelude::*;
 
 #[cfg(feature = "serialize")]
-use {bevy_asset::AssetApp, bevy_ecs::schedule::IntoScheduleConfigs};
+use {
+    bevy_asset::{transformer::IdentityAssetTransformer, AssetApp},
+    bevy_ecs::{schedule::IntoScheduleConfigs, world::FromWorld},
+};
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -61,8 +75,24 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<NestedScene>()
+            .add_systems(
+                SpawnScene,
+                (resolve_nested_scenes, scene_spawner, scene_spawner_system).chain(),
+            );
+
+        let binary_saver = BinarySceneSaver::from_world(app.world_mut());
+        let ron_saver = SceneSaver::from_world(app.world_mut());
+        app.register_asset_processor(SceneToBinaryProcessor::new(
+            IdentityAssetTransformer::new(),
+            binary_saver,
+        ))
+        .register_asset_processor(BinaryToSceneProcessor::new(
+            IdentityAssetTransformer::new(),
+            ron_saver,
+        ));
 
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
@@ -0,0 +1,786 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_asset::{AssetPath, AssetServer};
+use bevy_camera::visibility::Visibility;
+use bevy_ecs::{
+    change_detection::Res,
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    hierarchy::ChildOf,
+    prelude::{Changed, ReflectComponent},
+    reflect::AppTypeRegistry,
+    system::{Commands, Query},
+    world::World,
+};
+use bevy_reflect::{prelude::ReflectDefault, GetPath, PartialReflect, Reflect, TypePath};
+use bevy_transform::components::Transform;
+use thiserror::Error;
+use tracing::{error, warn};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::serde::{ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry};
+
+use crate::DynamicSceneRoot;
+
+/// Instantiates the [`DynamicScene`](crate::DynamicScene) asset at [`NestedScene::path`] as a child of this entity, then
+/// applies [`NestedScene::overrides`] to the spawned entities.
+///
+/// This enables "prefab"-style composition: a scene can contain entities with a [`NestedScene`] component that reference
+/// other scene assets, and patch some of their reflected fields for this particular instance. The [`SceneSpawner`] resolves
+/// nested scenes recursively, and re-applies the overrides whenever the nested scene is respawned (for example when it is
+/// hot-reloaded). When the scene containing this component is hot-reloaded, the nested scene is instantiated again.
+///
+/// In the scene format, a nested scene looks like this:
+///
+/// ```ron
+/// "bevy_scene::nested_scene::NestedScene": (
+///   path: "prefabs/tree.scn.ron",
+///   overrides: [
+///     (
+///       entity: 4294967297,
+///       component: "bevy_transform::components::transform::Transform",
+///       path: "scale.y",
+///       value: {
+///         "f32": 2.0,
+///       },
+///     ),
+///   ],
+/// ),
+/// ```
+///
+/// This component inserts a [`DynamicSceneRoot`] on its entity, and therefore shouldn't be used alongside a
+/// [`SceneRoot`](crate::SceneRoot).
+///
+/// [`SceneSpawner`]: crate::SceneSpawner
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(opaque)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+#[require(Transform)]
+#[require(Visibility)]
+pub struct NestedScene {
+    /// The path of the [`DynamicScene`](crate::DynamicScene) asset to instantiate.
+    pub path: AssetPath<'static>,
+    /// The overrides applied to the entities of the nested scene once it has been spawned.
+    pub overrides: Vec<SceneOverride>,
+}
+
+/// Patches a reflected field of a component of an entity spawned by a [`NestedScene`].
+#[derive(Debug)]
+pub struct SceneOverride {
+    /// The entity to patch, as identified in the nested scene (not in the world).
+    pub entity: Entity,
+    /// The type path of the component to patch.
+    pub component: String,
+    /// The [reflect path](bevy_reflect::ParsedPath) of the field to patch within the component.
+    /// If this is empty, the whole component is patched (or inserted, if the entity doesn't have it yet).
+    pub path: String,
+    /// The value to apply to the field.
+    pub value: Box<dyn PartialReflect>,
+}
+
+impl Clone for SceneOverride {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            component: self.component.clone(),
+            path: self.path.clone(),
+            value: self.value.to_dynamic(),
+        }
+    }
+}
+
+/// An error that occurs when applying a [`SceneOverride`].
+#[derive(Error, Debug)]
+pub enum SceneOverrideError {
+    /// The overridden entity does not exist in the nested scene.
+    #[error("entity {0} does not exist in the nested scene")]
+    MissingEntity(Entity),
+    /// The overridden component is not registered as a reflected component.
+    #[error("component `{0}` is not registered, or does not reflect `Component`")]
+    UnregisteredComponent(String),
+    /// The overridden entity does not have the overridden component.
+    #[error("entity {entity} does not have component `{component}`")]
+    MissingComponent {
+        /// The entity, as identified in the nested scene.
+        entity: Entity,
+        /// The type path of the component.
+        component: String,
+    },
+    /// The path of the override could not be resolved.
+    #[error("failed to access `{path}` on `{component}`: {message}")]
+    InvalidPath {
+        /// The type path of the component.
+        component: String,
+        /// The path of the override.
+        path: String,
+        /// The reason the path could not be resolved.
+        message: String,
+    },
+    /// The value of the override could not be applied to the field.
+    #[error("failed to apply override to `{path}` on `{component}`: {err}")]
+    ApplyError {
+        /// The type path of the component.
+        component: String,
+        /// The path of the override.
+        path: String,
+        /// The underlying error.
+        err: bevy_reflect::ApplyError,
+    },
+}
+
+impl NestedScene {
+    /// Creates a new [`NestedScene`] that instantiates the scene at `path`, without any overrides.
+    pub fn new(path: impl Into<AssetPath<'static>>) -> Self {
+        Self {
+            path: path.into(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Adds an override that sets the field at `path` of the component `C` of the nested scene `entity` to `value`.
+    pub fn with_override<C: Component + TypePath>(
+        mut self,
+        entity: Entity,
+        path: impl Into<String>,
+        value: impl PartialReflect,
+    ) -> Self {
+        self.overrides.push(SceneOverride {
+            entity,
+            component: C::type_path().into(),
+            path: path.into(),
+            value: Box::new(value),
+        });
+        self
+    }
+}
+
+impl SceneOverride {
+    /// Applies this override to the world entities spawned for the nested scene, as described by `entity_map`.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneOverrideError> {
+        let type_registry = type_registry.read();
+        let entity = *entity_map
+            .get(&self.entity)
+            .ok_or(SceneOverrideError::MissingEntity(self.entity))?;
+        let reflect_component = type_registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .ok_or_else(|| SceneOverrideError::UnregisteredComponent(self.component.clone()))?;
+        let mut entity_mut = world.entity_mut(entity);
+
+        if self.path.is_empty() && !reflect_component.contains(&entity_mut) {
+            reflect_component.insert(&mut entity_mut, self.value.as_ref(), &type_registry);
+            return Ok(());
+        }
+        let mut component = reflect_component
+            .reflect_mut(&mut entity_mut)
+            .ok_or_else(|| SceneOverrideError::MissingComponent {
+                entity: self.entity,
+                component: self.component.clone(),
+            })?;
+        let field = if self.path.is_empty() {
+            component.as_partial_reflect_mut()
+        } else {
+            component
+                .reflect_path_mut(self.path.as_str())
+                .map_err(|err| SceneOverrideError::InvalidPath {
+                    component: self.component.clone(),
+                    path: self.path.clone(),
+                    message: err.to_string(),
+                })?
+        };
+        field
+            .try_apply(self.value.as_ref())
+            .map_err(|err| SceneOverrideError::ApplyError {
+                component: self.component.clone(),
+                path: self.path.clone(),
+                err,
+            })
+    }
+}
+
+/// System that starts loading the scene of each new or changed [`NestedScene`], by inserting a [`DynamicSceneRoot`] on its entity.
+///
+/// A nested scene whose path is already instantiated by one of its ancestors (for example a scene that nests itself, or
+/// two scenes that nest each other) would be spawned recursively forever. Such a nested scene is skipped, and an error is
+/// logged.
+pub fn resolve_nested_scenes(
+    mut commands: Commands,
+    nested_scenes: Query<(Entity, &NestedScene), Changed<NestedScene>>,
+    parents: Query<&ChildOf>,
+    scene_roots: Query<&DynamicSceneRoot>,
+    asset_server: Res<AssetServer>,
+) {
+    for (entity, nested_scene) in &nested_scenes {
+        let mut ancestor_paths = parents
+            .iter_ancestors(entity)
+            .filter_map(|ancestor| scene_roots.get(ancestor).ok())
+            .filter_map(|scene_root| scene_root.0.path());
+        if ancestor_paths.any(|path| *path == nested_scene.path) {
+            error!(
+                "Nested scene {} on entity {entity} is already instantiated by one of its ancestors; skipping it to avoid an infinite recursion",
+                nested_scene.path
+            );
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(DynamicSceneRoot(asset_server.load(&nested_scene.path)));
+    }
+}
+
+/// Applies the overrides of the [`NestedScene`] of `parent` (if any) to a freshly spawned instance of its scene.
+pub(crate) fn apply_nested_scene_overrides(
+    world: &mut World,
+    parent: Entity,
+    entity_map: &EntityHashMap<Entity>,
+) {
+    let Some(nested_scene) = world
+        .get_entity(parent)
+        .ok()
+        .and_then(|parent| parent.get::<NestedScene>())
+    else {
+        return;
+    };
+    if nested_scene.overrides.is_empty() {
+        return;
+    }
+    let overrides = nested_scene.overrides.clone();
+    let path = nested_scene.path.clone();
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    for scene_override in &overrides {
+        if let Err(err) = scene_override.apply(world, entity_map, &type_registry) {
+            warn!("Failed to apply override to nested scene {path}: {err}");
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+mod serde {
+    use super::{NestedScene, SceneOverride};
+    use alloc::{string::String, vec::Vec};
+    use bevy_asset::AssetPath;
+    use bevy_ecs::entity::Entity;
+    use bevy_reflect::{
+        serde::{
+            DeserializeWithRegistry, ReflectDeserializer, ReflectSerializer, SerializeWithRegistry,
+        },
+        TypeRegistry,
+    };
+    use core::fmt::Formatter;
+    use serde::{
+        de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+        ser::{SerializeSeq, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    const NESTED_SCENE_STRUCT: &str = "NestedScene";
+    const NESTED_SCENE_FIELDS: &[&str] = &["path", "overrides"];
+    const OVERRIDE_STRUCT: &str = "SceneOverride";
+    const OVERRIDE_FIELDS: &[&str] = &["entity", "component", "path", "value"];
+
+    impl SerializeWithRegistry for NestedScene {
+        fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct(NESTED_SCENE_STRUCT, 2)?;
+            state.serialize_field("path", &self.path)?;
+            state.serialize_field(
+                "overrides",
+                &OverridesSerializer {
+                    overrides: &self.overrides,
+                    registry,
+                },
+            )?;
+            state.end()
+        }
+    }
+
+    struct OverridesSerializer<'a> {
+        overrides: &'a [SceneOverride],
+        registry: &'a TypeRegistry,
+    }
+
+    impl Serialize for OverridesSerializer<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_seq(Some(self.overrides.len()))?;
+            for scene_override in self.overrides {
+                state.serialize_element(&OverrideSerializer {
+                    scene_override,
+                    registry: self.registry,
+                })?;
+            }
+            state.end()
+        }
+    }
+
+    struct OverrideSerializer<'a> {
+        scene_override: &'a SceneOverride,
+        registry: &'a TypeRegistry,
+    }
+
+    impl Serialize for OverrideSerializer<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct(OVERRIDE_STRUCT, 4)?;
+            state.serialize_field("entity", &self.scene_override.entity)?;
+            state.serialize_field("component", &self.scene_override.component)?;
+            state.serialize_field("path", &self.scene_override.path)?;
+            state.serialize_field(
+                "value",
+                &ReflectSerializer::new(self.scene_override.value.as_ref(), self.registry),
+            )?;
+            state.end()
+        }
+    }
+
+    impl<'de> DeserializeWithRegistry<'de> for NestedScene {
+        fn deserialize<D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_struct(
+                NESTED_SCENE_STRUCT,
+                NESTED_SCENE_FIELDS,
+                NestedSceneVisitor { registry },
+            )
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(field_identifier, rename_all = "lowercase")]
+    enum NestedSceneField {
+        Path,
+        Overrides,
+    }
+
+    struct NestedSceneVisitor<'a> {
+        registry: &'a TypeRegistry,
+    }
+
+    impl<'de> Visitor<'de> for NestedSceneVisitor<'_> {
+        type Value = NestedScene;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("nested scene struct")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let path = seq
+                .next_element::<AssetPath<'static>>()?
+                .ok_or_else(|| Error::missing_field("path"))?;
+            let overrides = seq
+                .next_element_seed(OverridesDeserializer {
+                    registry: self.registry,
+                })?
+                .ok_or_else(|| Error::missing_field("overrides"))?;
+            Ok(NestedScene { path, overrides })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut path = None;
+            let mut overrides = None;
+            while let Some(key) = map.next_key()? {
+                match key {
+                    NestedSceneField::Path => {
+                        if path.is_some() {
+                            return Err(Error::duplicate_field("path"));
+                        }
+                        path = Some(map.next_value::<AssetPath<'static>>()?);
+                    }
+                    NestedSceneField::Overrides => {
+                        if overrides.is_some() {
+                            return Err(Error::duplicate_field("overrides"));
+                        }
+                        overrides = Some(map.next_value_seed(OverridesDeserializer {
+                            registry: self.registry,
+                        })?);
+                    }
+                }
+            }
+            Ok(NestedScene {
+                path: path.ok_or_else(|| Error::missing_field("path"))?,
+                overrides: overrides.unwrap_or_default(),
+            })
+        }
+    }
+
+    struct OverridesDeserializer<'a> {
+        registry: &'a TypeRegistry,
+    }
+
+    impl<'de> DeserializeSeed<'de> for OverridesDeserializer<'_> {
+        type Value = Vec<SceneOverride>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for OverridesDeserializer<'_> {
+        type Value = Vec<SceneOverride>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("list of scene overrides")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut overrides = Vec::new();
+            while let Some(scene_override) = seq.next_element_seed(OverrideDeserializer {
+                registry: self.registry,
+            })? {
+                overrides.push(scene_override);
+            }
+            Ok(overrides)
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(field_identifier, rename_all = "lowercase")]
+    enum OverrideField {
+        Entity,
+        Component,
+        Path,
+        Value,
+    }
+
+    struct OverrideDeserializer<'a> {
+        registry: &'a TypeRegistry,
+    }
+
+    impl<'de> DeserializeSeed<'de> for OverrideDeserializer<'_> {
+        type Value = SceneOverride;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_struct(OVERRIDE_STRUCT, OVERRIDE_FIELDS, self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for OverrideDeserializer<'_> {
+        type Value = SceneOverride;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("scene override struct")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let entity = seq
+                .next_element::<Entity>()?
+                .ok_or_else(|| Error::missing_field("entity"))?;
+            let component = seq
+                .next_element::<String>()?
+                .ok_or_else(|| Error::missing_field("component"))?;
+            let path = seq
+                .next_element::<String>()?
+                .ok_or_else(|| Error::missing_field("path"))?;
+            let value = seq
+                .next_element_seed(ReflectDeserializer::new(self.registry))?
+                .ok_or_else(|| Error::missing_field("value"))?;
+            Ok(SceneOverride {
+                entity,
+                component,
+                path,
+                value,
+            })
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entity = None;
+            let mut component = None;
+            let mut path = None;
+            let mut value = None;
+            while let Some(key) = map.next_key()? {
+                match key {
+                    OverrideField::Entity => {
+                        if entity.is_some() {
+                            return Err(Error::duplicate_field("entity"));
+                        }
+                        entity = Some(map.next_value::<Entity>()?);
+                    }
+                    OverrideField::Component => {
+                        if component.is_some() {
+                            return Err(Error::duplicate_field("component"));
+                        }
+                        component = Some(map.next_value::<String>()?);
+                    }
+                    OverrideField::Path => {
+                        if path.is_some() {
+                            return Err(Error::duplicate_field("path"));
+                        }
+                        path = Some(map.next_value::<String>()?);
+                    }
+                    OverrideField::Value => {
+                        if value.is_some() {
+                            return Err(Error::duplicate_field("value"));
+                        }
+                        value = Some(map.next_value_seed(ReflectDeserializer::new(self.registry))?);
+                    }
+                }
+            }
+            Ok(SceneOverride {
+                entity: entity.ok_or_else(|| Error::missing_field("entity"))?,
+                component: component.ok_or_else(|| Error::missing_field("component"))?,
+                path: path.unwrap_or_default(),
+                value: value.ok_or_else(|| Error::missing_field("value"))?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicSceneBuilder, DynamicSceneRoot, NestedScene, ScenePlugin};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin,
+    };
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        hierarchy::Children,
+        query::{With, Without},
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::{
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        FromReflect, Reflect,
+    };
+    use serde::de::DeserializeSeed;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Circle {
+        radius: f32,
+    }
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Label(u32);
+
+    fn nested_scene_ron(type_registry: &AppTypeRegistry, radius: f32) -> (String, Entity) {
+        let mut world = World::new();
+        world.insert_resource(type_registry.clone());
+        let circle = world.spawn(Circle { radius }).id();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(circle)
+            .build();
+        (scene.serialize(&type_registry.read()).unwrap(), circle)
+    }
+
+    fn spawned_circle(app: &App, entity: Entity) -> Option<Entity> {
+        let children = app.world().get::<Children>(entity)?;
+        children
+            .iter()
+            .copied()
+            .find(|child| app.world().get::<Circle>(*child).is_some())
+    }
+
+    #[test]
+    fn nested_scene_applies_overrides() {
+        let dir = Dir::default();
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || {
+                Box::new(MemoryAssetReader {
+                    root: reader_dir.clone(),
+                })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Circle>()
+        .register_type::<Label>();
+
+        let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        let (ron, circle) = nested_scene_ron(&type_registry, 1.0);
+        dir.insert_asset_text(Path::new("circle.scn.ron"), &ron);
+
+        let nested = NestedScene::new("circle.scn.ron")
+            .with_override::<Circle>(circle, "radius", 5.0f32)
+            .with_override::<Label>(circle, "", Label(3));
+        let first = app.world_mut().spawn(nested).id();
+        let second = app
+            .world_mut()
+            .spawn(NestedScene::new("circle.scn.ron"))
+            .id();
+
+        for _ in 0..100 {
+            if spawned_circle(&app, first).is_some() && spawned_circle(&app, second).is_some() {
+                break;
+            }
+            app.update();
+        }
+
+        let first_circle = spawned_circle(&app, first).expect("nested scene was spawned");
+        assert_eq!(
+            app.world().get::<Circle>(first_circle),
+            Some(&Circle { radius: 5.0 })
+        );
+        assert_eq!(app.world().get::<Label>(first_circle), Some(&Label(3)));
+
+        let second_circle = spawned_circle(&app, second).expect("nested scene was spawned");
+        assert_eq!(
+            app.world().get::<Circle>(second_circle),
+            Some(&Circle { radius: 1.0 })
+        );
+        assert_eq!(app.world().get::<Label>(second_circle), None);
+    }
+
+    fn nesting_scene_ron(type_registry: &AppTypeRegistry, path: &str) -> String {
+        let mut world = World::new();
+        world.insert_resource(type_registry.clone());
+        let nested = world.spawn(NestedScene::new(path.to_string())).id();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(nested)
+            .build();
+        scene.serialize(&type_registry.read()).unwrap()
+    }
+
+    fn app_with_scenes(scenes: &[(&str, &str)]) -> App {
+        let dir = Dir::default();
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || {
+                Box::new(MemoryAssetReader {
+                    root: reader_dir.clone(),
+                })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ));
+
+        let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        for (path, nested_path) in scenes {
+            let ron = nesting_scene_ron(&type_registry, nested_path);
+            dir.insert_asset_text(Path::new(path), &ron);
+        }
+        app
+    }
+
+    fn nested_scene_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query::<&NestedScene>()
+            .iter(app.world())
+            .count()
+    }
+
+    fn unresolved_nested_scene_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), (With<NestedScene>, Without<DynamicSceneRoot>)>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn self_nested_scene_is_not_instantiated_recursively() {
+        let mut app = app_with_scenes(&[("a.scn.ron", "a.scn.ron")]);
+        app.world_mut().spawn(NestedScene::new("a.scn.ron"));
+
+        for _ in 0..100 {
+            app.update();
+        }
+
+        // The root instantiates `a`, whose nested scene referencing `a` again is skipped.
+        assert_eq!(nested_scene_count(&mut app), 2);
+        assert_eq!(unresolved_nested_scene_count(&mut app), 1);
+    }
+
+    #[test]
+    fn mutually_nested_scenes_are_not_instantiated_recursively() {
+        let mut app = app_with_scenes(&[("a.scn.ron", "b.scn.ron"), ("b.scn.ron", "a.scn.ron")]);
+        app.world_mut().spawn(NestedScene::new("a.scn.ron"));
+
+        for _ in 0..100 {
+            app.update();
+        }
+
+        // The root instantiates `a`, which instantiates `b`, whose nested scene referencing `a` again is skipped.
+        assert_eq!(nested_scene_count(&mut app), 3);
+        assert_eq!(unresolved_nested_scene_count(&mut app), 1);
+    }
+
+    #[test]
+    fn nested_scene_serde_round_trip() {
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<NestedScene>();
+            registry.register::<Circle>();
+        }
+        let registry = type_registry.read();
+
+        let nested = NestedScene::new("circle.scn.ron").with_override::<Circle>(
+            Entity::from_raw_u32(1).unwrap(),
+            "radius",
+            5.0f32,
+        );
+        let ron = crate::ron::to_string(&TypedReflectSerializer::new(&nested, &registry)).unwrap();
+
+        let registration = registry
+            .get(core::any::TypeId::of::<NestedScene>())
+            .unwrap();
+        let mut deserializer = crate::ron::Deserializer::from_str(&ron).unwrap();
+        let deserialized = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = NestedScene::from_reflect(deserialized.as_ref()).unwrap();
+
+        assert_eq!(deserialized.path, nested.path);
+        assert_eq!(deserialized.overrides.len(), 1);
+        let scene_override = &deserialized.overrides[0];
+        assert_eq!(scene_override.entity, nested.overrides[0].entity);
+        assert_eq!(scene_override.component, nested.overrides[0].component);
+        assert_eq!(scene_override.path, "radius");
+        assert_eq!(f32::from_reflect(scene_override.value.as_ref()), Some(5.0));
+    }
+}
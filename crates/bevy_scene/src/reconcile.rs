@@ -0,0 +1,375 @@
+use crate::{
+    reflect_utils::clone_reflect_value, DynamicEntity, DynamicScene, Scene, SceneSpawnError,
+};
+use alloc::{string::String, vec, vec::Vec};
+use bevy_ecs::{
+    component::ComponentCloneBehavior,
+    entity::{Entity, EntityHashMap, EntityHashSet, SceneEntityMapper},
+    entity_disabling::DefaultQueryFilters,
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectResource},
+    world::World,
+};
+use bevy_reflect::{PartialReflect, ReflectPath, ReflectRef, TypeRegistration, TypeRegistry};
+use core::any::TypeId;
+
+/// How the [`SceneSpawner`](crate::SceneSpawner) updates spawned instances when their scene asset is modified
+/// (for example when it is hot-reloaded).
+///
+/// See [`SceneSpawner::set_reload_mode`](crate::SceneSpawner::set_reload_mode).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SceneReloadMode {
+    /// Despawns every entity of the instance, then spawns the modified scene again.
+    ///
+    /// Any change made to the instance entities at runtime is lost.
+    #[default]
+    Respawn,
+    /// Compares the previous and the modified contents of the scene, and only applies the differences to the instance:
+    /// - entities added to the scene are spawned, and entities removed from the scene are despawned,
+    /// - components added to (or removed from) a scene entity are inserted in (or removed from) its instance entity,
+    /// - only the fields that were edited in the scene are written to the instance components and resources.
+    ///
+    /// Components added at runtime, and fields that were not edited in the scene, keep their runtime value.
+    /// Entities despawned at runtime stay despawned.
+    ///
+    /// Fields are compared with [`PartialReflect::reflect_partial_eq`]: values that can't be compared are considered
+    /// edited.
+    Reconcile,
+}
+
+/// Copies the contents of `scene`, to be compared with its next version.
+pub(crate) fn snapshot_dynamic_scene(scene: &DynamicScene) -> DynamicScene {
+    DynamicScene {
+        resources: scene
+            .resources
+            .iter()
+            .map(|resource| resource.to_dynamic())
+            .collect(),
+        entities: scene
+            .entities
+            .iter()
+            .map(|entity| DynamicEntity {
+                entity: entity.entity,
+                components: entity
+                    .components
+                    .iter()
+                    .map(|component| component.to_dynamic())
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Copies the reflected contents of `scene`, to be compared with its next version.
+///
+/// Components and resources that are not registered in `type_registry` are skipped.
+pub(crate) fn snapshot_scene(scene: &Scene, type_registry: &TypeRegistry) -> DynamicScene {
+    let world = &scene.world;
+    let reflect_data =
+        |type_id: Option<TypeId>| type_id.and_then(|type_id| type_registry.get(type_id));
+    let default_query_filters = world
+        .components()
+        .get_resource_id(TypeId::of::<DefaultQueryFilters>());
+
+    let resources = world
+        .storages()
+        .resources
+        .iter()
+        .filter(|(component_id, data)| {
+            data.is_present() && Some(*component_id) != default_query_filters
+        })
+        .filter_map(|(component_id, _)| {
+            let type_id = world.components().get_info(component_id)?.type_id();
+            let reflect_resource = reflect_data(type_id)?.data::<ReflectResource>()?;
+            Some(reflect_resource.reflect(world).ok()?.to_dynamic())
+        })
+        .collect();
+
+    let entities = world
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| {
+            archetype
+                .entities()
+                .iter()
+                .map(move |entity| (archetype, entity.id()))
+        })
+        .map(|(archetype, entity)| {
+            let entity_ref = world.entity(entity);
+            let components = archetype
+                .components()
+                .filter_map(|component_id| {
+                    let type_id = world.components().get_info(component_id)?.type_id();
+                    let reflect_component = reflect_data(type_id)?.data::<ReflectComponent>()?;
+                    Some(reflect_component.reflect(entity_ref)?.to_dynamic())
+                })
+                .collect();
+            DynamicEntity { entity, components }
+        })
+        .collect();
+
+    DynamicScene {
+        resources,
+        entities,
+    }
+}
+
+/// Updates a spawned instance of the `old` scene so that it matches the `new` scene, only applying their differences.
+///
+/// See [`SceneReloadMode::Reconcile`].
+pub(crate) fn reconcile_instance(
+    world: &mut World,
+    old: &DynamicScene,
+    new: &DynamicScene,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+) -> Result<(), SceneSpawnError> {
+    let old_entities: EntityHashMap<&DynamicEntity> = old
+        .entities
+        .iter()
+        .map(|entity| (entity.entity, entity))
+        .collect();
+    let new_entities: EntityHashSet = new.entities.iter().map(|entity| entity.entity).collect();
+
+    // Despawn the entities that were removed from the scene.
+    for old_entity in &old.entities {
+        if new_entities.contains(&old_entity.entity) {
+            continue;
+        }
+        if let Some(entity) = entity_map.remove(&old_entity.entity)
+            && let Ok(entity_mut) = world.get_entity_mut(entity)
+        {
+            entity_mut.despawn();
+        }
+    }
+
+    // Spawn the entities that were added to the scene before writing any component,
+    // so that references to them can be mapped.
+    for new_entity in &new.entities {
+        if !old_entities.contains_key(&new_entity.entity) {
+            entity_map
+                .entry(new_entity.entity)
+                .or_insert_with(|| world.spawn_empty().id());
+        }
+    }
+
+    for new_entity in &new.entities {
+        let Some(&entity) = entity_map.get(&new_entity.entity) else {
+            continue;
+        };
+        // Entities despawned at runtime stay despawned.
+        if world.get_entity(entity).is_err() {
+            continue;
+        }
+        let old_components = old_entities
+            .get(&new_entity.entity)
+            .map(|old_entity| old_entity.components.as_slice())
+            .unwrap_or_default();
+
+        for component in &new_entity.components {
+            let registration = get_registration(component.as_ref(), type_registry)?;
+            let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredComponent {
+                    type_path: registration.type_info().type_path().to_string(),
+                }
+            })?;
+            let component_id = reflect_component.register_component(world);
+            if world
+                .components()
+                .get_info(component_id)
+                .is_some_and(|info| {
+                    matches!(*info.clone_behavior(), ComponentCloneBehavior::Ignore)
+                })
+            {
+                continue;
+            }
+
+            let changes = match find_value(old_components, registration.type_id()) {
+                Some(old_component) => changed_paths(old_component, component.as_ref()),
+                None => vec![String::new()],
+            };
+            if changes.is_empty() {
+                continue;
+            }
+
+            let mut value = clone_reflect_value(component.as_ref(), registration);
+            SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+                if let Some(value) = value.try_as_reflect_mut() {
+                    reflect_component.map_entities(value, mapper);
+                }
+                let mut entity_mut = world.entity_mut(entity);
+                let merged = reflect_component
+                    .reflect(&entity_mut)
+                    .and_then(|current| {
+                        merge_changes(
+                            current.as_partial_reflect(),
+                            value.as_ref(),
+                            &changes,
+                            registration,
+                        )
+                    })
+                    .unwrap_or(value);
+                reflect_component.insert(&mut entity_mut, merged.as_ref(), type_registry);
+            });
+        }
+
+        // Remove the components that were removed from the scene entity.
+        for old_component in old_components {
+            let Ok(registration) = get_registration(old_component.as_ref(), type_registry) else {
+                continue;
+            };
+            if find_value(&new_entity.components, registration.type_id()).is_some() {
+                continue;
+            }
+            if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                reflect_component.remove(&mut world.entity_mut(entity));
+            }
+        }
+    }
+
+    for resource in &new.resources {
+        let registration = get_registration(resource.as_ref(), type_registry)?;
+        let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredResource {
+                type_path: registration.type_info().type_path().to_string(),
+            }
+        })?;
+        let changes = match find_value(&old.resources, registration.type_id()) {
+            Some(old_resource) => changed_paths(old_resource, resource.as_ref()),
+            None => vec![String::new()],
+        };
+        if changes.is_empty() {
+            continue;
+        }
+
+        let mut value = clone_reflect_value(resource.as_ref(), registration);
+        if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+            SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                map_entities.map_entities(value.as_partial_reflect_mut(), mapper);
+            });
+        }
+        let merged = reflect_resource
+            .reflect(&*world)
+            .ok()
+            .and_then(|current| {
+                merge_changes(
+                    current.as_partial_reflect(),
+                    value.as_ref(),
+                    &changes,
+                    registration,
+                )
+            })
+            .unwrap_or(value);
+        reflect_resource.insert(world, merged.as_ref(), type_registry);
+    }
+
+    // Remove the resources that were removed from the scene.
+    for old_resource in &old.resources {
+        let Ok(registration) = get_registration(old_resource.as_ref(), type_registry) else {
+            continue;
+        };
+        if find_value(&new.resources, registration.type_id()).is_none()
+            && let Some(reflect_resource) = registration.data::<ReflectResource>()
+        {
+            reflect_resource.remove(world);
+        }
+    }
+
+    Ok(())
+}
+
+fn get_registration<'a>(
+    value: &dyn PartialReflect,
+    type_registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, SceneSpawnError> {
+    let type_info =
+        value
+            .get_represented_type_info()
+            .ok_or_else(|| SceneSpawnError::NoRepresentedType {
+                type_path: value.reflect_type_path().to_string(),
+            })?;
+    type_registry.get(type_info.type_id()).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_info.type_path().to_string(),
+        }
+    })
+}
+
+fn find_value(values: &[Box<dyn PartialReflect>], type_id: TypeId) -> Option<&dyn PartialReflect> {
+    values
+        .iter()
+        .find(|value| {
+            value
+                .get_represented_type_info()
+                .is_some_and(|type_info| type_info.type_id() == type_id)
+        })
+        .map(AsRef::as_ref)
+}
+
+/// Returns the paths of the fields of `old` that differ in `new`. An empty path means that the whole value differs.
+pub(crate) fn changed_paths(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Vec<String> {
+    let mut changes = Vec::new();
+    collect_changed_paths(old, new, &mut String::new(), &mut changes);
+    changes
+}
+
+fn collect_changed_paths(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    path: &mut String,
+    changes: &mut Vec<String>,
+) {
+    if old.reflect_partial_eq(new) == Some(true) {
+        return;
+    }
+    let mut visit = |field: &str, old: Option<&dyn PartialReflect>, new: &dyn PartialReflect| {
+        let len = path.len();
+        path.push('.');
+        path.push_str(field);
+        match old {
+            Some(old) => collect_changed_paths(old, new, path, changes),
+            None => changes.push(path.clone()),
+        }
+        path.truncate(len);
+    };
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => {
+            for (index, new_field) in new.iter_fields().enumerate() {
+                let name = new.name_at(index).unwrap_or_default();
+                visit(name, old.field(name), new_field);
+            }
+        }
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            for (index, new_field) in new.iter_fields().enumerate() {
+                visit(&index.to_string(), old.field(index), new_field);
+            }
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            for (index, new_field) in new.iter_fields().enumerate() {
+                visit(&index.to_string(), old.field(index), new_field);
+            }
+        }
+        _ => changes.push(path.clone()),
+    }
+}
+
+/// Returns a copy of `current` where the fields at the `changes` paths are replaced by the ones of `new`, or `None` if
+/// the whole value should be replaced.
+fn merge_changes(
+    current: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    changes: &[String],
+    registration: &TypeRegistration,
+) -> Option<Box<dyn PartialReflect>> {
+    if changes.iter().any(String::is_empty) {
+        return None;
+    }
+    let mut merged = clone_reflect_value(current, registration);
+    for path in changes {
+        let target = path.as_str().reflect_element_mut(merged.as_mut()).ok()?;
+        let source = path.as_str().reflect_element(new).ok()?;
+        target.try_apply(source).ok()?;
+    }
+    Some(merged)
+}
@@ -8,7 +8,10 @@ use thiserror::Error;
 
 #[cfg(feature = "serialize")]
 use {
-    crate::{serde::SceneDeserializer, DynamicScene},
+    crate::{
+        serde::{binary::BinarySceneError, SceneDeserializer},
+        DynamicScene,
+    },
     bevy_asset::{io::Reader, AssetLoader, LoadContext},
     serde::de::DeserializeSeed,
 };
@@ -44,6 +47,10 @@ pub enum SceneLoaderError {
     /// A [RON Error](ron::error::SpannedError)
     #[error("Could not parse RON: {0}")]
     RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [binary scene error](BinarySceneError)
+    #[cfg(feature = "serialize")]
+    #[error("Could not decode binary scene: {0}")]
+    Binary(#[from] BinarySceneError),
 }
 
 #[cfg(feature = "serialize")]
@@ -73,3 +80,50 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// Asset loader for a Bevy dynamic scene in the compact binary format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`]. Scenes can be converted to and from
+/// this format with the [asset processor](bevy_asset::processor), see [`SceneToBinaryProcessor`](crate::SceneToBinaryProcessor).
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    #[cfg_attr(
+        not(feature = "serialize"),
+        expect(dead_code, reason = "only used with `serialize` feature")
+    )]
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(DynamicScene::deserialize_binary(
+            &bytes,
+            &self.type_registry.read(),
+        )?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
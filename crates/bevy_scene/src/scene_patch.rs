@@ -0,0 +1,765 @@
+use crate::{
+    dynamic_scene::{write_components, write_resources},
+    reconcile::changed_paths,
+    reflect_utils::clone_reflect_value,
+    DynamicEntity, DynamicScene, DynamicSceneBuilder, InstanceId, SceneFilter, SceneSpawnError,
+    SceneSpawner,
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityHashSet, EntityMapper, SceneEntityMapper},
+    hierarchy::ChildOf,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+    world::{Mut, World},
+};
+use bevy_reflect::{
+    ApplyError, PartialReflect, ReflectPath, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use crate::{ron, serde::patch::ScenePatchSerializer, serialize_ron};
+
+/// The differences between two versions of a [`DynamicScene`]: the entities that were added and removed, and the
+/// components and fields that changed on the other entities.
+///
+/// Patches are computed with [`ScenePatch::diff`], or with [`ScenePatch::diff_instance`] to capture the changes made
+/// at runtime to a spawned scene instance. They can be applied to a scene with [`ScenePatch::apply`], or to a spawned
+/// instance with [`ScenePatch::write_to_instance`].
+///
+/// Entities are identified by their id in the scene the patch was computed from, so a patch should only be applied to
+/// that scene or its instances. This makes it possible to save a level as its scene asset and a (much smaller) patch of
+/// the changes made by the player.
+///
+/// Patches can be serialized with [`ScenePatch::serialize`] and deserialized with
+/// [`ScenePatchDeserializer`](crate::serde::patch::ScenePatchDeserializer).
+#[derive(Default)]
+pub struct ScenePatch {
+    /// Entities that were added to the scene.
+    pub added_entities: Vec<DynamicEntity>,
+    /// Entities that were removed from the scene.
+    pub removed_entities: Vec<Entity>,
+    /// Changes to the components of the entities present in both versions of the scene.
+    pub modified_entities: Vec<EntityPatch>,
+    /// Changes to the resources of the scene.
+    pub resources: SceneMapPatch,
+}
+
+/// Changes to the components of an entity of a [`ScenePatch`].
+pub struct EntityPatch {
+    /// The identifier of the entity in the patched scene.
+    pub entity: Entity,
+    /// The changes to the components of the entity.
+    pub components: SceneMapPatch,
+}
+
+/// The differences between two lists of values with unique types, such as the components of an entity or the resources
+/// of a scene.
+#[derive(Default)]
+pub struct SceneMapPatch {
+    /// Values that were added, or that must be replaced as a whole (for example an enum that changed variant).
+    pub inserted: Vec<Box<dyn PartialReflect>>,
+    /// Type paths of the values that were removed.
+    pub removed: Vec<String>,
+    /// Fields that changed in values present in both lists.
+    pub changed: Vec<FieldPatch>,
+}
+
+/// A new value for a field of a component or resource.
+pub struct FieldPatch {
+    /// The type path of the patched component or resource.
+    pub type_path: String,
+    /// The [reflection path](bevy_reflect::ReflectPath) of the field within the component or resource,
+    /// for example `.translation.x`.
+    pub path: String,
+    /// The new value of the field.
+    pub value: Box<dyn PartialReflect>,
+}
+
+/// Errors that can occur when computing or applying a [`ScenePatch`].
+#[derive(Error, Debug)]
+pub enum ScenePatchError {
+    /// The patch modifies an entity which doesn't exist.
+    #[error("the patched entity {entity} does not exist")]
+    MissingEntity {
+        /// The identifier of the entity in the patched scene.
+        entity: Entity,
+    },
+    /// The patch modifies a component or resource which doesn't exist.
+    #[error("the patched value of type `{type_path}` does not exist")]
+    MissingValue {
+        /// Type path of the missing component or resource.
+        type_path: String,
+    },
+    /// The patch modifies a field which doesn't exist.
+    #[error("invalid field path `{path}` in `{type_path}`: {message}")]
+    InvalidPath {
+        /// Type path of the patched component or resource.
+        type_path: String,
+        /// Path of the field.
+        path: String,
+        /// Why the path could not be resolved.
+        message: String,
+    },
+    /// The new value of a field could not be applied.
+    #[error("could not patch the field `{path}` of `{type_path}`: {error}")]
+    Apply {
+        /// Type path of the patched component or resource.
+        type_path: String,
+        /// Path of the field.
+        path: String,
+        /// The underlying error.
+        #[source]
+        error: ApplyError,
+    },
+    /// The patch contains a type which is not registered in the type registry.
+    #[error("the type `{type_path}` was not found in the type registry")]
+    UnregisteredType {
+        /// The unregistered type.
+        type_path: String,
+    },
+    /// The scene instance with the given id does not exist.
+    #[error("scene instance {id:?} does not exist")]
+    NonExistentInstance {
+        /// Id of the non-existent scene instance.
+        id: InstanceId,
+    },
+    /// The patch could not be written to the world.
+    #[error(transparent)]
+    Spawn(#[from] SceneSpawnError),
+}
+
+impl ScenePatch {
+    /// Computes the changes that turn the `old` scene into the `new` scene.
+    ///
+    /// Entities are matched by id, and values by type. Fields are compared with
+    /// [`PartialReflect::reflect_partial_eq`]: values that can't be compared are considered changed.
+    pub fn diff(old: &DynamicScene, new: &DynamicScene) -> Self {
+        let old_entities: EntityHashMap<&DynamicEntity> = old
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect();
+        let new_entities: EntityHashSet = new.entities.iter().map(|entity| entity.entity).collect();
+
+        let mut patch = ScenePatch {
+            resources: SceneMapPatch::diff(&old.resources, &new.resources),
+            ..Default::default()
+        };
+        for new_entity in &new.entities {
+            match old_entities.get(&new_entity.entity) {
+                Some(old_entity) => {
+                    let components =
+                        SceneMapPatch::diff(&old_entity.components, &new_entity.components);
+                    if !components.is_empty() {
+                        patch.modified_entities.push(EntityPatch {
+                            entity: new_entity.entity,
+                            components,
+                        });
+                    }
+                }
+                None => patch.added_entities.push(DynamicEntity {
+                    entity: new_entity.entity,
+                    components: new_entity
+                        .components
+                        .iter()
+                        .map(|component| clone_value(component.as_ref()))
+                        .collect(),
+                }),
+            }
+        }
+        patch.removed_entities = old
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !new_entities.contains(entity))
+            .collect();
+        patch
+    }
+
+    /// Computes the changes made at runtime to the spawned instance `instance_id` of `scene`.
+    ///
+    /// Only the components allowed by `component_filter` are compared, and only the resources of `scene`.
+    /// Entities despawned from the instance are reported as removed, but entities spawned at runtime are not
+    /// part of the instance and are ignored.
+    pub fn diff_instance(
+        scene: &DynamicScene,
+        world: &World,
+        instance_id: InstanceId,
+        component_filter: SceneFilter,
+    ) -> Result<Self, ScenePatchError> {
+        let entity_map = world
+            .resource::<SceneSpawner>()
+            .instance_entity_map(instance_id)
+            .ok_or(ScenePatchError::NonExistentInstance { id: instance_id })?;
+        let mut scene_entities: EntityHashMap<Entity> = entity_map
+            .iter()
+            .map(|(&scene_entity, &entity)| (entity, scene_entity))
+            .collect();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut instance = DynamicSceneBuilder::from_world(world)
+            .with_component_filter(component_filter)
+            .extract_entities(
+                entity_map
+                    .values()
+                    .copied()
+                    .filter(|&entity| world.get_entity(entity).is_ok()),
+            )
+            .build();
+
+        // Express the instance in terms of scene entities.
+        for dynamic_entity in &mut instance.entities {
+            // The instance root entities are attached to the entity the scene was spawned on, outside of the scene.
+            dynamic_entity.components.retain(|component| {
+                component
+                    .try_downcast_ref::<ChildOf>()
+                    .is_none_or(|child_of| scene_entities.contains_key(&child_of.parent()))
+            });
+            for component in &mut dynamic_entity.components {
+                let Some(reflect_component) = component
+                    .get_represented_type_info()
+                    .and_then(|type_info| type_registry.get(type_info.type_id()))
+                    .and_then(TypeRegistration::data::<ReflectComponent>)
+                else {
+                    continue;
+                };
+                if let Some(component) = component.try_as_reflect_mut() {
+                    reflect_component.map_entities(component, &mut scene_entities);
+                }
+            }
+            dynamic_entity.entity = scene_entities.get_mapped(dynamic_entity.entity);
+        }
+
+        for resource in &scene.resources {
+            let Some(registration) = resource
+                .get_represented_type_info()
+                .and_then(|type_info| type_registry.get(type_info.type_id()))
+            else {
+                continue;
+            };
+            let Some(current) = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect(world).ok())
+            else {
+                continue;
+            };
+            let mut current = clone_reflect_value(current.as_partial_reflect(), registration);
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                map_entities.map_entities(current.as_mut(), &mut scene_entities);
+            }
+            instance.resources.push(current);
+        }
+
+        Ok(Self::diff(scene, &instance))
+    }
+
+    /// Returns `true` if the patch doesn't contain any change.
+    pub fn is_empty(&self) -> bool {
+        self.added_entities.is_empty()
+            && self.removed_entities.is_empty()
+            && self.modified_entities.is_empty()
+            && self.resources.is_empty()
+    }
+
+    /// Applies the changes of this patch to `scene`.
+    pub fn apply(&self, scene: &mut DynamicScene) -> Result<(), ScenePatchError> {
+        scene
+            .entities
+            .retain(|entity| !self.removed_entities.contains(&entity.entity));
+        for added_entity in &self.added_entities {
+            let components = added_entity
+                .components
+                .iter()
+                .map(|component| clone_value(component.as_ref()))
+                .collect();
+            match scene
+                .entities
+                .iter_mut()
+                .find(|entity| entity.entity == added_entity.entity)
+            {
+                Some(entity) => entity.components = components,
+                None => scene.entities.push(DynamicEntity {
+                    entity: added_entity.entity,
+                    components,
+                }),
+            }
+        }
+        for entity_patch in &self.modified_entities {
+            let entity = scene
+                .entities
+                .iter_mut()
+                .find(|entity| entity.entity == entity_patch.entity)
+                .ok_or(ScenePatchError::MissingEntity {
+                    entity: entity_patch.entity,
+                })?;
+            entity_patch.components.apply(&mut entity.components)?;
+        }
+        self.resources.apply(&mut scene.resources)
+    }
+
+    /// Writes the changes of this patch to the spawned instance `instance_id`.
+    ///
+    /// The entities added by the patch become part of the instance.
+    pub fn write_to_instance(
+        &self,
+        world: &mut World,
+        instance_id: InstanceId,
+    ) -> Result<(), ScenePatchError> {
+        world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+            let entity_map = scene_spawner
+                .instance_entity_map_mut(instance_id)
+                .ok_or(ScenePatchError::NonExistentInstance { id: instance_id })?;
+            self.write_to_world(world, entity_map)
+        })
+    }
+
+    /// Writes the changes of this patch to the world, using `entity_map` to find the world entities that correspond to
+    /// the entities of the patched scene.
+    ///
+    /// The entities added by the patch are spawned and inserted into `entity_map`, and the removed ones are despawned
+    /// and removed from it.
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), ScenePatchError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+
+        for scene_entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(scene_entity)
+                && let Ok(entity_mut) = world.get_entity_mut(entity)
+            {
+                entity_mut.despawn();
+            }
+        }
+
+        // Spawn the added entities before writing any component, so that references to them can be mapped.
+        for added_entity in &self.added_entities {
+            entity_map
+                .entry(added_entity.entity)
+                .or_insert_with(|| world.spawn_empty().id());
+        }
+        for added_entity in &self.added_entities {
+            let entity = entity_map[&added_entity.entity];
+            write_components(
+                world,
+                entity_map,
+                &type_registry,
+                entity,
+                &added_entity.components,
+            )?;
+        }
+
+        for entity_patch in &self.modified_entities {
+            let entity = entity_map
+                .get(&entity_patch.entity)
+                .copied()
+                .filter(|&entity| world.get_entity(entity).is_ok())
+                .ok_or(ScenePatchError::MissingEntity {
+                    entity: entity_patch.entity,
+                })?;
+            let components = &entity_patch.components;
+
+            for type_path in &components.removed {
+                let registration = get_registration(type_path, &type_registry)?;
+                if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                }
+            }
+            write_components(
+                world,
+                entity_map,
+                &type_registry,
+                entity,
+                &components.inserted,
+            )?;
+            for field in &components.changed {
+                let registration = get_registration(&field.type_path, &type_registry)?;
+                let reflect_component =
+                    registration.data::<ReflectComponent>().ok_or_else(|| {
+                        SceneSpawnError::UnregisteredComponent {
+                            type_path: field.type_path.clone(),
+                        }
+                    })?;
+                let value = map_field_entities(world, entity_map, &type_registry, field);
+                let mut entity_mut = world.entity_mut(entity);
+                let mut component =
+                    reflect_component
+                        .reflect_mut(&mut entity_mut)
+                        .ok_or_else(|| ScenePatchError::MissingValue {
+                            type_path: field.type_path.clone(),
+                        })?;
+                field.apply_value(component.as_partial_reflect_mut(), value.as_ref())?;
+            }
+        }
+
+        let resources = &self.resources;
+        for type_path in &resources.removed {
+            let registration = get_registration(type_path, &type_registry)?;
+            if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                reflect_resource.remove(world);
+            }
+        }
+        write_resources(world, entity_map, &type_registry, &resources.inserted)?;
+        for field in &resources.changed {
+            let registration = get_registration(&field.type_path, &type_registry)?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: field.type_path.clone(),
+                }
+            })?;
+            let value = map_field_entities(world, entity_map, &type_registry, field);
+            let mut resource = reflect_resource.reflect_mut(&mut *world).map_err(|_| {
+                ScenePatchError::MissingValue {
+                    type_path: field.type_path.clone(),
+                }
+            })?;
+            field.apply_value(resource.as_partial_reflect_mut(), value.as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    /// Serialize this patch into the Bevy scene format, based on [Rusty Object Notation (RON)].
+    ///
+    /// [Rusty Object Notation (RON)]: https://crates.io/crates/ron
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ScenePatchSerializer::new(self, registry))
+    }
+}
+
+impl SceneMapPatch {
+    /// Computes the changes that turn the `old` values into the `new` values, matching them by type.
+    pub fn diff(old: &[Box<dyn PartialReflect>], new: &[Box<dyn PartialReflect>]) -> Self {
+        let mut patch = SceneMapPatch::default();
+        for new_value in new {
+            let type_path = type_path_of(new_value.as_ref());
+            let Some(old_value) = find_value(old, type_path) else {
+                patch.inserted.push(clone_value(new_value.as_ref()));
+                continue;
+            };
+            let paths = changed_paths(old_value, new_value.as_ref());
+            if paths.iter().any(String::is_empty) {
+                patch.inserted.push(clone_value(new_value.as_ref()));
+                continue;
+            }
+            for path in paths {
+                let Ok(field) = path.as_str().reflect_element(new_value.as_ref()) else {
+                    continue;
+                };
+                patch.changed.push(FieldPatch {
+                    type_path: type_path.to_owned(),
+                    value: clone_value(field),
+                    path,
+                });
+            }
+        }
+        patch.removed = old
+            .iter()
+            .map(|old_value| type_path_of(old_value.as_ref()))
+            .filter(|&type_path| find_value(new, type_path).is_none())
+            .map(ToOwned::to_owned)
+            .collect();
+        patch
+    }
+
+    /// Returns `true` if the patch doesn't contain any change.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Applies the changes of this patch to `values`.
+    pub fn apply(&self, values: &mut Vec<Box<dyn PartialReflect>>) -> Result<(), ScenePatchError> {
+        values.retain(|value| {
+            let type_path = type_path_of(value.as_ref());
+            !self.removed.iter().any(|removed| removed == type_path)
+        });
+        for inserted in &self.inserted {
+            let type_path = type_path_of(inserted.as_ref());
+            let inserted = clone_value(inserted.as_ref());
+            match values
+                .iter_mut()
+                .find(|value| type_path_of(value.as_ref()) == type_path)
+            {
+                Some(value) => *value = inserted,
+                None => values.push(inserted),
+            }
+        }
+        for field in &self.changed {
+            let value = values
+                .iter_mut()
+                .find(|value| type_path_of(value.as_ref()) == field.type_path)
+                .ok_or_else(|| ScenePatchError::MissingValue {
+                    type_path: field.type_path.clone(),
+                })?;
+            field.apply_value(value.as_mut(), field.value.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+impl FieldPatch {
+    fn apply_value(
+        &self,
+        target: &mut dyn PartialReflect,
+        value: &dyn PartialReflect,
+    ) -> Result<(), ScenePatchError> {
+        let field = self
+            .path
+            .as_str()
+            .reflect_element_mut(target)
+            .map_err(|error| ScenePatchError::InvalidPath {
+                type_path: self.type_path.clone(),
+                path: self.path.clone(),
+                message: error.to_string(),
+            })?;
+        field
+            .try_apply(value)
+            .map_err(|error| ScenePatchError::Apply {
+                type_path: self.type_path.clone(),
+                path: self.path.clone(),
+                error,
+            })
+    }
+}
+
+fn type_path_of(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+}
+
+fn find_value<'a>(
+    values: &'a [Box<dyn PartialReflect>],
+    type_path: &str,
+) -> Option<&'a dyn PartialReflect> {
+    values
+        .iter()
+        .map(AsRef::as_ref)
+        .find(|value| type_path_of(*value) == type_path)
+}
+
+/// Copies `value`, keeping its concrete type when it can be cloned.
+fn clone_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    value
+        .reflect_clone()
+        .map(PartialReflect::into_partial_reflect)
+        .unwrap_or_else(|_| value.to_dynamic())
+}
+
+fn get_registration<'a>(
+    type_path: &str,
+    type_registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, ScenePatchError> {
+    type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| ScenePatchError::UnregisteredType {
+            type_path: type_path.to_owned(),
+        })
+}
+
+/// Copies the new value of `field`, replacing the scene entities it references with their world entities.
+fn map_field_entities(
+    world: &mut World,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+    field: &FieldPatch,
+) -> Box<dyn PartialReflect> {
+    let mut value = clone_value(field.value.as_ref());
+    if let Some(entity) = value.try_downcast_mut::<Entity>() {
+        *entity = entity_map.get_mapped(*entity);
+    } else if let Some(map_entities) = value
+        .get_represented_type_info()
+        .and_then(|type_info| type_registry.get(type_info.type_id()))
+        .and_then(TypeRegistration::data::<ReflectMapEntities>)
+    {
+        SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+            map_entities.map_entities(value.as_mut(), mapper);
+        });
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        snapshot_dynamic_scene, DynamicScene, DynamicSceneRoot, SceneFilter, SceneInstance,
+        ScenePatch, ScenePlugin,
+    };
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, Assets, Handle};
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap},
+        hierarchy::ChildOf,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Chest;
+
+    fn world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Chest>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let mut world = world();
+        let player = world
+            .spawn(Health {
+                current: 10,
+                max: 10,
+            })
+            .id();
+        let chest = world.spawn(Chest).id();
+        let old = DynamicScene::from_world(&world);
+
+        world.get_mut::<Health>(player).unwrap().current = 4;
+        world.despawn(chest);
+        let bandit = world.spawn(Health { current: 5, max: 5 }).id();
+        let new = DynamicScene::from_world(&world);
+
+        let patch = ScenePatch::diff(&old, &new);
+        assert_eq!(patch.removed_entities, [chest]);
+        assert_eq!(patch.added_entities.len(), 1);
+        assert_eq!(patch.added_entities[0].entity, bandit);
+        assert_eq!(patch.modified_entities.len(), 1);
+        let changed = &patch.modified_entities[0].components.changed;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].path, ".current");
+
+        let mut patched = snapshot_dynamic_scene(&old);
+        patch.apply(&mut patched).unwrap();
+        assert!(ScenePatch::diff(&patched, &new).is_empty());
+    }
+
+    #[test]
+    fn write_patch_to_world() {
+        let mut scene_world = world();
+        let player = scene_world
+            .spawn(Health {
+                current: 10,
+                max: 10,
+            })
+            .id();
+        let chest = scene_world.spawn(Chest).id();
+        let scene = DynamicScene::from_world(&scene_world);
+
+        let mut world = world();
+        let mut entity_map = EntityHashMap::default();
+        scene.write_to_world(&mut world, &mut entity_map).unwrap();
+
+        scene_world.get_mut::<Health>(player).unwrap().current = 4;
+        scene_world.despawn(chest);
+        let patch = ScenePatch::diff(&scene, &DynamicScene::from_world(&scene_world));
+
+        let player_instance = entity_map[&player];
+        let chest_instance = entity_map[&chest];
+        world.get_mut::<Health>(player_instance).unwrap().max = 20;
+        patch.write_to_world(&mut world, &mut entity_map).unwrap();
+
+        assert_eq!(
+            world.get::<Health>(player_instance),
+            Some(&Health {
+                current: 4,
+                max: 20
+            })
+        );
+        assert!(world.get_entity(chest_instance).is_err());
+        assert!(!entity_map.contains_key(&chest));
+    }
+
+    #[test]
+    fn diff_and_patch_spawned_instances() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Health>()
+            .register_type::<Chest>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        scene_world.spawn(Health {
+            current: 10,
+            max: 10,
+        });
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+
+        let spawn = |app: &mut App, scene: &Handle<DynamicScene>| {
+            let root = app.world_mut().spawn(DynamicSceneRoot(scene.clone())).id();
+            app.update();
+            let instance_id = **app.world().get::<SceneInstance>(root).unwrap();
+            let player = app
+                .world_mut()
+                .query::<(Entity, &Health)>()
+                .iter(app.world())
+                .map(|(entity, _)| entity)
+                .find(|&entity| app.world().get::<ChildOf>(entity).unwrap().parent() == root)
+                .unwrap();
+            (instance_id, player)
+        };
+
+        // The player gets hurt and opens a chest, then the game is saved.
+        let (instance_id, player) = spawn(&mut app, &scene);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(Chest)
+            .get_mut::<Health>()
+            .unwrap()
+            .current = 3;
+        let patch = ScenePatch::diff_instance(
+            app.world()
+                .resource::<Assets<DynamicScene>>()
+                .get(&scene)
+                .unwrap(),
+            app.world(),
+            instance_id,
+            SceneFilter::allow_all(),
+        )
+        .unwrap();
+        assert!(patch.added_entities.is_empty());
+        assert!(patch.removed_entities.is_empty());
+        assert_eq!(patch.modified_entities.len(), 1);
+        let components = &patch.modified_entities[0].components;
+        assert_eq!(components.inserted.len(), 1);
+        assert_eq!(components.changed.len(), 1);
+
+        // The saved game is loaded by spawning the level again, then applying the patch.
+        let (instance_id, player) = spawn(&mut app, &scene);
+        patch
+            .write_to_instance(app.world_mut(), instance_id)
+            .unwrap();
+        assert_eq!(
+            app.world().get::<Health>(player),
+            Some(&Health {
+                current: 3,
+                max: 10
+            })
+        );
+        assert!(app.world().get::<Chest>(player).is_some());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{ron, serde::binary::BinarySceneError, BinarySceneLoader, DynamicScene, SceneLoader};
+use bevy_asset::{
+    io::Writer,
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    AsyncWriteExt,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+/// An [asset processor](bevy_asset::processor::Process) that converts RON scenes into the compact binary format.
+///
+/// To convert every `.scn.ron` scene of a project when it is processed:
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_scene::SceneToBinaryProcessor;
+/// # let mut app = App::new();
+/// app.set_default_asset_processor::<SceneToBinaryProcessor>("scn.ron");
+/// ```
+pub type SceneToBinaryProcessor =
+    LoadTransformAndSave<SceneLoader, IdentityAssetTransformer<DynamicScene>, BinarySceneSaver>;
+
+/// An [asset processor](bevy_asset::processor::Process) that converts binary scenes into RON scenes.
+pub type BinaryToSceneProcessor =
+    LoadTransformAndSave<BinarySceneLoader, IdentityAssetTransformer<DynamicScene>, SceneSaver>;
+
+/// Possible errors that can be produced by [`SceneSaver`] and [`BinarySceneSaver`].
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON Error](ron::Error)
+    #[error("Could not serialize RON: {0}")]
+    Ron(#[from] ron::Error),
+    /// A [binary scene error](BinarySceneError)
+    #[error("Could not encode binary scene: {0}")]
+    Binary(#[from] BinarySceneError),
+}
+
+/// Asset saver for a Bevy dynamic scene, writing it in RON with [`DynamicScene::serialize`].
+///
+/// The saved scene is loaded with the [`SceneLoader`].
+#[derive(Debug)]
+pub struct SceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for SceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        SceneSaver {
+            type_registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+impl AssetSaver for SceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = SceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let ron = asset.serialize(&self.type_registry.read())?;
+        writer.write_all(ron.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// Asset saver for a Bevy dynamic scene, writing it in the compact binary format with
+/// [`DynamicScene::serialize_binary`].
+///
+/// The saved scene is loaded with the [`BinarySceneLoader`].
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        BinarySceneSaver {
+            type_registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let bytes = asset.serialize_binary(&self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
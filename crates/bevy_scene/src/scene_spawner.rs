@@ -11,10 +11,14 @@ use bevy_ecs::{
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::Reflect;
 use bevy_utils::prelude::DebugName;
+use core::num::NonZeroUsize;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::{DynamicSceneRoot, SceneRoot};
+use crate::{
+    apply_nested_scene_overrides, reconcile_instance, snapshot_dynamic_scene, snapshot_scene,
+    DynamicSceneRoot, SceneReloadMode, SceneRoot, SceneSpawnBudget,
+};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     change_detection::ResMut,
@@ -43,6 +47,41 @@ struct InstanceInfo {
     parent: Option<Entity>,
 }
 
+/// A dynamic scene instance being spawned over several frames. See [`SceneSpawner::spawn_dynamic_incremental`].
+#[derive(Debug)]
+struct IncrementalSpawn {
+    handle: Handle<DynamicScene>,
+    instance: InstanceInfo,
+    /// The number of scene entities whose components have been written to the world.
+    spawned_entities: usize,
+    entities_per_frame: NonZeroUsize,
+}
+
+/// The progress of a scene instance being spawned, as returned by [`SceneSpawner::spawn_progress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SceneSpawnProgress {
+    /// The number of scene entities that have been spawned.
+    pub spawned_entities: usize,
+    /// The total number of entities in the scene.
+    pub total_entities: usize,
+}
+
+impl SceneSpawnProgress {
+    /// Returns `true` if every entity of the scene has been spawned.
+    pub fn is_complete(&self) -> bool {
+        self.spawned_entities >= self.total_entities
+    }
+
+    /// Returns the fraction of the scene entities that have been spawned, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total_entities == 0 {
+            1.0
+        } else {
+            self.spawned_entities as f32 / self.total_entities as f32
+        }
+    }
+}
+
 /// Unique id identifying a scene instance.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash, Clone)]
@@ -71,6 +110,8 @@ impl InstanceId {
 /// Deferred methods: (Scene operations will be processed when the [`scene_spawner_system`] is run)
 /// - [`spawn_dynamic`](Self::spawn_dynamic)
 /// - [`spawn_dynamic_as_child`](Self::spawn_dynamic_as_child)
+/// - [`spawn_dynamic_incremental`](Self::spawn_dynamic_incremental)
+/// - [`spawn_dynamic_incremental_as_child`](Self::spawn_dynamic_incremental_as_child)
 /// - [`spawn`](Self::spawn)
 /// - [`spawn_as_child`](Self::spawn_as_child)
 /// - [`despawn`](Self::despawn)
@@ -100,6 +141,14 @@ pub struct SceneSpawner {
     dynamic_scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     instances_ready: Vec<(InstanceId, Option<Entity>)>,
+    /// Per-frame entity budgets of the queued dynamic scenes that should be spawned incrementally.
+    incremental_spawn_budgets: HashMap<InstanceId, NonZeroUsize>,
+    incremental_spawns: HashMap<InstanceId, IncrementalSpawn>,
+    reload_mode: SceneReloadMode,
+    /// Contents of the spawned scenes, as of their last (re)spawn. Only kept in [`SceneReloadMode::Reconcile`].
+    scene_snapshots: HashMap<AssetId<Scene>, DynamicScene>,
+    /// Contents of the spawned dynamic scenes, as of their last (re)spawn. Only kept in [`SceneReloadMode::Reconcile`].
+    dynamic_scene_snapshots: HashMap<AssetId<DynamicScene>, DynamicScene>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -157,6 +206,49 @@ pub enum SceneSpawnError {
 }
 
 impl SceneSpawner {
+    /// Sets how spawned instances are updated when their scene asset is modified. Defaults to
+    /// [`SceneReloadMode::Respawn`].
+    ///
+    /// [`SceneReloadMode::Reconcile`] only applies to instances spawned after it has been set.
+    pub fn set_reload_mode(&mut self, reload_mode: SceneReloadMode) {
+        self.reload_mode = reload_mode;
+        if reload_mode != SceneReloadMode::Reconcile {
+            self.scene_snapshots.clear();
+            self.dynamic_scene_snapshots.clear();
+        }
+    }
+
+    /// Returns how spawned instances are updated when their scene asset is modified.
+    pub fn reload_mode(&self) -> SceneReloadMode {
+        self.reload_mode
+    }
+
+    /// Keeps a copy of the contents of the given scene, to reconcile its instances when it is modified.
+    fn store_scene_snapshot(&mut self, world: &World, id: AssetId<Scene>) {
+        if self.reload_mode != SceneReloadMode::Reconcile || self.scene_snapshots.contains_key(&id)
+        {
+            return;
+        }
+        if let Some(scene) = world.resource::<Assets<Scene>>().get(id) {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            self.scene_snapshots
+                .insert(id, snapshot_scene(scene, &type_registry));
+        }
+    }
+
+    /// Keeps a copy of the contents of the given dynamic scene, to reconcile its instances when it is modified.
+    fn store_dynamic_scene_snapshot(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.reload_mode != SceneReloadMode::Reconcile
+            || self.dynamic_scene_snapshots.contains_key(&id)
+        {
+            return;
+        }
+        if let Some(scene) = world.resource::<Assets<DynamicScene>>().get(id) {
+            self.dynamic_scene_snapshots
+                .insert(id, snapshot_dynamic_scene(scene));
+        }
+    }
+
     /// Schedule the spawn of a new instance of the provided dynamic scene.
     pub fn spawn_dynamic(&mut self, id: impl Into<Handle<DynamicScene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -177,6 +269,39 @@ impl SceneSpawner {
         instance_id
     }
 
+    /// Schedule the spawn of a new instance of the provided dynamic scene, spread over several frames.
+    ///
+    /// Once the scene has loaded, at most `entities_per_frame` of its entities are spawned each time the
+    /// [`scene_spawner_system`] runs. Scene resources are inserted, and [`SceneInstanceReady`] is triggered, once the last
+    /// batch has been spawned. Use [`Self::spawn_progress`] to follow the progress of the instance.
+    ///
+    /// Note that the entities of the instance exist (without components) as soon as the first batch is spawned.
+    pub fn spawn_dynamic_incremental(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        entities_per_frame: NonZeroUsize,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(id);
+        self.incremental_spawn_budgets
+            .insert(instance_id, entities_per_frame);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent`, spread over several frames.
+    ///
+    /// See [`Self::spawn_dynamic_incremental`].
+    pub fn spawn_dynamic_incremental_as_child(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        parent: Entity,
+        entities_per_frame: NonZeroUsize,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic_as_child(id, parent);
+        self.incremental_spawn_budgets
+            .insert(instance_id, entities_per_frame);
+        instance_id
+    }
+
     /// Schedule the spawn of a new instance of the provided scene.
     pub fn spawn(&mut self, id: impl Into<Handle<Scene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -212,8 +337,12 @@ impl SceneSpawner {
     }
 
     /// This will remove all records of this instance, without despawning any entities.
+    ///
+    /// If the instance is being spawned incrementally, its remaining batches won't be spawned.
     pub fn unregister_instance(&mut self, instance_id: InstanceId) {
         self.spawned_instances.remove(&instance_id);
+        self.incremental_spawn_budgets.remove(&instance_id);
+        self.incremental_spawns.remove(&instance_id);
     }
 
     /// Immediately despawns all instances of a scene.
@@ -222,7 +351,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<Scene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -236,7 +367,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.dynamic_scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -249,6 +382,10 @@ impl SceneSpawner {
         if let Some(mut instance) = self.spawned_instances.remove(instance_id) {
             Self::despawn_instance_internal(world, &mut instance);
         }
+        self.incremental_spawn_budgets.remove(instance_id);
+        if let Some(mut spawn) = self.incremental_spawns.remove(instance_id) {
+            Self::despawn_instance_internal(world, &mut spawn.instance);
+        }
     }
 
     fn despawn_instance_internal(world: &mut World, instance: &mut InstanceInfo) {
@@ -280,6 +417,7 @@ impl SceneSpawner {
         );
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
+        self.store_dynamic_scene_snapshot(world, id);
         // We trigger `SceneInstanceReady` events after processing all scenes
         // SceneSpawner may not be available in the observer.
         self.instances_ready.push((instance_id, None));
@@ -319,6 +457,7 @@ impl SceneSpawner {
         );
         let spawned = self.spawned_scenes.entry(id).or_default();
         spawned.insert(instance_id);
+        self.store_scene_snapshot(world, id);
         // We trigger `SceneInstanceReady` events after processing all scenes
         // SceneSpawner may not be available in the observer.
         self.instances_ready.push((instance_id, None));
@@ -346,7 +485,7 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been
-    /// modified.
+    /// modified. Instances are either respawned or reconciled, depending on the [`SceneReloadMode`].
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
@@ -354,6 +493,31 @@ impl SceneSpawner {
     ) -> Result<(), SceneSpawnError> {
         for id in scene_ids {
             if let Some(spawned_instances) = self.spawned_scenes.get(id) {
+                if let Some(old) = self.scene_snapshots.get(id) {
+                    let type_registry = world.resource::<AppTypeRegistry>().clone();
+                    let type_registry = type_registry.read();
+                    let new = world
+                        .resource::<Assets<Scene>>()
+                        .get(*id)
+                        .map(|scene| snapshot_scene(scene, &type_registry))
+                        .ok_or(SceneSpawnError::NonExistentRealScene { id: *id })?;
+                    for instance_id in spawned_instances {
+                        if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                            reconcile_instance(
+                                world,
+                                old,
+                                &new,
+                                &mut instance_info.entity_map,
+                                &type_registry,
+                            )?;
+                            Self::set_scene_instance_parent_sync(world, instance_info);
+                            self.instances_ready
+                                .push((*instance_id, instance_info.parent));
+                        }
+                    }
+                    self.scene_snapshots.insert(*id, new);
+                    continue;
+                }
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
                         // Despawn the scene before respawning it. This is a very heavy operation,
@@ -376,7 +540,7 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided dynamic scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding dynamic scene
-    /// has been modified.
+    /// has been modified. Instances are either respawned or reconciled, depending on the [`SceneReloadMode`].
     pub fn update_spawned_dynamic_scenes(
         &mut self,
         world: &mut World,
@@ -384,21 +548,57 @@ impl SceneSpawner {
     ) -> Result<(), SceneSpawnError> {
         for id in scene_ids {
             if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        // Despawn the scene before respawning it. This is a very heavy operation,
-                        // but otherwise, entities may be left behind, or be left in an otherwise
-                        // invalid state (e.g., invalid relationships).
-                        Self::despawn_instance_internal(world, instance_info);
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
-                        Self::set_scene_instance_parent_sync(world, instance_info);
-                        // We trigger `SceneInstanceReady` events after processing all scenes
-                        // SceneSpawner may not be available in the observer.
-                        self.instances_ready
-                            .push((*instance_id, instance_info.parent));
+                if let Some(old) = self.dynamic_scene_snapshots.get(id) {
+                    let type_registry = world.resource::<AppTypeRegistry>().clone();
+                    let type_registry = type_registry.read();
+                    let new = world
+                        .resource::<Assets<DynamicScene>>()
+                        .get(*id)
+                        .map(snapshot_dynamic_scene)
+                        .ok_or(SceneSpawnError::NonExistentScene { id: *id })?;
+                    for instance_id in spawned_instances {
+                        if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                            reconcile_instance(
+                                world,
+                                old,
+                                &new,
+                                &mut instance_info.entity_map,
+                                &type_registry,
+                            )?;
+                            Self::set_scene_instance_parent_sync(world, instance_info);
+                            self.instances_ready
+                                .push((*instance_id, instance_info.parent));
+                        }
+                    }
+                    self.dynamic_scene_snapshots.insert(*id, new);
+                } else {
+                    for instance_id in spawned_instances {
+                        if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                            // Despawn the scene before respawning it. This is a very heavy operation,
+                            // but otherwise, entities may be left behind, or be left in an otherwise
+                            // invalid state (e.g., invalid relationships).
+                            Self::despawn_instance_internal(world, instance_info);
+                            Self::spawn_dynamic_internal(
+                                world,
+                                *id,
+                                &mut instance_info.entity_map,
+                            )?;
+                            Self::set_scene_instance_parent_sync(world, instance_info);
+                            // We trigger `SceneInstanceReady` events after processing all scenes
+                            // SceneSpawner may not be available in the observer.
+                            self.instances_ready
+                                .push((*instance_id, instance_info.parent));
+                        }
                     }
                 }
             }
+            // Restart the instances of this scene that are being spawned incrementally.
+            for spawn in self.incremental_spawns.values_mut() {
+                if spawn.handle.id() == *id {
+                    Self::despawn_instance_internal(world, &mut spawn.instance);
+                    spawn.spawned_entities = 0;
+                }
+            }
         }
         Ok(())
     }
@@ -430,6 +630,23 @@ impl SceneSpawner {
         let scenes_to_spawn = core::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (handle, instance_id, parent) in scenes_to_spawn {
+            if let Some(entities_per_frame) = self.incremental_spawn_budgets.remove(&instance_id) {
+                // The first batch is spawned by `spawn_incremental_batches`.
+                self.incremental_spawns.insert(
+                    instance_id,
+                    IncrementalSpawn {
+                        handle,
+                        instance: InstanceInfo {
+                            entity_map: EntityHashMap::default(),
+                            parent,
+                        },
+                        spawned_entities: 0,
+                        entities_per_frame,
+                    },
+                );
+                continue;
+            }
+
             let mut entity_map = EntityHashMap::default();
 
             match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
@@ -440,6 +657,7 @@ impl SceneSpawner {
                     self.spawned_instances.insert(instance_id, instance_info);
                     let spawned = self.spawned_dynamic_scenes.entry(handle.id()).or_default();
                     spawned.insert(instance_id);
+                    self.store_dynamic_scene_snapshot(world, handle.id());
                     // We trigger `SceneInstanceReady` events after processing all scenes
                     // SceneSpawner may not be available in the observer.
                     self.instances_ready.push((instance_id, parent));
@@ -465,6 +683,7 @@ impl SceneSpawner {
                     self.spawned_instances.insert(instance_id, instance_info);
                     let spawned = self.spawned_scenes.entry(scene_handle.id()).or_default();
                     spawned.insert(instance_id);
+                    self.store_scene_snapshot(world, scene_handle.id());
 
                     // We trigger `SceneInstanceReady` events after processing all scenes
                     // SceneSpawner may not be available in the observer.
@@ -481,6 +700,76 @@ impl SceneSpawner {
         Ok(())
     }
 
+    /// Immediately spawns the next batch of entities of every dynamic scene instance being spawned incrementally.
+    ///
+    /// Instances whose last batch has been spawned are registered as regular instances, and will trigger
+    /// [`SceneInstanceReady`].
+    pub fn spawn_incremental_batches(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        let mut completed = Vec::new();
+        world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            for (instance_id, spawn) in &mut self.incremental_spawns {
+                // Wait for the scene to be loaded.
+                let Some(scene) = scenes.get(spawn.handle.id()) else {
+                    continue;
+                };
+                let entity_map = &mut spawn.instance.entity_map;
+                if spawn.spawned_entities == 0 {
+                    // Spawning every entity upfront ensures that entity references can be mapped across batches.
+                    scene.reserve_entities(world, entity_map);
+                }
+                let start = spawn.spawned_entities.min(scene.entities.len());
+                let end = (start + spawn.entities_per_frame.get()).min(scene.entities.len());
+                scene.write_entities_to_world(world, entity_map, &type_registry, start..end)?;
+                spawn.spawned_entities = end;
+                if end == scene.entities.len() {
+                    scene.write_resources_to_world(world, entity_map, &type_registry)?;
+                    completed.push(*instance_id);
+                }
+            }
+            Ok(())
+        })?;
+
+        for instance_id in completed {
+            let Some(spawn) = self.incremental_spawns.remove(&instance_id) else {
+                continue;
+            };
+            Self::set_scene_instance_parent_sync(world, &spawn.instance);
+            let parent = spawn.instance.parent;
+            self.spawned_instances.insert(instance_id, spawn.instance);
+            self.spawned_dynamic_scenes
+                .entry(spawn.handle.id())
+                .or_default()
+                .insert(instance_id);
+            self.store_dynamic_scene_snapshot(world, spawn.handle.id());
+            // We trigger `SceneInstanceReady` events after processing all scenes
+            // SceneSpawner may not be available in the observer.
+            self.instances_ready.push((instance_id, parent));
+        }
+        Ok(())
+    }
+
+    /// Returns the spawn progress of an instance, if it is being spawned incrementally or has been spawned.
+    ///
+    /// Returns `None` if the instance does not exist, or if its scene has not been loaded yet.
+    pub fn spawn_progress(&self, instance_id: InstanceId) -> Option<SceneSpawnProgress> {
+        if let Some(instance) = self.spawned_instances.get(&instance_id) {
+            return Some(SceneSpawnProgress {
+                spawned_entities: instance.entity_map.len(),
+                total_entities: instance.entity_map.len(),
+            });
+        }
+        let spawn = self.incremental_spawns.get(&instance_id)?;
+        if spawn.spawned_entities == 0 && spawn.instance.entity_map.is_empty() {
+            return None;
+        }
+        Some(SceneSpawnProgress {
+            spawned_entities: spawn.spawned_entities,
+            total_entities: spawn.instance.entity_map.len(),
+        })
+    }
+
     fn set_scene_instance_parent_sync(world: &mut World, instance: &InstanceInfo) {
         let Some(parent) = instance.parent else {
             return;
@@ -505,6 +794,9 @@ impl SceneSpawner {
     fn trigger_scene_ready_events(&mut self, world: &mut World) {
         for (instance_id, parent) in self.instances_ready.drain(..) {
             if let Some(parent) = parent {
+                if let Some(instance) = self.spawned_instances.get(&instance_id) {
+                    apply_nested_scene_overrides(world, parent, &instance.entity_map);
+                }
                 // Defer via commands otherwise SceneSpawner is not available in the observer.
                 world
                     .commands()
@@ -536,6 +828,26 @@ impl SceneSpawner {
             .flatten()
             .copied()
     }
+
+    /// Mapping of entities from the scene world to the world of the spawned instance `instance_id`.
+    pub(crate) fn instance_entity_map(
+        &self,
+        instance_id: InstanceId,
+    ) -> Option<&EntityHashMap<Entity>> {
+        self.spawned_instances
+            .get(&instance_id)
+            .map(|instance| &instance.entity_map)
+    }
+
+    /// Mutable access to the mapping of entities of the spawned instance `instance_id`.
+    pub(crate) fn instance_entity_map_mut(
+        &mut self,
+        instance_id: InstanceId,
+    ) -> Option<&mut EntityHashMap<Entity>> {
+        self.spawned_instances
+            .get_mut(&instance_id)
+            .map(|instance| &mut instance.entity_map)
+    }
 }
 
 /// System that handles scheduled scene instance spawning and despawning through a [`SceneSpawner`].
@@ -553,6 +865,15 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .scenes_to_spawn
             .retain(|(_, _, parent)| is_parent_alive(parent));
+        let orphaned_spawns: Vec<_> = scene_spawner
+            .incremental_spawns
+            .iter()
+            .filter(|(_, spawn)| !is_parent_alive(&spawn.instance.parent))
+            .map(|(instance_id, _)| *instance_id)
+            .collect();
+        for instance_id in orphaned_spawns {
+            scene_spawner.despawn_instance_sync(world, &instance_id);
+        }
 
         let scene_asset_events = world.resource::<Events<AssetEvent<Scene>>>();
         let dynamic_scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
@@ -596,7 +917,11 @@ pub fn scene_spawner_system(world: &mut World) {
                         .debounced_dynamic_scene_asset_events
                         .insert(*id, 0)
                         .is_none()
-                        && scene_spawner.spawned_dynamic_scenes.contains_key(id)
+                        && (scene_spawner.spawned_dynamic_scenes.contains_key(id)
+                            || scene_spawner
+                                .incremental_spawns
+                                .values()
+                                .any(|spawn| spawn.handle.id() == *id))
                     {
                         updated_spawned_dynamic_scenes.push(*id);
                     }
@@ -610,6 +935,9 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .spawn_queued_scenes(world)
             .unwrap_or_else(|err| panic!("{}", err));
+        scene_spawner
+            .spawn_incremental_batches(world)
+            .unwrap_or_else(|err| panic!("{}", err));
         scene_spawner
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
@@ -667,7 +995,12 @@ pub fn scene_spawner(
         (Changed<SceneRoot>, Without<DynamicSceneRoot>),
     >,
     mut dynamic_scene_to_spawn: Query<
-        (Entity, &DynamicSceneRoot, Option<&mut SceneInstance>),
+        (
+            Entity,
+            &DynamicSceneRoot,
+            Option<&SceneSpawnBudget>,
+            Option<&mut SceneInstance>,
+        ),
         (Changed<DynamicSceneRoot>, Without<SceneRoot>),
     >,
     mut scene_spawner: ResMut<SceneSpawner>,
@@ -681,8 +1014,15 @@ pub fn scene_spawner(
             commands.entity(entity).insert(SceneInstance(new_instance));
         }
     }
-    for (entity, dynamic_scene, instance) in &mut dynamic_scene_to_spawn {
-        let new_instance = scene_spawner.spawn_dynamic_as_child(dynamic_scene.0.clone(), entity);
+    for (entity, dynamic_scene, budget, instance) in &mut dynamic_scene_to_spawn {
+        let new_instance = match budget {
+            Some(budget) => scene_spawner.spawn_dynamic_incremental_as_child(
+                dynamic_scene.0.clone(),
+                entity,
+                budget.entities_per_frame,
+            ),
+            None => scene_spawner.spawn_dynamic_as_child(dynamic_scene.0.clone(), entity),
+        };
         if let Some(mut old_instance) = instance {
             scene_spawner.despawn_instance(**old_instance);
             *old_instance = SceneInstance(new_instance);
@@ -1098,4 +1438,139 @@ mod tests {
             2.0
         );
     }
+
+    #[test]
+    fn spawn_dynamic_scene_incrementally() {
+        let mut app = setup();
+        app.world_mut()
+            .spawn_batch([ComponentF, ComponentF, ComponentF]);
+        let scene = build_dynamic_scene(&mut app);
+
+        let parent = app
+            .world_mut()
+            .spawn((
+                DynamicSceneRoot(scene),
+                SceneSpawnBudget {
+                    entities_per_frame: NonZeroUsize::new(2).unwrap(),
+                },
+            ))
+            .id();
+        app.world_mut().add_observer(
+            |_: On<SceneInstanceReady>, mut trigger_count: ResMut<TriggerCount>| {
+                trigger_count.0 += 1;
+            },
+        );
+
+        let progress = |app: &App| {
+            let instance_id = **app.world().get::<SceneInstance>(parent).unwrap();
+            app.world()
+                .resource::<SceneSpawner>()
+                .spawn_progress(instance_id)
+                .unwrap()
+        };
+        let spawned = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), (With<ComponentF>, With<ChildOf>)>()
+                .iter(app.world())
+                .count()
+        };
+
+        for expected in [2, 4] {
+            app.update();
+            let progress = progress(&app);
+            assert_eq!(progress.spawned_entities, expected);
+            assert_eq!(progress.total_entities, 5);
+            assert!(!progress.is_complete());
+            assert_eq!(app.world().resource::<TriggerCount>().0, 0);
+            assert!(app.world().get::<Children>(parent).is_none());
+        }
+
+        app.update();
+        assert!(progress(&app).is_complete());
+        assert_eq!(app.world().resource::<TriggerCount>().0, 1);
+        assert_eq!(app.world().get::<Children>(parent).unwrap().len(), 5);
+        assert_eq!(spawned(&mut app), 5);
+
+        app.update();
+        assert_eq!(app.world().resource::<TriggerCount>().0, 1);
+    }
+
+    #[test]
+    fn reconcile_dynamic_scene_preserves_runtime_state() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<ComponentA>();
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .set_reload_mode(SceneReloadMode::Reconcile);
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let kept = scene_world.spawn(ComponentA { x: 1.0, y: 1.0 }).id();
+        let removed = scene_world.spawn(ComponentA { x: 2.0, y: 2.0 }).id();
+        let build = |scene_world: &mut World| {
+            let entities: Vec<Entity> = scene_world
+                .query_filtered::<Entity, With<ComponentA>>()
+                .iter(scene_world)
+                .collect();
+            DynamicSceneBuilder::from_world(scene_world)
+                .extract_entities(entities.into_iter())
+                .build()
+        };
+
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(build(&mut scene_world));
+        let parent = app.world_mut().spawn(DynamicSceneRoot(scene.clone())).id();
+        // Multiple updates to get past the debounced asset events.
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let find = |app: &mut App, x: f32| {
+            app.world_mut()
+                .query::<(Entity, &ComponentA)>()
+                .iter(app.world())
+                .find(|(_, component)| component.x == x)
+                .map(|(entity, _)| entity)
+        };
+        let kept_instance = find(&mut app, 1.0).unwrap();
+        let removed_instance = find(&mut app, 2.0).unwrap();
+
+        // Runtime-only changes: an edited field and an added component.
+        app.world_mut()
+            .entity_mut(kept_instance)
+            .insert(ComponentF)
+            .get_mut::<ComponentA>()
+            .unwrap()
+            .y = 10.0;
+
+        // Edit the scene: change a field, remove an entity and add a new one.
+        scene_world.get_mut::<ComponentA>(kept).unwrap().x = 5.0;
+        scene_world.despawn(removed);
+        scene_world.spawn(ComponentA { x: 3.0, y: 3.0 });
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&scene, build(&mut scene_world))
+            .unwrap();
+
+        // Multiple updates to get past the debounced asset events.
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let component = app.world().get::<ComponentA>(kept_instance).unwrap();
+        assert_eq!(component.x, 5.0);
+        assert_eq!(component.y, 10.0);
+        assert!(app.world().get::<ComponentF>(kept_instance).is_some());
+        assert!(app.world().get_entity(removed_instance).is_err());
+
+        let added_instance = find(&mut app, 3.0).unwrap();
+        assert_eq!(
+            app.world().get::<ChildOf>(added_instance).unwrap().parent(),
+            parent
+        );
+        assert_eq!(app.world().get::<Children>(parent).unwrap().len(), 2);
+    }
 }
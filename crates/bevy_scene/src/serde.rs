@@ -1,5 +1,8 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
+pub mod binary;
+pub mod patch;
+
 use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
 use bevy_platform::collections::HashSet;
@@ -0,0 +1,632 @@
+//! Compact binary serialization and deserialization implementation for Bevy scenes.
+//!
+//! A binary scene is laid out as follows:
+//! - the [`BINARY_SCENE_MAGIC`] bytes, followed by a single [`BINARY_SCENE_VERSION`] byte,
+//! - the [type table](SceneTypeTable): the type paths of every resource and component type used in the scene,
+//!   encoded with [`postcard`],
+//! - the scene itself, encoded with [`postcard`] by a [`BinarySceneSerializer`]. Types are referred to by their index
+//!   in the type table instead of by their type path.
+//!
+//! Use [`DynamicScene::serialize_binary`] and [`DynamicScene::deserialize_binary`] to encode and decode binary scenes,
+//! or the [`BinarySceneLoader`](crate::BinarySceneLoader) and [`BinarySceneSaver`](crate::BinarySceneSaver) to use
+//! them as assets.
+
+use crate::{DynamicEntity, DynamicScene};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use bevy_ecs::entity::Entity;
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+use core::fmt::Formatter;
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeStruct, SerializeTuple},
+    Serialize, Serializer,
+};
+use thiserror::Error;
+
+/// The bytes at the start of every binary scene.
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"BSCN";
+/// The version of the binary scene format written by [`DynamicScene::serialize_binary`].
+pub const BINARY_SCENE_VERSION: u8 = 1;
+
+/// Name of the serialized binary scene struct type.
+pub const BINARY_SCENE_STRUCT: &str = "BinaryScene";
+
+/// An error that occurs when encoding or decoding a binary scene.
+#[derive(Error, Debug)]
+pub enum BinarySceneError {
+    /// The data does not start with [`BINARY_SCENE_MAGIC`].
+    #[error("data is not a binary scene")]
+    InvalidHeader,
+    /// The binary scene was written with an unsupported version of the format.
+    #[error("unsupported binary scene version {0}, expected version {BINARY_SCENE_VERSION}")]
+    UnsupportedVersion(u8),
+    /// A value of the scene does not represent any type.
+    #[error("scene contains a value that does not represent a type")]
+    MissingTypeInfo,
+    /// The type table of the binary scene contains a type that is not registered.
+    #[error("scene contains the unregistered type `{0}`")]
+    UnregisteredType(String),
+    /// The binary scene is followed by unexpected data.
+    #[error("binary scene is followed by {0} unexpected bytes")]
+    TrailingBytes(usize),
+    /// Encoding or decoding failed.
+    #[error("failed to encode or decode binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+/// The table of every type used in a [`DynamicScene`], assigning an index to each type path.
+///
+/// Types are indexed in the order in which they first appear in the scene, resources first.
+#[derive(Debug, Default)]
+pub struct SceneTypeTable<'a> {
+    type_paths: Vec<&'a str>,
+    indices: HashMap<&'a str, u32>,
+}
+
+impl<'a> SceneTypeTable<'a> {
+    /// Builds the type table of the given `scene`.
+    pub fn from_scene(scene: &'a DynamicScene) -> Result<Self, BinarySceneError> {
+        let mut table = Self::default();
+        let values = scene.resources.iter().chain(
+            scene
+                .entities
+                .iter()
+                .flat_map(|entity| entity.components.iter()),
+        );
+        for value in values {
+            let type_path = value
+                .get_represented_type_info()
+                .ok_or(BinarySceneError::MissingTypeInfo)?
+                .type_path();
+            if !table.indices.contains_key(type_path) {
+                table
+                    .indices
+                    .insert(type_path, table.type_paths.len() as u32);
+                table.type_paths.push(type_path);
+            }
+        }
+        Ok(table)
+    }
+
+    /// Returns the type paths in this table, in index order.
+    pub fn type_paths(&self) -> &[&'a str] {
+        &self.type_paths
+    }
+
+    /// Returns the index of the type represented by `value`, if it is in this table.
+    pub fn index_of(&self, value: &dyn PartialReflect) -> Option<u32> {
+        let type_path = value.get_represented_type_info()?.type_path();
+        self.indices.get(type_path).copied()
+    }
+
+    /// Resolves every type path of this table in the given `registry`.
+    pub fn resolve<'r>(
+        type_paths: &[String],
+        registry: &'r TypeRegistry,
+    ) -> Result<Vec<&'r TypeRegistration>, BinarySceneError> {
+        type_paths
+            .iter()
+            .map(|type_path| {
+                registry
+                    .get_with_type_path(type_path)
+                    .ok_or_else(|| BinarySceneError::UnregisteredType(type_path.to_owned()))
+            })
+            .collect()
+    }
+}
+
+impl DynamicScene {
+    /// Serializes this dynamic scene into the compact [binary scene format](self).
+    ///
+    /// Binary scenes are faster to load and smaller than scenes serialized with [`DynamicScene::serialize`], but they are
+    /// not human-readable. To deserialize the scene, use [`DynamicScene::deserialize_binary`] or the
+    /// [`BinarySceneLoader`](crate::BinarySceneLoader).
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, BinarySceneError> {
+        let table = SceneTypeTable::from_scene(self)?;
+        let mut bytes = BINARY_SCENE_MAGIC.to_vec();
+        bytes.push(BINARY_SCENE_VERSION);
+        let bytes = postcard::to_extend(table.type_paths(), bytes)?;
+        let bytes = postcard::to_extend(
+            &BinarySceneSerializer {
+                scene: self,
+                table: &table,
+                registry,
+            },
+            bytes,
+        )?;
+        Ok(bytes)
+    }
+
+    /// Deserializes a dynamic scene from the compact [binary scene format](self).
+    ///
+    /// Every type used in the scene must be registered in `registry`.
+    pub fn deserialize_binary(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+    ) -> Result<DynamicScene, BinarySceneError> {
+        let bytes = bytes
+            .strip_prefix(&BINARY_SCENE_MAGIC)
+            .ok_or(BinarySceneError::InvalidHeader)?;
+        let (&version, bytes) = bytes.split_first().ok_or(BinarySceneError::InvalidHeader)?;
+        if version != BINARY_SCENE_VERSION {
+            return Err(BinarySceneError::UnsupportedVersion(version));
+        }
+        let (type_paths, bytes) = postcard::take_from_bytes::<Vec<String>>(bytes)?;
+        let types = SceneTypeTable::resolve(&type_paths, registry)?;
+        let mut deserializer = postcard::Deserializer::from_bytes(bytes);
+        let scene = BinarySceneDeserializer {
+            types: &types,
+            registry,
+        }
+        .deserialize(&mut deserializer)?;
+        let remaining = deserializer.finalize()?;
+        if !remaining.is_empty() {
+            return Err(BinarySceneError::TrailingBytes(remaining.len()));
+        }
+        Ok(scene)
+    }
+}
+
+/// Serializer for a [`DynamicScene`] in the [binary scene format](self).
+///
+/// Resources and components are serialized as `(type index, value)` pairs, where the index refers to the given
+/// [`SceneTypeTable`]. The table itself must be serialized separately, before the scene.
+pub struct BinarySceneSerializer<'a> {
+    /// The scene to serialize.
+    pub scene: &'a DynamicScene,
+    /// The type table of the scene.
+    pub table: &'a SceneTypeTable<'a>,
+    /// The type registry containing the types present in the scene.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for BinarySceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(BINARY_SCENE_STRUCT, 2)?;
+        state.serialize_field(
+            super::SCENE_RESOURCES,
+            &BinaryValuesSerializer {
+                values: &self.scene.resources,
+                table: self.table,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            super::SCENE_ENTITIES,
+            &BinaryEntitiesSerializer {
+                entities: &self.scene.entities,
+                table: self.table,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct BinaryEntitiesSerializer<'a> {
+    entities: &'a [DynamicEntity],
+    table: &'a SceneTypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for BinaryEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_element(&(
+                entity.entity,
+                BinaryValuesSerializer {
+                    values: &entity.components,
+                    table: self.table,
+                    registry: self.registry,
+                },
+            ))?;
+        }
+        state.end()
+    }
+}
+
+struct BinaryValuesSerializer<'a> {
+    values: &'a [Box<dyn PartialReflect>],
+    table: &'a SceneTypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for BinaryValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in self.values {
+            state.serialize_element(&BinaryValueSerializer {
+                value: value.as_ref(),
+                table: self.table,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct BinaryValueSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    table: &'a SceneTypeTable<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for BinaryValueSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let index = self.table.index_of(self.value).ok_or_else(|| {
+            serde::ser::Error::custom("value type is missing from the scene type table")
+        })?;
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&index)?;
+        state.serialize_element(&TypedReflectSerializer::new(self.value, self.registry))?;
+        state.end()
+    }
+}
+
+/// Handles deserialization of a [`DynamicScene`] in the [binary scene format](self).
+pub struct BinarySceneDeserializer<'a> {
+    /// The registrations of the types of the scene's type table, in index order.
+    pub types: &'a [&'a TypeRegistration],
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinarySceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            BINARY_SCENE_STRUCT,
+            &[super::SCENE_RESOURCES, super::SCENE_ENTITIES],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinarySceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("binary scene struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let resources = seq
+            .next_element_seed(BinaryValuesDeserializer {
+                types: self.types,
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(super::SCENE_RESOURCES))?;
+        let entities = seq
+            .next_element_seed(BinaryEntitiesDeserializer {
+                types: self.types,
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(super::SCENE_ENTITIES))?;
+        Ok(DynamicScene {
+            resources,
+            entities,
+        })
+    }
+}
+
+struct BinaryEntitiesDeserializer<'a> {
+    types: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("sequence of entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entity) = seq.next_element_seed(BinaryEntityDeserializer {
+            types: self.types,
+            registry: self.registry,
+        })? {
+            entities.push(entity);
+        }
+        Ok(entities)
+    }
+}
+
+struct BinaryEntityDeserializer<'a> {
+    types: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity and its components")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element::<Entity>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let components = seq
+            .next_element_seed(BinaryValuesDeserializer {
+                types: self.types,
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(DynamicEntity { entity, components })
+    }
+}
+
+struct BinaryValuesDeserializer<'a> {
+    types: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("sequence of reflect values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut added = <HashSet<_>>::default();
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some((index, value)) = seq.next_element_seed(BinaryValueDeserializer {
+            types: self.types,
+            registry: self.registry,
+        })? {
+            if !added.insert(index) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    self.types[index].type_info().type_path(),
+                )));
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct BinaryValueDeserializer<'a> {
+    types: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryValueDeserializer<'a> {
+    type Value = (usize, Box<dyn PartialReflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryValueDeserializer<'a> {
+    type Value = (usize, Box<dyn PartialReflect>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("type index and reflect value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))? as usize;
+        let registration = *self.types.get(index).ok_or_else(|| {
+            Error::custom(format_args!(
+                "type index {index} is out of bounds of the type table"
+            ))
+        })?;
+        let value = seq
+            .next_element_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        // Attempt to convert using FromReflect.
+        let value = registration
+            .data::<ReflectFromReflect>()
+            .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
+            .map(PartialReflect::into_partial_reflect)
+            .unwrap_or(value);
+
+        Ok((index, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinarySceneError, BINARY_SCENE_MAGIC};
+    use crate::{DynamicScene, DynamicSceneBuilder};
+    use bevy_ecs::{
+        prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
+        reflect::AppTypeRegistry,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Foo(i32);
+
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Bar {
+        name: String,
+        values: Vec<f32>,
+    }
+
+    #[derive(Resource, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct Score(u64);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Foo>();
+            registry.register::<Bar>();
+            registry.register::<Score>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn binary_scene_round_trip() {
+        let mut world = create_world();
+        world.insert_resource(Score(42));
+        let a = world.spawn(Foo(1)).id();
+        let b = world
+            .spawn((
+                Foo(2),
+                Bar {
+                    name: "bar".into(),
+                    values: vec![1.0, 2.5],
+                },
+            ))
+            .id();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities([a, b].into_iter())
+            .extract_resources()
+            .build();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let bytes = scene.serialize_binary(&registry).unwrap();
+        let ron = scene.serialize(&registry).unwrap();
+        assert!(bytes.len() < ron.len());
+        assert!(bytes.starts_with(&BINARY_SCENE_MAGIC));
+
+        let deserialized = DynamicScene::deserialize_binary(&bytes, &registry).unwrap();
+        assert_eq!(deserialized.serialize(&registry).unwrap(), ron);
+        drop(registry);
+
+        let mut dst_world = create_world();
+        deserialized
+            .write_to_world(&mut dst_world, &mut Default::default())
+            .unwrap();
+        assert_eq!(dst_world.resource::<Score>(), &Score(42));
+        let mut query = dst_world.query::<(&Foo, Option<&Bar>)>();
+        let mut entities = query.iter(&dst_world).collect::<Vec<_>>();
+        entities.sort_by_key(|(foo, _)| foo.0);
+        assert_eq!(entities[0], (&Foo(1), None));
+        assert_eq!(
+            entities[1],
+            (
+                &Foo(2),
+                Some(&Bar {
+                    name: "bar".into(),
+                    values: vec![1.0, 2.5],
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn binary_scene_errors() {
+        let mut world = create_world();
+        let entity = world.spawn(Foo(1)).id();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(entity)
+            .build();
+        let bytes = scene
+            .serialize_binary(&world.resource::<AppTypeRegistry>().read())
+            .unwrap();
+
+        let empty_registry = AppTypeRegistry::default();
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&bytes, &empty_registry.read()),
+            Err(BinarySceneError::UnregisteredType(type_path)) if type_path.ends_with("Foo")
+        ));
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        assert!(matches!(
+            DynamicScene::deserialize_binary(b"(resources: {})", &registry),
+            Err(BinarySceneError::InvalidHeader)
+        ));
+        let mut future_version = bytes.clone();
+        future_version[BINARY_SCENE_MAGIC.len()] = 2;
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&future_version, &registry),
+            Err(BinarySceneError::UnsupportedVersion(2))
+        ));
+        let mut trailing = bytes.clone();
+        trailing.extend_from_slice(&[0, 0, 0]);
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&trailing, &registry),
+            Err(BinarySceneError::TrailingBytes(3))
+        ));
+    }
+}
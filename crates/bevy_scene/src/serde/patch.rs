@@ -0,0 +1,718 @@
+//! `serde` serialization and deserialization implementation for [`ScenePatch`]es.
+
+use crate::{
+    serde::{
+        EntitiesSerializer, SceneEntitiesDeserializer, SceneMapDeserializer, SceneMapSerializer,
+    },
+    EntityPatch, FieldPatch, SceneMapPatch, ScenePatch,
+};
+use alloc::{string::String, vec::Vec};
+use bevy_ecs::entity::Entity;
+use bevy_reflect::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    TypeRegistry,
+};
+use core::fmt::Formatter;
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Name of the serialized scene patch struct type.
+pub const SCENE_PATCH_STRUCT: &str = "ScenePatch";
+/// Name of the serialized added entities field in a scene patch struct.
+pub const SCENE_PATCH_ADDED_ENTITIES: &str = "added_entities";
+/// Name of the serialized removed entities field in a scene patch struct.
+pub const SCENE_PATCH_REMOVED_ENTITIES: &str = "removed_entities";
+/// Name of the serialized modified entities field in a scene patch struct.
+pub const SCENE_PATCH_MODIFIED_ENTITIES: &str = "modified_entities";
+/// Name of the serialized resources field in a scene patch struct.
+pub const SCENE_PATCH_RESOURCES: &str = "resources";
+
+/// Name of the serialized map patch struct type.
+pub const MAP_PATCH_STRUCT: &str = "MapPatch";
+/// Name of the serialized inserted values field in a map patch struct.
+pub const MAP_PATCH_INSERTED: &str = "inserted";
+/// Name of the serialized removed types field in a map patch struct.
+pub const MAP_PATCH_REMOVED: &str = "removed";
+/// Name of the serialized changed fields field in a map patch struct.
+pub const MAP_PATCH_CHANGED: &str = "changed";
+
+/// Name of the serialized field patch struct type.
+pub const FIELD_PATCH_STRUCT: &str = "FieldPatch";
+/// Name of the serialized type path field in a field patch struct.
+pub const FIELD_PATCH_TYPE_PATH: &str = "type_path";
+/// Name of the serialized path field in a field patch struct.
+pub const FIELD_PATCH_PATH: &str = "path";
+/// Name of the serialized value field in a field patch struct.
+pub const FIELD_PATCH_VALUE: &str = "value";
+
+/// Serializer for a [`ScenePatch`].
+///
+/// Added entities are serialized like the entities of a scene, and the new values of changed fields are serialized
+/// along with their type path.
+pub struct ScenePatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a ScenePatch,
+    /// The type registry containing the types present in the patch.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ScenePatchSerializer<'a> {
+    /// Create a new serializer from a [`ScenePatch`] and an associated [`TypeRegistry`].
+    pub fn new(patch: &'a ScenePatch, registry: &'a TypeRegistry) -> Self {
+        ScenePatchSerializer { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ScenePatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(SCENE_PATCH_STRUCT, 4)?;
+        state.serialize_field(
+            SCENE_PATCH_ADDED_ENTITIES,
+            &EntitiesSerializer {
+                entities: &self.patch.added_entities,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(SCENE_PATCH_REMOVED_ENTITIES, &self.patch.removed_entities)?;
+        state.serialize_field(
+            SCENE_PATCH_MODIFIED_ENTITIES,
+            &EntityPatchesSerializer {
+                entities: &self.patch.modified_entities,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_PATCH_RESOURCES,
+            &SceneMapPatchSerializer {
+                patch: &self.patch.resources,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// Handles serialization of modified entities as a map of entity id to the patch of its components.
+pub struct EntityPatchesSerializer<'a> {
+    /// The entity patches to serialize.
+    pub entities: &'a [EntityPatch],
+    /// Type registry in which the component types used by the patches are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_entry(
+                &entity.entity,
+                &SceneMapPatchSerializer {
+                    patch: &entity.components,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a [`SceneMapPatch`].
+pub struct SceneMapPatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a SceneMapPatch,
+    /// Type registry in which the types used by the patch are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneMapPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(MAP_PATCH_STRUCT, 3)?;
+        state.serialize_field(
+            MAP_PATCH_INSERTED,
+            &SceneMapSerializer {
+                entries: &self.patch.inserted,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(MAP_PATCH_REMOVED, &self.patch.removed)?;
+        state.serialize_field(
+            MAP_PATCH_CHANGED,
+            &FieldPatchesSerializer {
+                fields: &self.patch.changed,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct FieldPatchesSerializer<'a> {
+    fields: &'a [FieldPatch],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for FieldPatchesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.fields.len()))?;
+        for field in self.fields {
+            state.serialize_element(&FieldPatchSerializer {
+                field,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct FieldPatchSerializer<'a> {
+    field: &'a FieldPatch,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for FieldPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(FIELD_PATCH_STRUCT, 3)?;
+        state.serialize_field(FIELD_PATCH_TYPE_PATH, &self.field.type_path)?;
+        state.serialize_field(FIELD_PATCH_PATH, &self.field.path)?;
+        state.serialize_field(
+            FIELD_PATCH_VALUE,
+            &ReflectSerializer::new(self.field.value.as_ref(), self.registry),
+        )?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ScenePatchField {
+    AddedEntities,
+    RemovedEntities,
+    ModifiedEntities,
+    Resources,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum MapPatchField {
+    Inserted,
+    Removed,
+    Changed,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum FieldPatchField {
+    TypePath,
+    Path,
+    Value,
+}
+
+/// Handles scene patch deserialization.
+pub struct ScenePatchDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePatchDeserializer<'a> {
+    type Value = ScenePatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_PATCH_STRUCT,
+            &[
+                SCENE_PATCH_ADDED_ENTITIES,
+                SCENE_PATCH_REMOVED_ENTITIES,
+                SCENE_PATCH_MODIFIED_ENTITIES,
+                SCENE_PATCH_RESOURCES,
+            ],
+            ScenePatchVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct ScenePatchVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePatchVisitor<'a> {
+    type Value = ScenePatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let added_entities = seq
+            .next_element_seed(SceneEntitiesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_ADDED_ENTITIES))?;
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?;
+        let modified_entities = seq
+            .next_element_seed(EntityPatchesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_MODIFIED_ENTITIES))?;
+        let resources = seq
+            .next_element_seed(SceneMapPatchDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_RESOURCES))?;
+
+        Ok(ScenePatch {
+            added_entities,
+            removed_entities,
+            modified_entities,
+            resources,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added_entities = None;
+        let mut removed_entities = None;
+        let mut modified_entities = None;
+        let mut resources = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ScenePatchField::AddedEntities => {
+                    if added_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_ADDED_ENTITIES));
+                    }
+                    added_entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                ScenePatchField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value::<Vec<Entity>>()?);
+                }
+                ScenePatchField::ModifiedEntities => {
+                    if modified_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_MODIFIED_ENTITIES));
+                    }
+                    modified_entities = Some(map.next_value_seed(EntityPatchesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                ScenePatchField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneMapPatchDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(ScenePatch {
+            added_entities: added_entities
+                .ok_or_else(|| Error::missing_field(SCENE_PATCH_ADDED_ENTITIES))?,
+            removed_entities: removed_entities
+                .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?,
+            modified_entities: modified_entities
+                .ok_or_else(|| Error::missing_field(SCENE_PATCH_MODIFIED_ENTITIES))?,
+            resources: resources.ok_or_else(|| Error::missing_field(SCENE_PATCH_RESOURCES))?,
+        })
+    }
+}
+
+/// Handles deserialization of the modified entities of a scene patch.
+pub struct EntityPatchesDeserializer<'a> {
+    /// Type registry in which the component types used by the patches to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchesDeserializer<'a> {
+    type Value = Vec<EntityPatch>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EntityPatchesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct EntityPatchesVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityPatchesVisitor<'a> {
+    type Value = Vec<EntityPatch>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity patches")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let components = map.next_value_seed(SceneMapPatchDeserializer {
+                type_registry: self.type_registry,
+            })?;
+            entities.push(EntityPatch { entity, components });
+        }
+
+        Ok(entities)
+    }
+}
+
+/// Handles deserialization of a [`SceneMapPatch`].
+pub struct SceneMapPatchDeserializer<'a> {
+    /// Type registry in which the types used by the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapPatchDeserializer<'a> {
+    type Value = SceneMapPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            MAP_PATCH_STRUCT,
+            &[MAP_PATCH_INSERTED, MAP_PATCH_REMOVED, MAP_PATCH_CHANGED],
+            SceneMapPatchVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneMapPatchVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapPatchVisitor<'a> {
+    type Value = SceneMapPatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let inserted = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(MAP_PATCH_INSERTED))?;
+        let removed = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(MAP_PATCH_REMOVED))?;
+        let changed = seq
+            .next_element_seed(FieldPatchesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(MAP_PATCH_CHANGED))?;
+
+        Ok(SceneMapPatch {
+            inserted,
+            removed,
+            changed,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut inserted = None;
+        let mut removed = None;
+        let mut changed = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                MapPatchField::Inserted => {
+                    if inserted.is_some() {
+                        return Err(Error::duplicate_field(MAP_PATCH_INSERTED));
+                    }
+                    inserted = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                MapPatchField::Removed => {
+                    if removed.is_some() {
+                        return Err(Error::duplicate_field(MAP_PATCH_REMOVED));
+                    }
+                    removed = Some(map.next_value::<Vec<String>>()?);
+                }
+                MapPatchField::Changed => {
+                    if changed.is_some() {
+                        return Err(Error::duplicate_field(MAP_PATCH_CHANGED));
+                    }
+                    changed = Some(map.next_value_seed(FieldPatchesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(SceneMapPatch {
+            inserted: inserted.ok_or_else(|| Error::missing_field(MAP_PATCH_INSERTED))?,
+            removed: removed.ok_or_else(|| Error::missing_field(MAP_PATCH_REMOVED))?,
+            changed: changed.ok_or_else(|| Error::missing_field(MAP_PATCH_CHANGED))?,
+        })
+    }
+}
+
+struct FieldPatchesDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldPatchesDeserializer<'a> {
+    type Value = Vec<FieldPatch>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FieldPatchesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct FieldPatchesVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for FieldPatchesVisitor<'a> {
+    type Value = Vec<FieldPatch>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("sequence of field patches")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut fields = Vec::new();
+        while let Some(field) = seq.next_element_seed(FieldPatchDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            fields.push(field);
+        }
+
+        Ok(fields)
+    }
+}
+
+struct FieldPatchDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldPatchDeserializer<'a> {
+    type Value = FieldPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            FIELD_PATCH_STRUCT,
+            &[FIELD_PATCH_TYPE_PATH, FIELD_PATCH_PATH, FIELD_PATCH_VALUE],
+            FieldPatchVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct FieldPatchVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for FieldPatchVisitor<'a> {
+    type Value = FieldPatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("field patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_path = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(FIELD_PATCH_TYPE_PATH))?;
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(FIELD_PATCH_PATH))?;
+        let value = seq
+            .next_element_seed(ReflectDeserializer::new(self.type_registry))?
+            .ok_or_else(|| Error::missing_field(FIELD_PATCH_VALUE))?;
+
+        Ok(FieldPatch {
+            type_path,
+            path,
+            value,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut type_path = None;
+        let mut path = None;
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                FieldPatchField::TypePath => {
+                    if type_path.is_some() {
+                        return Err(Error::duplicate_field(FIELD_PATCH_TYPE_PATH));
+                    }
+                    type_path = Some(map.next_value::<String>()?);
+                }
+                FieldPatchField::Path => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(FIELD_PATCH_PATH));
+                    }
+                    path = Some(map.next_value::<String>()?);
+                }
+                FieldPatchField::Value => {
+                    if value.is_some() {
+                        return Err(Error::duplicate_field(FIELD_PATCH_VALUE));
+                    }
+                    value =
+                        Some(map.next_value_seed(ReflectDeserializer::new(self.type_registry))?);
+                }
+            }
+        }
+
+        Ok(FieldPatch {
+            type_path: type_path.ok_or_else(|| Error::missing_field(FIELD_PATCH_TYPE_PATH))?,
+            path: path.ok_or_else(|| Error::missing_field(FIELD_PATCH_PATH))?,
+            value: value.ok_or_else(|| Error::missing_field(FIELD_PATCH_VALUE))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ron,
+        serde::patch::{ScenePatchDeserializer, ScenePatchSerializer},
+        DynamicScene, ScenePatch,
+    };
+    use bevy_ecs::{
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        resource::Resource,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Door;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn scene_patch_round_trip() {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Position>();
+            registry.register::<Door>();
+            registry.register::<Score>();
+        }
+        world.insert_resource(registry.clone());
+        world.insert_resource(Score(0));
+        let moved = world.spawn(Position { x: 1.0, y: 2.0 }).id();
+        let opened = world.spawn(Door).id();
+        let old = DynamicScene::from_world(&world);
+
+        world.get_mut::<Position>(moved).unwrap().y = 5.0;
+        world.despawn(opened);
+        world.spawn((Position { x: 3.0, y: 4.0 }, Door));
+        world.resource_mut::<Score>().0 = 100;
+        let new = DynamicScene::from_world(&world);
+
+        let registry = registry.read();
+        let patch = ScenePatch::diff(&old, &new);
+        let serialized = patch.serialize(&registry).unwrap();
+        let deserialized = ScenePatchDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut ron::de::Deserializer::from_str(&serialized).unwrap())
+        .unwrap();
+
+        assert_eq!(
+            serialized,
+            deserialized.serialize(&registry).unwrap(),
+            "serialized patch should round-trip"
+        );
+        assert_eq!(deserialized.removed_entities, [opened]);
+        assert_eq!(deserialized.added_entities.len(), 1);
+        assert_eq!(deserialized.modified_entities[0].entity, moved);
+        assert_eq!(
+            deserialized.modified_entities[0].components.changed[0].path,
+            ".y"
+        );
+        assert_eq!(deserialized.resources.changed[0].path, ".0");
+
+        let mut patched = crate::snapshot_dynamic_scene(&old);
+        deserialized.apply(&mut patched).unwrap();
+        assert!(ScenePatch::diff(&patched, &new).is_empty());
+
+        // `ScenePatchSerializer` can be used with any serde serializer.
+        ron::ser::to_string(&ScenePatchSerializer::new(&patch, &registry)).unwrap();
+    }
+}
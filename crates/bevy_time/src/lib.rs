@@ -118,6 +118,31 @@ pub enum TimeUpdateStrategy {
     ManualDuration(Duration),
 }
 
+/// Extends [`AppHarness`](bevy_app::AppHarness) to advance time deterministically.
+#[cfg(feature = "std")]
+pub trait TimeHarnessExt {
+    /// Advances [`Time<Real>`] by `step` every frame instead of following the system clock, by
+    /// setting [`TimeUpdateStrategy::ManualDuration`].
+    ///
+    /// As usual, the first frame has a delta of zero. The maximum delta of [`Time<Virtual>`] is
+    /// raised to `step` if needed, so that virtual time advances by `step` as well.
+    fn set_time_step(&mut self, step: Duration) -> &mut Self;
+}
+
+#[cfg(feature = "std")]
+impl TimeHarnessExt for bevy_app::AppHarness {
+    fn set_time_step(&mut self, step: Duration) -> &mut Self {
+        let world = self.world_mut();
+        world.insert_resource(TimeUpdateStrategy::ManualDuration(step));
+        if let Some(mut virtual_time) = world.get_resource_mut::<Time<Virtual>>()
+            && virtual_time.max_delta() < step
+        {
+            virtual_time.set_max_delta(step);
+        }
+        self
+    }
+}
+
 /// Channel resource used to receive time from the render world.
 #[cfg(feature = "std")]
 #[derive(Resource)]
@@ -181,8 +206,8 @@ pub fn time_system(
 #[cfg(test)]
 #[expect(clippy::print_stdout, reason = "Allowed in tests.")]
 mod tests {
-    use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
-    use bevy_app::{App, FixedUpdate, Startup, Update};
+    use crate::{Fixed, Time, TimeHarnessExt, TimePlugin, TimeUpdateStrategy, Virtual};
+    use bevy_app::{App, AppHarness, FixedUpdate, Startup, Update};
     use bevy_ecs::{
         event::{
             BufferedEvent, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents,
@@ -403,4 +428,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn harness_time_step() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<FixedUpdateCounter>()
+            .add_systems(FixedUpdate, count_fixed_updates);
+
+        let mut harness = AppHarness::new(app);
+        harness.set_time_step(Duration::from_millis(500));
+        harness.run_frames(3).unwrap();
+
+        let virtual_time = harness.world().resource::<Time<Virtual>>();
+        assert_eq!(virtual_time.delta(), Duration::from_millis(500));
+        assert_eq!(virtual_time.elapsed(), Duration::from_secs(1));
+        // One second at the default rate of 64 fixed updates per second.
+        assert_eq!(harness.world().resource::<FixedUpdateCounter>().0, 64);
+    }
 }